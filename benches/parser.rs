@@ -0,0 +1,66 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use joey_script::{ast, tokenizer::Tokenizer};
+
+// One statement of `terms` operands, the time per operand should stay the same as the chain grows
+fn chain(terms: usize, first: &str, separator: &str, last: &str) -> String {
+    let operands: Vec<String> = (0..terms).map(|index| format!("a{}", index % 10)).collect();
+
+    format!("{}{}{};", first, operands.join(separator), last)
+}
+
+fn parse_chains(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_chain");
+
+    // The tree of a chain is as deep as the chain is long and dropping it recurses through every level, longer chains
+    // overflow the stack of the main thread
+    for terms in [1_000, 10_000, 50_000] {
+        let inputs = [
+            ("binary", chain(terms, "", " + ", "")),
+            ("mixed_precedence", chain(terms, "", " * 2 + ", "")),
+            ("sequence", chain(terms, "", ", ", "")),
+            ("arguments", chain(terms, "call(", ", ", ")")),
+            ("member", chain(terms, "", ".", "")),
+            ("call", chain(terms, "call(", ")(", ")")),
+        ];
+
+        group.throughput(Throughput::Elements(terms as u64));
+
+        for (name, input) in &inputs {
+            group.bench_function(format!("{}_{}", name, terms), |b| {
+                b.iter(|| ast::parse(Tokenizer::new(input)).unwrap())
+            });
+        }
+    }
+
+    group.finish();
+}
+
+// `depth` nested brackets around a single operand, the time per level should stay the same as the nesting grows. The
+// depth stays below `ast::MAX_NESTING_DEPTH`
+fn nested(depth: usize, open: &str, close: &str) -> String {
+    format!("{}a{};", open.repeat(depth), close.repeat(depth))
+}
+
+fn parse_nesting(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_nesting");
+
+    for depth in [10, 100, 250] {
+        let inputs = [
+            ("parentheses", nested(depth, "(", ")")),
+            ("arrays", nested(depth, "[", "]")),
+        ];
+
+        group.throughput(Throughput::Elements(depth as u64));
+
+        for (name, input) in &inputs {
+            group.bench_function(format!("{}_{}", name, depth), |b| {
+                b.iter(|| ast::parse(Tokenizer::new(input)).unwrap())
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parse_chains, parse_nesting);
+criterion_main!(benches);
@@ -0,0 +1,37 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use joey_script::tokenizer;
+
+const SNIPPET: &str = r#"
+// Compute the totals for the current bundle
+function total(first, second, third) {
+    log('Totals for the "current" bundle', first + second - third, 1234567);
+    /* Block comments are skipped
+       by the tokenizer */
+    return first === null || second !== false && third >= 42;
+}
+log("Escaped \"quotes\" and \\ slashes", total(1, 2, 3));
+"#;
+
+fn bundle(repetitions: usize) -> String {
+    SNIPPET.repeat(repetitions)
+}
+
+// Run with `cargo bench --bench tokenizer -- --warm-up-time 2 --measurement-time 8`, comparisons between trees only
+// hold on the same machine
+fn tokenize_bundle(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tokenize");
+
+    for repetitions in [100, 1_000, 10_000] {
+        let input = bundle(repetitions);
+
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_function(format!("{}_snippets", repetitions), |b| {
+            b.iter(|| tokenizer::parse(&input).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, tokenize_bundle);
+criterion_main!(benches);
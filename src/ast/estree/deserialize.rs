@@ -0,0 +1,867 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+use crate::{tokenizer::{Operator, FileLocation, FileLocationPos, SourceType}, ast::{Program, span::Span, nodes::{AstNode, error::ErrorNode, Literal, LiteralValue, Identifier, Comment, CommentKind, block_statement::BlockStatement, function_declaration::FunctionDeclaration, pattern::{Pattern, ArrayPattern, ObjectPattern, ObjectPatternProperty, AssignmentProperty, RestElement}, jump_statement::{ReturnStatement, ThrowStatement, BreakStatement, ContinueStatement}, for_statement::{ForStatement, ForInit, ForInStatement, ForOfStatement, ForLeft, EmptyStatement}, do_while_statement::DoWhileStatement, variable_declaration::{VariableDeclaration, VariableDeclarator, VariableKind}, expression_statement::{Expression, ExpressionStatement, LiteralExpression, IdentifierExpression, BinaryExpression, BinaryOperator, LogicalExpression, UpdateExpression, ArrowFunctionExpression, ArrowFunctionBody, CallExpression, MemberExpression, ChainExpression, ObjectExpression, ObjectProperty, Property, ArrayExpression, ExpressionOrSpread, SpreadElement, SequenceExpression}}}};
+
+#[derive(Debug, PartialEq)]
+pub enum EstreeErrorKind {
+    InvalidJson(String),
+    MissingField(&'static str),
+    /// The value has the wrong JSON type, e.g. a string where a number is expected
+    InvalidType { expected: &'static str },
+    UnknownNodeType(String),
+    /// A known node where a different kind of node is expected, e.g. an expression in a statement list
+    UnexpectedNodeType { expected: &'static str, found: String },
+    UnknownOperator(String),
+    /// Valid ESTree that joey-script can't represent yet
+    Unsupported(&'static str),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct EstreeError {
+    /// Where in the document the error is, e.g. `$.body[0].expression.callee`
+    pub path: String,
+    pub kind: EstreeErrorKind,
+}
+
+impl fmt::Display for EstreeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EstreeErrorKind::InvalidJson(message) => write!(f, "invalid JSON: {}", message),
+            EstreeErrorKind::MissingField(field) => write!(f, "missing field `{}`", field),
+            EstreeErrorKind::InvalidType { expected } => write!(f, "expected {}", expected),
+            EstreeErrorKind::UnknownNodeType(found) => write!(f, "unknown node type `{}`", found),
+            EstreeErrorKind::UnexpectedNodeType { expected, found } => write!(f, "expected {}, found `{}`", expected, found),
+            EstreeErrorKind::UnknownOperator(found) => write!(f, "unknown operator `{}`", found),
+            EstreeErrorKind::Unsupported(feature) => write!(f, "{} are not supported", feature),
+        }
+    }
+}
+
+impl fmt::Display for EstreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.kind)
+    }
+}
+
+impl std::error::Error for EstreeError {}
+
+// Every node type from the ESTree spec up to ES2022, the comment types and the `Error` node of a
+// recovered program, anything else is an unknown node
+const ESTREE_NODE_TYPES: [&str; 72] = [
+    "Program", "Identifier", "Literal", "PrivateIdentifier", "ExpressionStatement", "Directive", "BlockStatement",
+    "StaticBlock", "EmptyStatement", "DebuggerStatement", "WithStatement", "ReturnStatement", "LabeledStatement",
+    "BreakStatement", "ContinueStatement", "IfStatement", "SwitchStatement", "SwitchCase", "ThrowStatement",
+    "TryStatement", "CatchClause", "WhileStatement", "DoWhileStatement", "ForStatement", "ForInStatement",
+    "ForOfStatement", "FunctionDeclaration", "VariableDeclaration", "VariableDeclarator", "ClassDeclaration",
+    "ThisExpression", "ArrayExpression", "ObjectExpression", "Property", "FunctionExpression",
+    "ArrowFunctionExpression", "UnaryExpression", "UpdateExpression", "BinaryExpression", "AssignmentExpression",
+    "LogicalExpression", "MemberExpression", "ChainExpression", "ConditionalExpression", "CallExpression",
+    "NewExpression", "SequenceExpression", "YieldExpression", "AwaitExpression", "TemplateLiteral",
+    "TaggedTemplateExpression", "TemplateElement", "ClassExpression", "ClassBody", "MethodDefinition",
+    "PropertyDefinition", "MetaProperty", "ImportExpression", "SpreadElement", "RestElement", "ObjectPattern",
+    "ArrayPattern", "AssignmentPattern", "ImportDeclaration", "ImportSpecifier", "ExportNamedDeclaration",
+    "ExportDefaultDeclaration", "ExportAllDeclaration", "Super", "Line", "Block", "Error",
+];
+
+/// A JSON object that is read as an ESTree node, with the path that leads to it
+struct Node<'a> {
+    fields: &'a Map<String, Value>,
+    path: String,
+    node_type: &'a str,
+}
+
+fn error<T>(path: impl Into<String>, kind: EstreeErrorKind) -> Result<T, EstreeError> {
+    Err(EstreeError { path: path.into(), kind })
+}
+
+fn node(value: &Value, path: String) -> Result<Node<'_>, EstreeError> {
+    let Some(fields) = value.as_object() else {
+        return error(path, EstreeErrorKind::InvalidType { expected: "a node object" });
+    };
+
+    let node_type = match fields.get("type") {
+        Some(Value::String(node_type)) => node_type.as_str(),
+        Some(_) => return error(format!("{}.type", path), EstreeErrorKind::InvalidType { expected: "a string" }),
+        None => return error(path, EstreeErrorKind::MissingField("type")),
+    };
+
+    if !ESTREE_NODE_TYPES.contains(&node_type) {
+        return error(path, EstreeErrorKind::UnknownNodeType(node_type.to_string()));
+    }
+
+    Ok(Node { fields, path, node_type })
+}
+
+impl<'a> Node<'a> {
+    fn field_path(&self, field: &str) -> String {
+        format!("{}.{}", self.path, field)
+    }
+
+    fn field(&self, field: &'static str) -> Result<&'a Value, EstreeError> {
+        match self.fields.get(field) {
+            Some(value) => Ok(value),
+            None => error(self.path.clone(), EstreeErrorKind::MissingField(field)),
+        }
+    }
+
+    fn node(&self, field: &'static str) -> Result<Node<'a>, EstreeError> {
+        node(self.field(field)?, self.field_path(field))
+    }
+
+    // A missing field is read as `null`, like the flags
+    fn optional_node(&self, field: &'static str) -> Result<Option<Node<'a>>, EstreeError> {
+        match self.fields.get(field) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => node(value, self.field_path(field)).map(Some),
+        }
+    }
+
+    // Arrays can have holes, e.g. the `elements` of an array expression
+    fn optional_nodes(&self, field: &'static str) -> Result<Vec<Option<Node<'a>>>, EstreeError> {
+        let Value::Array(values) = self.field(field)? else {
+            return error(self.field_path(field), EstreeErrorKind::InvalidType { expected: "an array" });
+        };
+
+        values.iter()
+            .enumerate()
+            .map(|(index, value)| match value {
+                Value::Null => Ok(None),
+                value => node(value, format!("{}.{}[{}]", self.path, field, index)).map(Some),
+            })
+            .collect()
+    }
+
+    fn nodes(&self, field: &'static str) -> Result<Vec<Node<'a>>, EstreeError> {
+        let Value::Array(values) = self.field(field)? else {
+            return error(self.field_path(field), EstreeErrorKind::InvalidType { expected: "an array" });
+        };
+
+        values.iter()
+            .enumerate()
+            .map(|(index, value)| node(value, format!("{}.{}[{}]", self.path, field, index)))
+            .collect()
+    }
+
+    fn string(&self, field: &'static str) -> Result<&'a str, EstreeError> {
+        match self.field(field)? {
+            Value::String(value) => Ok(value),
+            _ => error(self.field_path(field), EstreeErrorKind::InvalidType { expected: "a string" }),
+        }
+    }
+
+    fn optional_number(&self, field: &'static str) -> Result<Option<usize>, EstreeError> {
+        match self.fields.get(field) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => match value.as_u64() {
+                Some(value) => Ok(Some(value as usize)),
+                None => error(self.field_path(field), EstreeErrorKind::InvalidType { expected: "a non-negative integer" }),
+            },
+        }
+    }
+
+    // Missing flags are read as `false`, not every tool writes them
+    fn flag(&self, field: &'static str) -> Result<bool, EstreeError> {
+        match self.fields.get(field) {
+            None | Some(Value::Null) => Ok(false),
+            Some(Value::Bool(value)) => Ok(*value),
+            Some(_) => error(self.field_path(field), EstreeErrorKind::InvalidType { expected: "a boolean" }),
+        }
+    }
+
+    fn expect(&self, expected: &'static str, node_types: &[&str]) -> Result<(), EstreeError> {
+        if node_types.contains(&self.node_type) { return Ok(()) }
+
+        self.unexpected(expected)
+    }
+
+    fn unexpected<T>(&self, expected: &'static str) -> Result<T, EstreeError> {
+        error(self.path.clone(), EstreeErrorKind::UnexpectedNodeType { expected, found: self.node_type.to_string() })
+    }
+
+    // Locations are optional, e.g. a tree built by hand or by a parser without `locations`. A missing offset falls back
+    // to the start of the input and a missing `loc` to the start of the first line
+    fn span(&self) -> Result<Span, EstreeError> {
+        let start = self.optional_number("start")?.unwrap_or(0);
+        let end = self.optional_number("end")?.unwrap_or(start);
+
+        let loc = match self.fields.get("loc") {
+            None | Some(Value::Null) => {
+                let position = FileLocationPos { line: 1, column: 0 };
+                FileLocation { start: position.clone(), end: position }
+            },
+            Some(value) => node_location(value, self.field_path("loc"))?,
+        };
+
+        Ok(Span { range: (start, end), loc })
+    }
+}
+
+fn node_location(value: &Value, path: String) -> Result<FileLocation, EstreeError> {
+    let position = |field: &'static str| -> Result<FileLocationPos, EstreeError> {
+        let path = format!("{}.{}", path, field);
+
+        let Some(position) = value.get(field) else {
+            return error(path, EstreeErrorKind::MissingField(field));
+        };
+
+        let number = |field: &'static str| match position.get(field).map(Value::as_u64) {
+            Some(Some(value)) => Ok(value as usize),
+            Some(None) => error(format!("{}.{}", path, field), EstreeErrorKind::InvalidType { expected: "a non-negative integer" }),
+            None => error(path.clone(), EstreeErrorKind::MissingField(field)),
+        };
+
+        Ok(FileLocationPos { line: number("line")?, column: number("column")? })
+    };
+
+    if !value.is_object() {
+        return error(path, EstreeErrorKind::InvalidType { expected: "a location object" });
+    }
+
+    Ok(FileLocation { start: position("start")?, end: position("end")? })
+}
+
+fn program(node: &Node) -> Result<Program, EstreeError> {
+    node.expect("a program", &["Program"])?;
+
+    let body = node.nodes("body")?.iter().map(statement).collect::<Result<_, _>>()?;
+
+    // Comments are optional, most tools only write them on request
+    let comments = match node.fields.get("comments") {
+        Some(_) => node.nodes("comments")?.iter().map(comment).collect::<Result<_, _>>()?,
+        None => Vec::new(),
+    };
+
+    // Trees from before ES2015 don't have a source type
+    let source_type = match node.fields.get("sourceType") {
+        None => SourceType::default(),
+        Some(_) => match node.string("sourceType")? {
+            "script" => SourceType::Script,
+            "module" => SourceType::Module,
+            _ => return error(node.field_path("sourceType"), EstreeErrorKind::InvalidType { expected: "`script` or `module`" }),
+        },
+    };
+
+    Ok(Program { body, comments, span: node.span()?, source_type })
+}
+
+fn comment(node: &Node) -> Result<Comment, EstreeError> {
+    let kind = match node.node_type {
+        "Line" => CommentKind::Line,
+        "Block" => CommentKind::Block,
+        _ => return node.unexpected("a comment"),
+    };
+
+    Ok(Comment { kind, value: node.string("value")?.to_string(), span: node.span()? })
+}
+
+fn statement(node: &Node) -> Result<AstNode, EstreeError> {
+    match node.node_type {
+        "ExpressionStatement" => Ok(AstNode::ExpressionStatement(ExpressionStatement {
+            expression: expression(&node.node("expression")?)?,
+            span: node.span()?,
+        })),
+        "BlockStatement" => Ok(AstNode::BlockStatement(block_statement(node)?)),
+        "FunctionDeclaration" => Ok(AstNode::FunctionDeclaration(function_declaration(node)?)),
+        "ReturnStatement" => Ok(AstNode::ReturnStatement(ReturnStatement {
+            argument: node.optional_node("argument")?.as_ref().map(expression).transpose()?,
+            span: node.span()?,
+        })),
+        "ThrowStatement" => Ok(AstNode::ThrowStatement(ThrowStatement {
+            argument: expression(&node.node("argument")?)?,
+            span: node.span()?,
+        })),
+        "BreakStatement" => Ok(AstNode::BreakStatement(BreakStatement {
+            label: node.optional_node("label")?.as_ref().map(identifier).transpose()?,
+            span: node.span()?,
+        })),
+        "ContinueStatement" => Ok(AstNode::ContinueStatement(ContinueStatement {
+            label: node.optional_node("label")?.as_ref().map(identifier).transpose()?,
+            span: node.span()?,
+        })),
+        "ForStatement" => {
+            let init = node.optional_node("init")?.as_ref().map(|init| match init.node_type {
+                "VariableDeclaration" => variable_declaration(init).map(ForInit::VariableDeclaration),
+                _ => expression(init).map(ForInit::Expression),
+            }).transpose()?;
+
+            Ok(AstNode::ForStatement(ForStatement {
+                init,
+                test: node.optional_node("test")?.as_ref().map(expression).transpose()?,
+                update: node.optional_node("update")?.as_ref().map(expression).transpose()?,
+                body: Box::new(statement(&node.node("body")?)?),
+                span: node.span()?,
+            }))
+        },
+        "ForInStatement" => Ok(AstNode::ForInStatement(ForInStatement {
+            left: for_left(&node.node("left")?)?,
+            right: expression(&node.node("right")?)?,
+            body: Box::new(statement(&node.node("body")?)?),
+            span: node.span()?,
+        })),
+        "ForOfStatement" => {
+            if node.flag("await")? {
+                return error(node.field_path("await"), EstreeErrorKind::Unsupported("`for await` loops"));
+            }
+
+            Ok(AstNode::ForOfStatement(ForOfStatement {
+                left: for_left(&node.node("left")?)?,
+                right: expression(&node.node("right")?)?,
+                body: Box::new(statement(&node.node("body")?)?),
+                span: node.span()?,
+            }))
+        },
+        "DoWhileStatement" => Ok(AstNode::DoWhileStatement(DoWhileStatement {
+            body: Box::new(statement(&node.node("body")?)?),
+            test: expression(&node.node("test")?)?,
+            span: node.span()?,
+        })),
+        "VariableDeclaration" => variable_declaration(node).map(AstNode::VariableDeclaration),
+        "EmptyStatement" => Ok(AstNode::EmptyStatement(EmptyStatement { span: node.span()? })),
+        "Error" => Ok(AstNode::Error(ErrorNode { span: node.span()? })),
+        _ => node.unexpected("a statement"),
+    }
+}
+
+fn variable_declaration(node: &Node) -> Result<VariableDeclaration, EstreeError> {
+    node.expect("a variable declaration", &["VariableDeclaration"])?;
+
+    let kind = match node.string("kind")? {
+        "var" => VariableKind::Var,
+        "let" => VariableKind::Let,
+        "const" => VariableKind::Const,
+        _ => return error(node.field_path("kind"), EstreeErrorKind::InvalidType { expected: "`var`, `let` or `const`" }),
+    };
+
+    let declarations = node.nodes("declarations")?.iter().map(variable_declarator).collect::<Result<_, _>>()?;
+
+    Ok(VariableDeclaration { kind, declarations, span: node.span()? })
+}
+
+fn variable_declarator(node: &Node) -> Result<VariableDeclarator, EstreeError> {
+    node.expect("a variable declarator", &["VariableDeclarator"])?;
+
+    Ok(VariableDeclarator {
+        id: pattern(&node.node("id")?)?,
+        init: node.optional_node("init")?.as_ref().map(expression).transpose()?,
+        span: node.span()?,
+    })
+}
+
+fn for_left(node: &Node) -> Result<ForLeft, EstreeError> {
+    match node.node_type {
+        "VariableDeclaration" => Ok(ForLeft::VariableDeclaration(variable_declaration(node)?)),
+        "MemberExpression" => Ok(ForLeft::Pattern(Pattern::MemberExpression(member_expression(node)?))),
+        _ => Ok(ForLeft::Pattern(pattern(node)?)),
+    }
+}
+
+fn block_statement(node: &Node) -> Result<BlockStatement, EstreeError> {
+    node.expect("a block statement", &["BlockStatement"])?;
+
+    let body = node.nodes("body")?.iter().map(statement).collect::<Result<_, _>>()?;
+
+    Ok(BlockStatement { body, span: node.span()? })
+}
+
+fn function_declaration(node: &Node) -> Result<FunctionDeclaration, EstreeError> {
+    if node.flag("async")? {
+        return error(node.field_path("async"), EstreeErrorKind::Unsupported("async functions"));
+    }
+
+    if node.flag("generator")? {
+        return error(node.field_path("generator"), EstreeErrorKind::Unsupported("generator functions"));
+    }
+
+    let params = node.nodes("params")?.iter().map(pattern).collect::<Result<_, _>>()?;
+
+    Ok(FunctionDeclaration {
+        id: identifier(&node.node("id")?)?,
+        params,
+        body: block_statement(&node.node("body")?)?,
+        span: node.span()?,
+    })
+}
+
+fn arrow_function_expression(node: &Node) -> Result<ArrowFunctionExpression, EstreeError> {
+    if node.flag("async")? {
+        return error(node.field_path("async"), EstreeErrorKind::Unsupported("async functions"));
+    }
+
+    let params = node.nodes("params")?.iter().map(pattern).collect::<Result<_, _>>()?;
+    let body = node.node("body")?;
+
+    let body = match body.node_type {
+        "BlockStatement" => ArrowFunctionBody::BlockStatement(Box::new(block_statement(&body)?)),
+        _ => ArrowFunctionBody::Expression(Box::new(expression(&body)?)),
+    };
+
+    Ok(ArrowFunctionExpression { params, body, span: node.span()? })
+}
+
+fn identifier(node: &Node) -> Result<Identifier, EstreeError> {
+    node.expect("an identifier", &["Identifier"])?;
+
+    Ok(Identifier { name: node.string("name")?.to_string(), span: node.span()? })
+}
+
+fn literal(node: &Node) -> Result<Literal, EstreeError> {
+    let value = if let Some(regex) = node.fields.get("regex") {
+        let regex = object(regex, node.field_path("regex"))?;
+
+        LiteralValue::RegExp { pattern: regex.string("pattern")?.to_string(), flags: regex.string("flags")?.to_string() }
+    } else if node.fields.contains_key("bigint") {
+        LiteralValue::BigInt(node.string("bigint")?.to_string())
+    } else {
+        match node.field("value")? {
+            Value::Number(value) => LiteralValue::Number(value.as_f64().unwrap_or(f64::NAN)),
+            Value::String(value) => LiteralValue::String(value.clone()),
+            Value::Bool(value) => LiteralValue::Boolean(*value),
+            Value::Null => LiteralValue::Null,
+            _ => return error(node.field_path("value"), EstreeErrorKind::InvalidType { expected: "a literal value" }),
+        }
+    };
+
+    // `raw` is optional in ESTree, without it the literal is written the way JavaScript would print its value
+    let raw = match node.fields.get("raw") {
+        None | Some(Value::Null) => raw_from_value(&value),
+        Some(_) => node.string("raw")?.to_string(),
+    };
+
+    Ok(Literal { value, raw, span: node.span()? })
+}
+
+fn raw_from_value(value: &LiteralValue) -> String {
+    match value {
+        LiteralValue::Number(value) => value.to_string(),
+        LiteralValue::BigInt(digits) => format!("{}n", digits),
+        // A JSON string is a valid JavaScript string literal
+        LiteralValue::String(value) => Value::from(value.as_str()).to_string(),
+        LiteralValue::Boolean(value) => value.to_string(),
+        LiteralValue::Null => String::from("null"),
+        LiteralValue::RegExp { pattern, flags } => format!("/{}/{}", pattern, flags),
+    }
+}
+
+// The `regex` field of a literal is a plain object, not a node
+fn object(value: &Value, path: String) -> Result<Node<'_>, EstreeError> {
+    match value.as_object() {
+        Some(fields) => Ok(Node { fields, path, node_type: "" }),
+        None => error(path, EstreeErrorKind::InvalidType { expected: "an object" }),
+    }
+}
+
+fn expression(node: &Node) -> Result<Expression, EstreeError> {
+    match node.node_type {
+        "Identifier" => Ok(Expression::Identifier(IdentifierExpression { identifier: identifier(node)? })),
+        "Literal" => Ok(Expression::Literal(LiteralExpression { value: literal(node)? })),
+        "BinaryExpression" => {
+            let operator = node.string("operator")?;
+
+            // Assignment, logical and unary operators have their own ESTree nodes
+            let operator = match (operator, Operator::from_str(operator)) {
+                ("in", _) => BinaryOperator::In,
+                ("instanceof", _) => BinaryOperator::Instanceof,
+                (_, Ok(operator @ (
+                    Operator::Equal | Operator::NotEqual | Operator::StrictEqual | Operator::StrictNotEqual |
+                    Operator::LessThan | Operator::LessThanOrEqual | Operator::GreaterThan | Operator::GreaterThanOrEqual |
+                    Operator::LeftShift | Operator::RightShift | Operator::UnsignedRightShift |
+                    Operator::Plus | Operator::Minus | Operator::Multiply | Operator::Divide | Operator::Remainder | Operator::Exponent |
+                    Operator::BitwiseOr | Operator::BitwiseXor | Operator::BitwiseAnd
+                ))) => BinaryOperator::Operator(operator),
+                _ => return error(node.field_path("operator"), EstreeErrorKind::UnknownOperator(operator.to_string())),
+            };
+
+            Ok(Expression::BinaryExpression(BinaryExpression {
+                operator,
+                left: Box::new(expression(&node.node("left")?)?),
+                right: Box::new(expression(&node.node("right")?)?),
+                span: node.span()?,
+            }))
+        },
+        "LogicalExpression" => {
+            let operator = node.string("operator")?;
+
+            let operator = match Operator::from_str(operator) {
+                Ok(operator @ (Operator::LogicalAnd | Operator::LogicalOr | Operator::NullishCoalescing)) => operator,
+                _ => return error(node.field_path("operator"), EstreeErrorKind::UnknownOperator(operator.to_string())),
+            };
+
+            Ok(Expression::LogicalExpression(LogicalExpression {
+                operator,
+                left: Box::new(expression(&node.node("left")?)?),
+                right: Box::new(expression(&node.node("right")?)?),
+                span: node.span()?,
+            }))
+        },
+        "UpdateExpression" => {
+            let operator = node.string("operator")?;
+
+            let operator = match Operator::from_str(operator) {
+                Ok(operator @ (Operator::Increment | Operator::Decrement)) => operator,
+                _ => return error(node.field_path("operator"), EstreeErrorKind::UnknownOperator(operator.to_string())),
+            };
+
+            Ok(Expression::UpdateExpression(UpdateExpression {
+                operator,
+                prefix: node.flag("prefix")?,
+                argument: Box::new(expression(&node.node("argument")?)?),
+                span: node.span()?,
+            }))
+        },
+        "ArrowFunctionExpression" => Ok(Expression::ArrowFunctionExpression(arrow_function_expression(node)?)),
+        "CallExpression" => {
+            let arguments = node.nodes("arguments")?.iter().map(expression_or_spread).collect::<Result<_, _>>()?;
+
+            Ok(Expression::CallExpression(CallExpression {
+                callee: Box::new(expression(&node.node("callee")?)?),
+                arguments,
+                optional: node.flag("optional")?,
+                span: node.span()?,
+            }))
+        },
+        "MemberExpression" => Ok(Expression::MemberExpression(member_expression(node)?)),
+        "ChainExpression" => {
+            Ok(Expression::ChainExpression(ChainExpression { expression: Box::new(expression(&node.node("expression")?)?), span: node.span()? }))
+        },
+        "SequenceExpression" => {
+            let expressions = node.nodes("expressions")?.iter().map(expression).collect::<Result<_, _>>()?;
+
+            Ok(Expression::SequenceExpression(SequenceExpression { expressions, span: node.span()? }))
+        },
+        "ObjectExpression" => {
+            let properties = node.nodes("properties")?.iter().map(object_property).collect::<Result<_, _>>()?;
+
+            Ok(Expression::ObjectExpression(ObjectExpression { properties, span: node.span()? }))
+        },
+        "ArrayExpression" => {
+            let elements = node.optional_nodes("elements")?.iter()
+                .map(|element| element.as_ref().map(expression_or_spread).transpose())
+                .collect::<Result<_, _>>()?;
+
+            Ok(Expression::ArrayExpression(ArrayExpression { elements, span: node.span()? }))
+        },
+        _ => node.unexpected("an expression"),
+    }
+}
+
+fn member_expression(node: &Node) -> Result<MemberExpression, EstreeError> {
+    let computed = node.flag("computed")?;
+    let property = node.node("property")?;
+
+    // Only computed properties can be any expression, `#private` names aren't supported
+    if !computed && property.node_type != "Identifier" {
+        return error(property.path, EstreeErrorKind::Unsupported("properties other than identifiers"));
+    }
+
+    Ok(MemberExpression {
+        object: Box::new(expression(&node.node("object")?)?),
+        property: Box::new(expression(&property)?),
+        computed,
+        optional: node.flag("optional")?,
+        span: node.span()?,
+    })
+}
+
+fn spread_element(node: &Node) -> Result<SpreadElement, EstreeError> {
+    Ok(SpreadElement { argument: Box::new(expression(&node.node("argument")?)?), span: node.span()? })
+}
+
+fn expression_or_spread(node: &Node) -> Result<ExpressionOrSpread, EstreeError> {
+    match node.node_type {
+        "SpreadElement" => Ok(ExpressionOrSpread::Spread(spread_element(node)?)),
+        _ => Ok(ExpressionOrSpread::Expression(expression(node)?)),
+    }
+}
+
+fn object_property(node: &Node) -> Result<ObjectProperty, EstreeError> {
+    if node.node_type == "SpreadElement" {
+        return Ok(ObjectProperty::Spread(spread_element(node)?));
+    }
+
+    node.expect("a property", &["Property"])?;
+
+    if node.flag("method")? || node.string("kind")? != "init" {
+        return error(node.field_path("kind"), EstreeErrorKind::Unsupported("methods and accessors"));
+    }
+
+    Ok(ObjectProperty::Property(Property {
+        key: Box::new(expression(&node.node("key")?)?),
+        value: Box::new(expression(&node.node("value")?)?),
+        computed: node.flag("computed")?,
+        shorthand: node.flag("shorthand")?,
+        span: node.span()?,
+    }))
+}
+
+fn pattern(node: &Node) -> Result<Pattern, EstreeError> {
+    match node.node_type {
+        "RestElement" => Ok(Pattern::RestElement(rest_element(node)?)),
+        "ArrayPattern" => {
+            let elements = node.optional_nodes("elements")?.iter()
+                .map(|element| element.as_ref().map(pattern).transpose())
+                .collect::<Result<_, _>>()?;
+
+            Ok(Pattern::ArrayPattern(ArrayPattern { elements, span: node.span()? }))
+        },
+        "ObjectPattern" => {
+            let properties = node.nodes("properties")?.iter().map(object_pattern_property).collect::<Result<_, _>>()?;
+
+            Ok(Pattern::ObjectPattern(ObjectPattern { properties, span: node.span()? }))
+        },
+        "AssignmentPattern" => error(node.path.clone(), EstreeErrorKind::Unsupported("default values")),
+        _ => Ok(Pattern::Identifier(identifier(node)?)),
+    }
+}
+
+fn rest_element(node: &Node) -> Result<RestElement, EstreeError> {
+    Ok(RestElement { argument: Box::new(pattern(&node.node("argument")?)?), span: node.span()? })
+}
+
+fn object_pattern_property(node: &Node) -> Result<ObjectPatternProperty, EstreeError> {
+    if node.node_type == "RestElement" {
+        return Ok(ObjectPatternProperty::Rest(rest_element(node)?));
+    }
+
+    node.expect("a property", &["Property"])?;
+
+    Ok(ObjectPatternProperty::Property(AssignmentProperty {
+        key: Box::new(expression(&node.node("key")?)?),
+        value: Box::new(pattern(&node.node("value")?)?),
+        computed: node.flag("computed")?,
+        shorthand: node.flag("shorthand")?,
+        span: node.span()?,
+    }))
+}
+
+/// Reads a `Program` from an ESTree JSON value
+pub fn from_value(value: &Value) -> Result<Program, EstreeError> {
+    program(&node(value, String::from("$"))?)
+}
+
+/// Reads a `Program` from an ESTree JSON document
+pub fn from_json(json: &str) -> Result<Program, EstreeError> {
+    match serde_json::from_str::<Value>(json) {
+        Ok(value) => from_value(&value),
+        Err(err) => error("$", EstreeErrorKind::InvalidJson(err.to_string())),
+    }
+}
+
+impl<'de> Deserialize<'de> for Program {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+
+        from_value(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{tokenizer::SourceType, ast::nodes::{AstNode, LiteralValue, expression_statement::Expression}};
+
+    use super::{from_value, from_json, EstreeError, EstreeErrorKind};
+
+    fn loc() -> serde_json::Value {
+        json!({ "start": { "line": 1, "column": 0 }, "end": { "line": 1, "column": 3 } })
+    }
+
+    fn program(body: serde_json::Value) -> serde_json::Value {
+        json!({ "type": "Program", "start": 0, "end": 3, "loc": loc(), "body": body })
+    }
+
+    fn statement(expression: serde_json::Value) -> serde_json::Value {
+        json!({ "type": "ExpressionStatement", "start": 0, "end": 3, "loc": loc(), "expression": expression })
+    }
+
+    fn error_of(value: serde_json::Value) -> EstreeError {
+        from_value(&value).unwrap_err()
+    }
+
+    #[test]
+    fn identifier_statement_is_read() {
+        let value = program(json!([statement(json!({ "type": "Identifier", "start": 0, "end": 3, "loc": loc(), "name": "foo" }))]));
+
+        let program = from_value(&value).unwrap();
+
+        let AstNode::ExpressionStatement(statement) = &program.body[0] else { panic!("Expected an expression statement") };
+        let Expression::Identifier(expression) = &statement.expression else { panic!("Expected an identifier") };
+
+        assert_eq!(expression.identifier.name, "foo");
+        assert_eq!(expression.identifier.span.range, (0, 3));
+        assert_eq!(expression.identifier.span.loc.end.column, 3);
+    }
+
+    #[test]
+    fn regexp_literal_is_read_from_regex_field() {
+        let literal = json!({ "type": "Literal", "start": 0, "end": 3, "loc": loc(), "value": null, "raw": "/a/g", "regex": { "pattern": "a", "flags": "g" } });
+
+        let program = from_value(&program(json!([statement(literal)]))).unwrap();
+
+        let AstNode::ExpressionStatement(statement) = &program.body[0] else { panic!("Expected an expression statement") };
+        let Expression::Literal(expression) = &statement.expression else { panic!("Expected a literal") };
+
+        assert_eq!(expression.value.value, LiteralValue::RegExp { pattern: String::from("a"), flags: String::from("g") });
+    }
+
+    macro_rules! literal_without_raw_tests {
+        ($($name:ident: $literal:expr => $raw:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let mut literal = $literal;
+                literal["type"] = json!("Literal");
+
+                let program = from_value(&program(json!([statement(literal)]))).unwrap();
+
+                let AstNode::ExpressionStatement(statement) = &program.body[0] else { panic!("Expected an expression statement") };
+                let Expression::Literal(expression) = &statement.expression else { panic!("Expected a literal") };
+
+                assert_eq!(expression.value.raw, $raw);
+            }
+        )*
+        };
+    }
+
+    literal_without_raw_tests! {
+        integer_without_raw_has_no_fraction: json!({ "value": 42 }) => "42",
+        float_without_raw_keeps_its_fraction: json!({ "value": 1.5 }) => "1.5",
+        string_without_raw_is_quoted: json!({ "value": "a\"b\n" }) => "\"a\\\"b\\n\"",
+        boolean_without_raw: json!({ "value": true }) => "true",
+        null_without_raw: json!({ "value": null }) => "null",
+        null_raw_is_missing: json!({ "value": false, "raw": null }) => "false",
+        bigint_without_raw_has_its_suffix: json!({ "value": null, "bigint": "10" }) => "10n",
+        regexp_without_raw_has_slashes: json!({ "value": null, "regex": { "pattern": "a+", "flags": "g" } }) => "/a+/g",
+    }
+
+    #[test]
+    fn document_without_locations_is_read() {
+        let value = json!({ "type": "Program", "body": [{
+            "type": "ExpressionStatement",
+            "expression": { "type": "CallExpression", "callee": { "type": "Identifier", "name": "f" }, "arguments": [] },
+        }] });
+
+        let program = from_value(&value).unwrap();
+
+        let AstNode::ExpressionStatement(statement) = &program.body[0] else { panic!("Expected an expression statement") };
+
+        assert!(matches!(statement.expression, Expression::CallExpression(_)));
+        assert_eq!(statement.span.range, (0, 0));
+        assert_eq!(statement.span.loc.start.line, 1);
+        assert_eq!(statement.span.loc.end.column, 0);
+    }
+
+    #[test]
+    fn invalid_offset_is_an_error() {
+        let error = error_of(json!({ "type": "Program", "start": -1, "body": [] }));
+
+        assert_eq!(error.path, "$.start");
+        assert_eq!(error.kind, EstreeErrorKind::InvalidType { expected: "a non-negative integer" });
+    }
+
+    #[test]
+    fn unknown_node_type_has_path() {
+        let error = error_of(program(json!([statement(json!({ "type": "Foobar" }))])));
+
+        assert_eq!(error.path, "$.body[0].expression");
+        assert_eq!(error.kind, EstreeErrorKind::UnknownNodeType(String::from("Foobar")));
+    }
+
+    #[test]
+    fn expression_in_statement_list_is_unexpected() {
+        let error = error_of(program(json!([{ "type": "Identifier", "name": "foo" }])));
+
+        assert_eq!(error.path, "$.body[0]");
+        assert_eq!(error.kind, EstreeErrorKind::UnexpectedNodeType { expected: "a statement", found: String::from("Identifier") });
+        assert_eq!(error.to_string(), "$.body[0]: expected a statement, found `Identifier`");
+    }
+
+    #[test]
+    fn missing_field_has_path_of_node() {
+        let error = error_of(program(json!([statement(json!({ "type": "Identifier", "start": 0, "end": 3, "loc": loc() }))])));
+
+        assert_eq!(error.path, "$.body[0].expression");
+        assert_eq!(error.kind, EstreeErrorKind::MissingField("name"));
+    }
+
+    #[test]
+    fn malformed_location_has_path() {
+        let identifier = json!({ "type": "Identifier", "start": 0, "end": 3, "name": "foo", "loc": { "start": { "line": "1", "column": 0 }, "end": { "line": 1, "column": 3 } } });
+
+        let error = error_of(program(json!([statement(identifier)])));
+
+        assert_eq!(error.path, "$.body[0].expression.loc.start.line");
+        assert_eq!(error.kind, EstreeErrorKind::InvalidType { expected: "a non-negative integer" });
+    }
+
+    #[test]
+    fn unknown_binary_operator_is_an_error() {
+        let identifier = json!({ "type": "Identifier", "start": 0, "end": 1, "loc": loc(), "name": "a" });
+        let binary = json!({ "type": "BinaryExpression", "start": 0, "end": 3, "loc": loc(), "operator": "+=", "left": identifier, "right": identifier });
+
+        let error = error_of(program(json!([statement(binary)])));
+
+        assert_eq!(error.path, "$.body[0].expression.operator");
+        assert_eq!(error.kind, EstreeErrorKind::UnknownOperator(String::from("+=")));
+    }
+
+    #[test]
+    fn for_await_is_unsupported() {
+        let identifier = json!({ "type": "Identifier", "start": 0, "end": 1, "loc": loc(), "name": "a" });
+        let body = json!({ "type": "EmptyStatement", "start": 2, "end": 3, "loc": loc() });
+        let statement = json!({ "type": "ForOfStatement", "start": 0, "end": 3, "loc": loc(), "await": true, "left": identifier, "right": identifier, "body": body });
+
+        let error = error_of(program(json!([statement])));
+
+        assert_eq!(error.path, "$.body[0].await");
+        assert_eq!(error.kind, EstreeErrorKind::Unsupported("`for await` loops"));
+    }
+
+    #[test]
+    fn unsupported_node_is_an_error() {
+        let error = error_of(program(json!([{ "type": "IfStatement" }])));
+
+        assert_eq!(error.kind, EstreeErrorKind::UnexpectedNodeType { expected: "a statement", found: String::from("IfStatement") });
+    }
+
+    #[test]
+    fn comments_are_read() {
+        let mut value = program(json!([]));
+        value["comments"] = json!([{ "type": "Block", "start": 0, "end": 3, "loc": loc(), "value": "*" }]);
+
+        let program = from_value(&value).unwrap();
+
+        assert_eq!(program.comments.len(), 1);
+        assert_eq!(program.comments[0].value, "*");
+    }
+
+    #[test]
+    fn source_type_is_read() {
+        let mut value = program(json!([]));
+        value["sourceType"] = json!("script");
+
+        assert_eq!(from_value(&value).unwrap().source_type, SourceType::Script);
+    }
+
+    #[test]
+    fn unknown_source_type_is_an_error() {
+        let mut value = program(json!([]));
+        value["sourceType"] = json!("commonjs");
+
+        let error = error_of(value);
+
+        assert_eq!(error.path, "$.sourceType");
+        assert_eq!(error.kind, EstreeErrorKind::InvalidType { expected: "`script` or `module`" });
+    }
+
+    #[test]
+    fn invalid_json_is_an_error() {
+        let error = from_json("{").unwrap_err();
+
+        assert_eq!(error.path, "$");
+        assert!(matches!(error.kind, EstreeErrorKind::InvalidJson(_)));
+    }
+}
@@ -0,0 +1,16 @@
+//! ESTree compatible JSON for the AST, see https://github.com/estree/estree
+
+use super::Program;
+
+mod serialize;
+mod deserialize;
+
+pub use self::deserialize::{from_json, from_value, EstreeError, EstreeErrorKind};
+
+pub fn to_json(program: &Program) -> String {
+    serde_json::to_string(program).expect("The AST can always be written as JSON")
+}
+
+pub fn to_json_pretty(program: &Program) -> String {
+    serde_json::to_string_pretty(program).expect("The AST can always be written as JSON")
+}
@@ -0,0 +1,626 @@
+use serde::{Serialize, Serializer, ser::SerializeMap};
+
+use crate::{tokenizer::SourceType, ast::{Program, span::Span, nodes::{AstNode, error::ErrorNode, Literal, LiteralValue, Identifier, Comment, CommentKind, block_statement::BlockStatement, function_declaration::FunctionDeclaration, pattern::{Pattern, ArrayPattern, ObjectPattern, ObjectPatternProperty, AssignmentProperty, RestElement}, jump_statement::{ReturnStatement, ThrowStatement, BreakStatement, ContinueStatement}, for_statement::{ForStatement, ForInit, ForInStatement, ForOfStatement, ForLeft, EmptyStatement}, do_while_statement::DoWhileStatement, variable_declaration::{VariableDeclaration, VariableDeclarator}, expression_statement::{Expression, ExpressionStatement, LiteralExpression, IdentifierExpression, BinaryExpression, LogicalExpression, UpdateExpression, ArrowFunctionExpression, ArrowFunctionBody, CallExpression, MemberExpression, ChainExpression, ObjectExpression, ObjectProperty, Property, ArrayExpression, ExpressionOrSpread, SpreadElement, SequenceExpression}}}};
+
+// Every ESTree node starts with its type and location
+fn serialize_node<S: Serializer>(serializer: S, node_type: &str, span: &Span) -> Result<S::SerializeMap, S::Error> {
+    let mut map = serializer.serialize_map(None)?;
+
+    map.serialize_entry("type", node_type)?;
+    map.serialize_entry("start", &span.range.0)?;
+    map.serialize_entry("end", &span.range.1)?;
+    map.serialize_entry("loc", &span.loc)?;
+
+    Ok(map)
+}
+
+impl Serialize for Program {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "Program", &self.span)?;
+
+        map.serialize_entry("body", &self.body)?;
+        map.serialize_entry("sourceType", match self.source_type {
+            SourceType::Script => "script",
+            SourceType::Module => "module",
+        })?;
+
+        // Comments are only collected when the tokenizer preserves trivia
+        if !self.comments.is_empty() {
+            map.serialize_entry("comments", &self.comments)?;
+        }
+
+        map.end()
+    }
+}
+
+impl Serialize for AstNode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            AstNode::ExpressionStatement(node) => node.serialize(serializer),
+            AstNode::BlockStatement(node) => node.serialize(serializer),
+            AstNode::FunctionDeclaration(node) => node.serialize(serializer),
+            AstNode::VariableDeclaration(node) => node.serialize(serializer),
+            AstNode::ReturnStatement(node) => node.serialize(serializer),
+            AstNode::ThrowStatement(node) => node.serialize(serializer),
+            AstNode::BreakStatement(node) => node.serialize(serializer),
+            AstNode::ContinueStatement(node) => node.serialize(serializer),
+            AstNode::ForStatement(node) => node.serialize(serializer),
+            AstNode::ForInStatement(node) => node.serialize(serializer),
+            AstNode::ForOfStatement(node) => node.serialize(serializer),
+            AstNode::DoWhileStatement(node) => node.serialize(serializer),
+            AstNode::EmptyStatement(node) => node.serialize(serializer),
+            AstNode::Error(node) => node.serialize(serializer),
+        }
+    }
+}
+
+// Not part of ESTree, only a recovered program has these
+impl Serialize for ErrorNode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_node(serializer, "Error", &self.span)?.end()
+    }
+}
+
+impl Serialize for Comment {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let comment_type = match self.kind {
+            CommentKind::Line => "Line",
+            CommentKind::Block => "Block",
+        };
+
+        let mut map = serialize_node(serializer, comment_type, &self.span)?;
+
+        map.serialize_entry("value", &self.value)?;
+        map.end()
+    }
+}
+
+impl Serialize for Identifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "Identifier", &self.span)?;
+
+        map.serialize_entry("name", &self.name)?;
+        map.end()
+    }
+}
+
+impl Serialize for Literal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "Literal", &self.span)?;
+
+        map.serialize_entry("value", &self.value)?;
+        map.serialize_entry("raw", &self.raw)?;
+
+        match &self.value {
+            LiteralValue::RegExp { pattern, flags } => {
+                map.serialize_entry("regex", &RegExpValue { pattern, flags })?;
+            },
+            LiteralValue::BigInt(digits) => {
+                map.serialize_entry("bigint", digits)?;
+            },
+            _ => {},
+        }
+
+        map.end()
+    }
+}
+
+#[derive(Serialize)]
+struct RegExpValue<'a> {
+    pattern: &'a str,
+    flags: &'a str,
+}
+
+// JSON can't hold regular expressions or BigInts, ESTree uses `null` for their value instead
+impl Serialize for LiteralValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            LiteralValue::Number(value) => serialize_number(*value, serializer),
+            LiteralValue::String(value) => serializer.serialize_str(value),
+            LiteralValue::Boolean(value) => serializer.serialize_bool(*value),
+            LiteralValue::Null | LiteralValue::BigInt(_) | LiteralValue::RegExp { .. } => serializer.serialize_none(),
+        }
+    }
+}
+
+// Integral numbers are written without a fraction, the way JavaScript prints them
+fn serialize_number<S: Serializer>(value: f64, serializer: S) -> Result<S::Ok, S::Error> {
+    const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+    if value.fract() == 0.0 && value.abs() <= MAX_SAFE_INTEGER {
+        return serializer.serialize_i64(value as i64);
+    }
+
+    serializer.serialize_f64(value)
+}
+
+impl Serialize for ExpressionStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "ExpressionStatement", &self.span)?;
+
+        map.serialize_entry("expression", &self.expression)?;
+        map.end()
+    }
+}
+
+impl Serialize for BlockStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "BlockStatement", &self.span)?;
+
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl Serialize for FunctionDeclaration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "FunctionDeclaration", &self.span)?;
+
+        map.serialize_entry("id", &self.id)?;
+        map.serialize_entry("expression", &false)?;
+        map.serialize_entry("generator", &false)?;
+        map.serialize_entry("async", &false)?;
+        map.serialize_entry("params", &self.params)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl Serialize for ReturnStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "ReturnStatement", &self.span)?;
+
+        map.serialize_entry("argument", &self.argument)?;
+        map.end()
+    }
+}
+
+impl Serialize for ThrowStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "ThrowStatement", &self.span)?;
+
+        map.serialize_entry("argument", &self.argument)?;
+        map.end()
+    }
+}
+
+impl Serialize for BreakStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "BreakStatement", &self.span)?;
+
+        map.serialize_entry("label", &self.label)?;
+        map.end()
+    }
+}
+
+impl Serialize for ContinueStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "ContinueStatement", &self.span)?;
+
+        map.serialize_entry("label", &self.label)?;
+        map.end()
+    }
+}
+
+impl Serialize for ForStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "ForStatement", &self.span)?;
+
+        map.serialize_entry("init", &self.init)?;
+        map.serialize_entry("test", &self.test)?;
+        map.serialize_entry("update", &self.update)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl Serialize for ForInit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ForInit::VariableDeclaration(declaration) => declaration.serialize(serializer),
+            ForInit::Expression(expression) => expression.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for ForInStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "ForInStatement", &self.span)?;
+
+        map.serialize_entry("left", &self.left)?;
+        map.serialize_entry("right", &self.right)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl Serialize for ForOfStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "ForOfStatement", &self.span)?;
+
+        // `for await` isn't supported
+        map.serialize_entry("await", &false)?;
+        map.serialize_entry("left", &self.left)?;
+        map.serialize_entry("right", &self.right)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl Serialize for ForLeft {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ForLeft::VariableDeclaration(declaration) => declaration.serialize(serializer),
+            ForLeft::Pattern(pattern) => pattern.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for DoWhileStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "DoWhileStatement", &self.span)?;
+
+        map.serialize_entry("body", &self.body)?;
+        map.serialize_entry("test", &self.test)?;
+        map.end()
+    }
+}
+
+impl Serialize for EmptyStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_node(serializer, "EmptyStatement", &self.span)?.end()
+    }
+}
+
+impl Serialize for VariableDeclaration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "VariableDeclaration", &self.span)?;
+
+        map.serialize_entry("declarations", &self.declarations)?;
+        map.serialize_entry("kind", self.kind.as_str())?;
+        map.end()
+    }
+}
+
+impl Serialize for VariableDeclarator {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "VariableDeclarator", &self.span)?;
+
+        map.serialize_entry("id", &self.id)?;
+        map.serialize_entry("init", &self.init)?;
+        map.end()
+    }
+}
+
+impl Serialize for Expression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Expression::Identifier(expression) => expression.serialize(serializer),
+            Expression::Literal(expression) => expression.serialize(serializer),
+            Expression::BinaryExpression(expression) => expression.serialize(serializer),
+            Expression::LogicalExpression(expression) => expression.serialize(serializer),
+            Expression::CallExpression(expression) => expression.serialize(serializer),
+            Expression::MemberExpression(expression) => expression.serialize(serializer),
+            Expression::ChainExpression(expression) => expression.serialize(serializer),
+            Expression::ObjectExpression(expression) => expression.serialize(serializer),
+            Expression::SequenceExpression(expression) => expression.serialize(serializer),
+            Expression::ArrayExpression(expression) => expression.serialize(serializer),
+            Expression::UpdateExpression(expression) => expression.serialize(serializer),
+            Expression::ArrowFunctionExpression(expression) => expression.serialize(serializer),
+        }
+    }
+}
+
+// ESTree has no wrapper nodes, these are written as the node they hold
+impl Serialize for IdentifierExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.identifier.serialize(serializer)
+    }
+}
+
+impl Serialize for LiteralExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+impl Serialize for BinaryExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "BinaryExpression", &self.span)?;
+
+        map.serialize_entry("left", &self.left)?;
+        map.serialize_entry("operator", self.operator.as_str())?;
+        map.serialize_entry("right", &self.right)?;
+        map.end()
+    }
+}
+
+impl Serialize for LogicalExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "LogicalExpression", &self.span)?;
+
+        map.serialize_entry("left", &self.left)?;
+        map.serialize_entry("operator", self.operator.as_str())?;
+        map.serialize_entry("right", &self.right)?;
+        map.end()
+    }
+}
+
+impl Serialize for UpdateExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "UpdateExpression", &self.span)?;
+
+        map.serialize_entry("operator", self.operator.as_str())?;
+        map.serialize_entry("prefix", &self.prefix)?;
+        map.serialize_entry("argument", &self.argument)?;
+        map.end()
+    }
+}
+
+impl Serialize for ArrowFunctionExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "ArrowFunctionExpression", &self.span)?;
+
+        // `expression` tells a body without braces apart from a block
+        map.serialize_entry("id", &None::<Identifier>)?;
+        map.serialize_entry("expression", &matches!(self.body, ArrowFunctionBody::Expression(_)))?;
+        map.serialize_entry("generator", &false)?;
+        map.serialize_entry("async", &false)?;
+        map.serialize_entry("params", &self.params)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl Serialize for ArrowFunctionBody {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ArrowFunctionBody::BlockStatement(body) => body.serialize(serializer),
+            ArrowFunctionBody::Expression(body) => body.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for CallExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "CallExpression", &self.span)?;
+
+        map.serialize_entry("callee", &self.callee)?;
+        map.serialize_entry("arguments", &self.arguments)?;
+        map.serialize_entry("optional", &self.optional)?;
+        map.end()
+    }
+}
+
+impl Serialize for MemberExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "MemberExpression", &self.span)?;
+
+        map.serialize_entry("object", &self.object)?;
+        map.serialize_entry("property", &self.property)?;
+        map.serialize_entry("computed", &self.computed)?;
+        map.serialize_entry("optional", &self.optional)?;
+        map.end()
+    }
+}
+
+impl Serialize for ChainExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "ChainExpression", &self.span)?;
+
+        map.serialize_entry("expression", &self.expression)?;
+        map.end()
+    }
+}
+
+impl Serialize for ObjectExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "ObjectExpression", &self.span)?;
+
+        map.serialize_entry("properties", &self.properties)?;
+        map.end()
+    }
+}
+
+impl Serialize for ObjectProperty {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ObjectProperty::Property(property) => property.serialize(serializer),
+            ObjectProperty::Spread(spread) => spread.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for Property {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "Property", &self.span)?;
+
+        map.serialize_entry("method", &false)?;
+        map.serialize_entry("shorthand", &self.shorthand)?;
+        map.serialize_entry("computed", &self.computed)?;
+        map.serialize_entry("key", &self.key)?;
+        map.serialize_entry("value", &self.value)?;
+        map.serialize_entry("kind", "init")?;
+        map.end()
+    }
+}
+
+impl Serialize for ArrayExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "ArrayExpression", &self.span)?;
+
+        map.serialize_entry("elements", &self.elements)?;
+        map.end()
+    }
+}
+
+impl Serialize for ExpressionOrSpread {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ExpressionOrSpread::Expression(expression) => expression.serialize(serializer),
+            ExpressionOrSpread::Spread(spread) => spread.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for SpreadElement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "SpreadElement", &self.span)?;
+
+        map.serialize_entry("argument", &self.argument)?;
+        map.end()
+    }
+}
+
+impl Serialize for Pattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Pattern::Identifier(identifier) => identifier.serialize(serializer),
+            Pattern::MemberExpression(expression) => expression.serialize(serializer),
+            Pattern::ArrayPattern(pattern) => pattern.serialize(serializer),
+            Pattern::ObjectPattern(pattern) => pattern.serialize(serializer),
+            Pattern::RestElement(element) => element.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for ArrayPattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "ArrayPattern", &self.span)?;
+
+        map.serialize_entry("elements", &self.elements)?;
+        map.end()
+    }
+}
+
+impl Serialize for ObjectPattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "ObjectPattern", &self.span)?;
+
+        map.serialize_entry("properties", &self.properties)?;
+        map.end()
+    }
+}
+
+impl Serialize for ObjectPatternProperty {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ObjectPatternProperty::Property(property) => property.serialize(serializer),
+            ObjectPatternProperty::Rest(rest) => rest.serialize(serializer),
+        }
+    }
+}
+
+// ESTree has no node of its own for it, it's a `Property` with a pattern as its value
+impl Serialize for AssignmentProperty {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "Property", &self.span)?;
+
+        map.serialize_entry("method", &false)?;
+        map.serialize_entry("shorthand", &self.shorthand)?;
+        map.serialize_entry("computed", &self.computed)?;
+        map.serialize_entry("key", &self.key)?;
+        map.serialize_entry("value", &self.value)?;
+        map.serialize_entry("kind", "init")?;
+        map.end()
+    }
+}
+
+impl Serialize for RestElement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "RestElement", &self.span)?;
+
+        map.serialize_entry("argument", &self.argument)?;
+        map.end()
+    }
+}
+
+impl Serialize for SequenceExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serialize_node(serializer, "SequenceExpression", &self.span)?;
+
+        map.serialize_entry("expressions", &self.expressions)?;
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{tokenizer::{Tokenizer, TokenizerOptions, SourceType}, ast::{parse, parse_with_options, ParserOptions}};
+
+    fn to_value(content: &str) -> serde_json::Value {
+        let program = parse(Tokenizer::new(content)).unwrap();
+
+        serde_json::to_value(&program).unwrap()
+    }
+
+    macro_rules! literal_json_tests {
+        ($($name:ident: $value:expr => $expected:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let value = to_value($value);
+                let literal = &value["body"][0]["expression"];
+
+                assert_eq!(literal["type"], "Literal");
+                assert_eq!(literal["raw"], $value);
+                assert_eq!(literal["value"], $expected);
+            }
+        )*
+        };
+    }
+
+    literal_json_tests! {
+        integer_is_written_without_fraction: "42" => json!(42),
+        float_keeps_its_fraction: "1.5" => json!(1.5),
+        hex_number_is_written_as_decimal: "0xFF" => json!(255),
+        string_is_written_cooked: "'a\\nb'" => json!("a\nb"),
+        boolean_is_written_as_boolean: "true" => json!(true),
+        null_is_written_as_null: "null" => json!(null),
+        regexp_value_is_null: "/ab+c/gi" => json!(null),
+        bigint_value_is_null: "10n" => json!(null),
+    }
+
+    #[test]
+    fn regexp_literal_has_regex_field() {
+        let value = to_value("/ab+c/gi");
+
+        assert_eq!(value["body"][0]["expression"]["regex"], json!({ "pattern": "ab+c", "flags": "gi" }));
+    }
+
+    #[test]
+    fn bigint_literal_has_bigint_field() {
+        let value = to_value("0xF_Fn");
+
+        assert_eq!(value["body"][0]["expression"]["bigint"], "0xFF");
+    }
+
+    #[test]
+    fn program_runs_to_the_end_of_the_source() {
+        let value = to_value("foo; // bar\n");
+
+        assert_eq!(value["end"], 12);
+        assert_eq!(value["loc"]["end"], json!({ "line": 2, "column": 0 }));
+    }
+
+    #[test]
+    fn program_has_its_source_type() {
+        let options = TokenizerOptions { source_type: SourceType::Script, ..Default::default() };
+        let program = parse_with_options(Tokenizer::with_options("foo", options), ParserOptions { source_type: options.source_type }).unwrap();
+
+        assert_eq!(to_value("foo")["sourceType"], "module");
+        assert_eq!(serde_json::to_value(&program).unwrap()["sourceType"], "script");
+    }
+
+    #[test]
+    fn node_has_estree_location() {
+        let value = to_value("foo");
+        let identifier = &value["body"][0]["expression"];
+
+        assert_eq!(identifier["start"], 0);
+        assert_eq!(identifier["end"], 3);
+        assert_eq!(identifier["loc"], json!({ "start": { "line": 1, "column": 0 }, "end": { "line": 1, "column": 3 } }));
+    }
+}
@@ -1,41 +1,191 @@
-use crate::tokenizer::Token;
+use std::fmt;
 
-use self::nodes::AstNode;
+use crate::tokenizer::{Token, TokenizeError, SourceType};
+
+use self::{nodes::{AstNode, Comment}, span::{Span, Spanned}};
 
 pub mod nodes;
+pub mod span;
+pub mod visit;
+#[cfg(feature = "serde")]
+pub mod estree;
 mod parsers;
 mod parser;
 
+/// How deep brackets, blocks and operators can be nested, the parser recurses for every level and would overflow the
+/// stack on deeper input
+pub const MAX_NESTING_DEPTH: usize = 256;
+
 #[derive(Debug, PartialEq)]
 pub enum AstErrorType {
     UnexpectedToken,
-    UnexpectedTokenStart,
     UnexpectedEndOfInput,
+    InvalidToken(TokenizeError),
+    /// Brackets, blocks or operators are nested deeper than `MAX_NESTING_DEPTH`
+    NestingTooDeep,
 }
 
-#[derive(Debug)]
-pub struct SearchResult<T> {
-    pub value: T,
-    pub ast_range: (usize, usize)
+impl AstErrorType {
+    /// A stable code to look up or filter the error by, it never changes once released. E1002 is retired, codes aren't
+    /// reused
+    pub fn code(&self) -> &'static str {
+        match self {
+            AstErrorType::UnexpectedToken => "E1001",
+            AstErrorType::UnexpectedEndOfInput => "E1003",
+            AstErrorType::InvalidToken(error) => error.error_type.code(),
+            AstErrorType::NestingTooDeep => "E1004",
+        }
+    }
+}
+
+/// Something the parser would have accepted where the error occurred
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expected {
+    /// A specific token, e.g. `)`
+    Token(&'static str),
+    /// A kind of syntax, e.g. an expression
+    Construct(&'static str),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Token(token) => write!(f, "`{}`", token),
+            Expected::Construct(construct) => f.write_str(construct),
+        }
+    }
+}
+
+/// A note on how to fix the error, optionally pointing at a related part of the source
+#[derive(Debug, Clone, PartialEq)]
+pub struct Help {
+    pub message: String,
+    pub span: Option<Span>,
 }
 
+// The span and help are boxed to keep `Result<_, AstParseError>` small, every parse function returns one
 #[derive(Debug)]
 pub struct AstParseError {
+    /// Index of the offending token in the token stream
     pub index: usize,
-    pub error_type: AstErrorType, 
+    pub error_type: AstErrorType,
+    /// The offending token, or an empty span after the last token at the end of the input.
+    /// Errors from the tokenizer only have the character index of the `TokenizeError`
+    pub span: Option<Box<Span>>,
+    /// The source text of the offending token, `None` at the end of the input
+    pub found: Option<String>,
+    pub expected: Vec<Expected>,
+    pub help: Option<Box<Help>>,
 }
 
+impl AstParseError {
+    pub fn new(index: usize, error_type: AstErrorType) -> Self {
+        AstParseError { index, error_type, span: None, found: None, expected: Vec::new(), help: None }
+    }
+
+    pub fn with_expected(mut self, expected: impl IntoIterator<Item = Expected>) -> Self {
+        self.expected = expected.into_iter().collect();
+        self
+    }
+
+    pub fn with_help(mut self, message: impl Into<String>, span: Option<Span>) -> Self {
+        self.help = Some(Box::new(Help { message: message.into(), span }));
+        self
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.error_type.code()
+    }
+
+    /// Lists what the parser would have accepted, e.g. "expected `)`, `,` or an expression"
+    pub fn expected_message(&self) -> Option<String> {
+        let (last, rest) = self.expected.split_last()?;
+        let rest: Vec<String> = rest.iter().map(Expected::to_string).collect();
+
+        if rest.is_empty() {
+            return Some(format!("expected {}", last));
+        }
+
+        Some(format!("expected {} or {}", rest.join(", "), last))
+    }
+}
+
+impl fmt::Display for AstParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.error_type, &self.found) {
+            (AstErrorType::InvalidToken(error), _) => write!(f, "{}", error.error_type)?,
+            (AstErrorType::NestingTooDeep, _) => write!(f, "Nesting is deeper than {} levels", MAX_NESTING_DEPTH)?,
+            (AstErrorType::UnexpectedEndOfInput, _) | (_, None) => f.write_str("Unexpected end of input")?,
+            (_, Some(found)) => write!(f, "Unexpected token `{}`", found)?,
+        }
+
+        match self.expected_message() {
+            Some(expected) => write!(f, ", {}", expected),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for AstParseError {}
+
 #[derive(Debug)]
 pub struct Program {
-    pub body: Vec<AstNode>
+    pub body: Vec<AstNode>,
+    /// Every comment in the source, only filled when the tokenizer preserves trivia
+    pub comments: Vec<Comment>,
+    /// Runs from the start to the end of the source, trailing whitespace and comments included
+    pub span: Span,
+    pub source_type: SourceType,
 }
 
-impl Default for Program {
-    fn default() -> Self {
-        Self { body: Default::default() }
-    }
+impl Spanned for Program {
+    fn span(&self) -> &Span { &self.span }
+}
+
+/// A program that has been parsed as far as possible, with every error that was recovered from
+#[derive(Debug)]
+pub struct RecoveredProgram {
+    pub program: Program,
+    pub diagnostics: Vec<AstParseError>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParserOptions {
+    /// Has to match the `source_type` the tokens were read with
+    pub source_type: SourceType,
+}
+
+/// Parses a stream of tokens, usually a `Tokenizer`, reading tokens as the parser needs them
+pub fn parse<'a, I>(tokens: I) -> Result<Program, AstParseError>
+where
+    I: IntoIterator<Item = Result<Token<'a>, TokenizeError>>,
+    I::IntoIter: 'a,
+{
+    parser::parse(tokens, ParserOptions::default())
+}
+
+pub fn parse_with_options<'a, I>(tokens: I, options: ParserOptions) -> Result<Program, AstParseError>
+where
+    I: IntoIterator<Item = Result<Token<'a>, TokenizeError>>,
+    I::IntoIter: 'a,
+{
+    parser::parse(tokens, options)
+}
+
+/// Parses a stream of tokens, statements with errors become `Error` nodes and parsing continues at the
+/// next statement. The errors of a tokenizer that recovers from them are diagnostics too
+pub fn parse_recovering<'a, I>(tokens: I) -> RecoveredProgram
+where
+    I: IntoIterator<Item = Result<Token<'a>, TokenizeError>>,
+    I::IntoIter: 'a,
+{
+    parser::parse_recovering(tokens, ParserOptions::default())
 }
 
-pub fn parse(tokens: &Vec<Token>) -> Result<Program, AstParseError> {
-    parser::parse(tokens)
+pub fn parse_recovering_with_options<'a, I>(tokens: I, options: ParserOptions) -> RecoveredProgram
+where
+    I: IntoIterator<Item = Result<Token<'a>, TokenizeError>>,
+    I::IntoIter: 'a,
+{
+    parser::parse_recovering(tokens, options)
 }
@@ -1,7 +1,13 @@
+use crate::ast::span::{Span, Spanned};
+
 use super::AstNode;
 
 #[derive(Debug)]
 pub struct BlockStatement {
     pub body: Vec<AstNode>,
-    pub range: (usize, usize)
+    pub span: Span,
+}
+
+impl Spanned for BlockStatement {
+    fn span(&self) -> &Span { &self.span }
 }
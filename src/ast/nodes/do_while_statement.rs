@@ -0,0 +1,15 @@
+use crate::ast::span::{Span, Spanned};
+
+use super::{AstNode, expression_statement::Expression};
+
+/// `do body while (test)`, the body runs once before the test is checked
+#[derive(Debug)]
+pub struct DoWhileStatement {
+    pub body: Box<AstNode>,
+    pub test: Expression,
+    pub span: Span,
+}
+
+impl Spanned for DoWhileStatement {
+    fn span(&self) -> &Span { &self.span }
+}
@@ -0,0 +1,11 @@
+use crate::ast::span::{Span, Spanned};
+
+/// Takes the place of a statement that couldn't be parsed, covering the tokens skipped while recovering
+#[derive(Debug)]
+pub struct ErrorNode {
+    pub span: Span,
+}
+
+impl Spanned for ErrorNode {
+    fn span(&self) -> &Span { &self.span }
+}
@@ -1,36 +1,145 @@
-use super::{Literal, Identifier};
+use crate::{ast::span::{Span, Spanned}, tokenizer::Operator};
+
+use super::{Literal, Identifier, block_statement::BlockStatement, pattern::Pattern};
 
 #[derive(Debug)]
 pub struct LiteralExpression {
     pub value: Literal,
 }
 
+/// `in` and `instanceof` are keywords, every other binary operator is an operator token
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinaryOperator {
+    Operator(Operator),
+    In,
+    Instanceof,
+}
+
+impl BinaryOperator {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BinaryOperator::Operator(operator) => operator.as_str(),
+            BinaryOperator::In => "in",
+            BinaryOperator::Instanceof => "instanceof",
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct BinaryExpression {
-    pub operator: String,
+    pub operator: BinaryOperator,
+    pub left: Box<Expression>,
+    pub right: Box<Expression>,
+    pub span: Span,
+}
+
+/// `&&`, `||` and `??`, the right operand is only evaluated depending on the value of the left one
+#[derive(Debug)]
+pub struct LogicalExpression {
+    pub operator: Operator,
     pub left: Box<Expression>,
     pub right: Box<Expression>,
+    pub span: Span,
+}
+
+/// `++` or `--` before or after its argument, the prefix form evaluates to the updated value
+#[derive(Debug)]
+pub struct UpdateExpression {
+    pub operator: Operator,
+    pub prefix: bool,
+    pub argument: Box<Expression>,
+    pub span: Span,
+}
+
+/// `params => body`, the body is a single expression when it isn't wrapped in braces
+#[derive(Debug)]
+pub struct ArrowFunctionExpression {
+    pub params: Vec<Pattern>,
+    pub body: ArrowFunctionBody,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub enum ArrowFunctionBody {
+    BlockStatement(Box<BlockStatement>),
+    Expression(Box<Expression>),
 }
 
 #[derive(Debug)]
 pub struct CallExpression {
-    pub callee: Identifier,
-    pub arguments: Vec<Expression>,
+    pub callee: Box<Expression>,
+    pub arguments: Vec<ExpressionOrSpread>,
+    /// Called with `?.()`, the call is skipped when the callee is null or undefined
+    pub optional: bool,
+    pub span: Span,
+}
+
+/// `object.property` or `object[property]` when it's `computed`, the property of the first form is an identifier
+#[derive(Debug)]
+pub struct MemberExpression {
+    pub object: Box<Expression>,
+    pub property: Box<Expression>,
+    pub computed: bool,
+    /// Accessed with `?.`, the access is skipped when the object is null or undefined
+    pub optional: bool,
+    pub span: Span,
+}
+
+/// Wraps a chain of member accesses and calls that has at least one optional link, e.g. `a?.b.c()`. The chain
+/// evaluates to undefined as a whole once an optional link is skipped
+#[derive(Debug)]
+pub struct ChainExpression {
+    pub expression: Box<Expression>,
+    pub span: Span,
+}
+
+/// `...argument` in a call, array or object literal, the values of the argument are spread into the list it's in
+#[derive(Debug)]
+pub struct SpreadElement {
+    pub argument: Box<Expression>,
+    pub span: Span,
+}
+
+/// An argument of a call or an element of an array literal
+#[derive(Debug)]
+pub enum ExpressionOrSpread {
+    Expression(Expression),
+    Spread(SpreadElement),
+}
+
+/// `key: value` in an object literal, the value is a copy of the key when it's `shorthand`, e.g. `{ a }`
+#[derive(Debug)]
+pub struct Property {
+    pub key: Box<Expression>,
+    pub value: Box<Expression>,
+    pub computed: bool,
+    pub shorthand: bool,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub enum ObjectProperty {
+    Property(Property),
+    Spread(SpreadElement),
 }
 
 #[derive(Debug)]
 pub struct ObjectExpression {
-    
+    pub properties: Vec<ObjectProperty>,
+    pub span: Span,
 }
 
 #[derive(Debug)]
 pub struct ArrayExpression {
-
+    /// A hole is `None`, e.g. the second element of `[a, , b]`
+    pub elements: Vec<Option<ExpressionOrSpread>>,
+    pub span: Span,
 }
 
 #[derive(Debug)]
 pub struct SequenceExpression {
-    pub expressions: Vec<Expression>
+    pub expressions: Vec<Expression>,
+    pub span: Span,
 }
 
 #[derive(Debug)]
@@ -43,16 +152,126 @@ pub enum Expression {
     Identifier(IdentifierExpression),
     Literal(LiteralExpression),
     BinaryExpression(BinaryExpression),
+    LogicalExpression(LogicalExpression),
     CallExpression(CallExpression),
+    MemberExpression(MemberExpression),
+    ChainExpression(ChainExpression),
     ObjectExpression(ObjectExpression),
     SequenceExpression(SequenceExpression),
     ArrayExpression(ArrayExpression),
-    // MemberExpression(MemberExpression)
-    // UpdateExpression(UpdateExpression),
+    UpdateExpression(UpdateExpression),
+    ArrowFunctionExpression(ArrowFunctionExpression),
 }
 
 #[derive(Debug)]
 pub struct ExpressionStatement {
     pub expression: Expression,
-    pub range: (usize, usize),
+    pub span: Span,
+}
+
+// Literal and identifier expressions share the span of the node they wrap
+impl Spanned for LiteralExpression {
+    fn span(&self) -> &Span { &self.value.span }
+}
+
+impl Spanned for IdentifierExpression {
+    fn span(&self) -> &Span { &self.identifier.span }
+}
+
+impl Spanned for BinaryExpression {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for LogicalExpression {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for UpdateExpression {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for ArrowFunctionExpression {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for ArrowFunctionBody {
+    fn span(&self) -> &Span {
+        match self {
+            ArrowFunctionBody::BlockStatement(body) => body.span(),
+            ArrowFunctionBody::Expression(body) => body.span(),
+        }
+    }
+}
+
+impl Spanned for CallExpression {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for MemberExpression {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for ChainExpression {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for SpreadElement {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for ExpressionOrSpread {
+    fn span(&self) -> &Span {
+        match self {
+            ExpressionOrSpread::Expression(expression) => expression.span(),
+            ExpressionOrSpread::Spread(spread) => spread.span(),
+        }
+    }
+}
+
+impl Spanned for Property {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for ObjectProperty {
+    fn span(&self) -> &Span {
+        match self {
+            ObjectProperty::Property(property) => property.span(),
+            ObjectProperty::Spread(spread) => spread.span(),
+        }
+    }
+}
+
+impl Spanned for ObjectExpression {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for ArrayExpression {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for SequenceExpression {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for Expression {
+    fn span(&self) -> &Span {
+        match self {
+            Expression::Identifier(expression) => expression.span(),
+            Expression::Literal(expression) => expression.span(),
+            Expression::BinaryExpression(expression) => expression.span(),
+            Expression::LogicalExpression(expression) => expression.span(),
+            Expression::CallExpression(expression) => expression.span(),
+            Expression::MemberExpression(expression) => expression.span(),
+            Expression::ChainExpression(expression) => expression.span(),
+            Expression::ObjectExpression(expression) => expression.span(),
+            Expression::SequenceExpression(expression) => expression.span(),
+            Expression::ArrayExpression(expression) => expression.span(),
+            Expression::UpdateExpression(expression) => expression.span(),
+            Expression::ArrowFunctionExpression(expression) => expression.span(),
+        }
+    }
+}
+
+impl Spanned for ExpressionStatement {
+    fn span(&self) -> &Span { &self.span }
 }
@@ -0,0 +1,85 @@
+use crate::ast::span::{Span, Spanned};
+
+use super::{AstNode, pattern::Pattern, variable_declaration::VariableDeclaration, expression_statement::Expression};
+
+/// The classic `for (init; test; update)` loop, every part of the header can be left out
+#[derive(Debug)]
+pub struct ForStatement {
+    pub init: Option<ForInit>,
+    pub test: Option<Expression>,
+    pub update: Option<Expression>,
+    pub body: Box<AstNode>,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub enum ForInit {
+    VariableDeclaration(VariableDeclaration),
+    Expression(Expression),
+}
+
+/// `for (left in right)`, loops over the enumerable property names of an object
+#[derive(Debug)]
+pub struct ForInStatement {
+    pub left: ForLeft,
+    pub right: Expression,
+    pub body: Box<AstNode>,
+    pub span: Span,
+}
+
+/// `for (left of right)`, loops over the values of an iterable
+#[derive(Debug)]
+pub struct ForOfStatement {
+    pub left: ForLeft,
+    pub right: Expression,
+    pub body: Box<AstNode>,
+    pub span: Span,
+}
+
+/// What each value of a `for…in` or `for…of` loop is assigned to, a declaration of a single variable without an
+/// initializer or an existing variable or property
+#[derive(Debug)]
+pub enum ForLeft {
+    VariableDeclaration(VariableDeclaration),
+    Pattern(Pattern),
+}
+
+/// A `;` where a statement is expected, e.g. the body of `for (;;);`
+#[derive(Debug)]
+pub struct EmptyStatement {
+    pub span: Span,
+}
+
+impl Spanned for ForStatement {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for ForInit {
+    fn span(&self) -> &Span {
+        match self {
+            ForInit::VariableDeclaration(declaration) => declaration.span(),
+            ForInit::Expression(expression) => expression.span(),
+        }
+    }
+}
+
+impl Spanned for ForInStatement {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for ForOfStatement {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for ForLeft {
+    fn span(&self) -> &Span {
+        match self {
+            ForLeft::VariableDeclaration(declaration) => declaration.span(),
+            ForLeft::Pattern(pattern) => pattern.span(),
+        }
+    }
+}
+
+impl Spanned for EmptyStatement {
+    fn span(&self) -> &Span { &self.span }
+}
@@ -1,9 +1,15 @@
-use super::{Identifier, block_statement::BlockStatement};
+use crate::ast::span::{Span, Spanned};
+
+use super::{Identifier, block_statement::BlockStatement, pattern::Pattern};
 
 #[derive(Debug)]
 pub struct FunctionDeclaration {
     pub id: Identifier,
-    pub params: Vec<Identifier>,
+    pub params: Vec<Pattern>,
     pub body: BlockStatement,
-    pub range: (usize, usize)
+    pub span: Span,
+}
+
+impl Spanned for FunctionDeclaration {
+    fn span(&self) -> &Span { &self.span }
 }
@@ -0,0 +1,43 @@
+use crate::ast::span::{Span, Spanned};
+
+use super::{Identifier, expression_statement::Expression};
+
+#[derive(Debug)]
+pub struct ReturnStatement {
+    pub argument: Option<Expression>,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct ThrowStatement {
+    pub argument: Expression,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct BreakStatement {
+    pub label: Option<Identifier>,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct ContinueStatement {
+    pub label: Option<Identifier>,
+    pub span: Span,
+}
+
+impl Spanned for ReturnStatement {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for ThrowStatement {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for BreakStatement {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for ContinueStatement {
+    fn span(&self) -> &Span { &self.span }
+}
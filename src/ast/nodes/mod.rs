@@ -1,27 +1,85 @@
-use crate::tokenizer::{Token, FileLocation};
+use crate::tokenizer::{Token, TokenType, Literal as TokenLiteral, Trivia, TriviaKind};
 
-use self::{expression_statement::ExpressionStatement, block_statement::BlockStatement, function_declaration::FunctionDeclaration};
+use super::span::{Span, Spanned};
+
+use self::{expression_statement::ExpressionStatement, block_statement::BlockStatement, function_declaration::FunctionDeclaration, variable_declaration::VariableDeclaration, jump_statement::{ReturnStatement, ThrowStatement, BreakStatement, ContinueStatement}, for_statement::{ForStatement, ForInStatement, ForOfStatement, EmptyStatement}, do_while_statement::DoWhileStatement, error::ErrorNode};
 
 pub mod expression_statement;
 pub mod block_statement;
 pub mod variable_declaration;
+pub mod pattern;
 pub mod function_declaration;
+pub mod jump_statement;
+pub mod for_statement;
+pub mod do_while_statement;
+pub mod error;
+
+#[derive(Debug, PartialEq)]
+pub enum LiteralValue {
+    Number(f64),
+    /// The digits of the BigInt without the `n` suffix and separators, e.g. `0xFF` for `0xF_Fn`
+    BigInt(String),
+    String(String),
+    Boolean(bool),
+    Null,
+    RegExp { pattern: String, flags: String },
+}
+
+impl LiteralValue {
+    fn from_token(token: &Token<'_>) -> Self {
+        let raw = token.raw_value;
+
+        match token.token_type {
+            TokenType::Literal(TokenLiteral::Number) => LiteralValue::Number(parse_number(raw)),
+            TokenType::Literal(TokenLiteral::BigInt) => LiteralValue::BigInt(raw[..raw.len() - 1].replace('_', "")),
+            TokenType::Literal(TokenLiteral::Boolean) => LiteralValue::Boolean(raw == "true"),
+            TokenType::Literal(TokenLiteral::Null) => LiteralValue::Null,
+            TokenType::Literal(TokenLiteral::RegExp) => {
+                // Flags never contain a `/`, so the last one ends the pattern
+                let end = raw.rfind('/').unwrap_or(raw.len());
+
+                LiteralValue::RegExp {
+                    pattern: raw[1..end].to_string(),
+                    flags: raw[end + 1..].to_string(),
+                }
+            },
+            _ => LiteralValue::String(token.value.to_string()),
+        }
+    }
+}
+
+// https://262.ecma-international.org/13.0/#sec-numericvalue
+fn parse_number(raw: &str) -> f64 {
+    let digits = raw.replace('_', "");
+
+    let (radix, digits) = match digits.get(..2) {
+        Some("0x" | "0X") => (16, &digits[2..]),
+        Some("0o" | "0O") => (8, &digits[2..]),
+        Some("0b" | "0B") => (2, &digits[2..]),
+        // A legacy octal literal, e.g. `010`. With an 8 or a 9 the leading zero is ignored, `08` is 8
+        _ if digits.len() > 1 && digits.starts_with('0') && digits.chars().all(|digit| digit.is_digit(8)) => (8, &digits[1..]),
+        _ => return digits.parse().unwrap_or(f64::NAN),
+    };
+
+    // Folding into a float also works for numbers that don't fit in a u64
+    digits.chars()
+        .filter_map(|digit| digit.to_digit(radix))
+        .fold(0.0, |value, digit| value * radix as f64 + digit as f64)
+}
 
 #[derive(Debug)]
 pub struct Literal {
-    pub value: String,
+    pub value: LiteralValue,
     pub raw: String,
-    pub range: (usize, usize),
-    pub loc: FileLocation,
+    pub span: Span,
 }
 
-impl From<&Token> for Literal {
-    fn from(token: &Token) -> Self {
+impl From<&Token<'_>> for Literal {
+    fn from(token: &Token<'_>) -> Self {
         Self {
-            value: token.value.clone(),
-            range: token.range.clone(),
-            raw: token.raw_value.clone(),
-            loc: token.loc.clone(),
+            value: LiteralValue::from_token(token),
+            raw: token.raw_value.to_string(),
+            span: Span::from(token),
         }
     }
 }
@@ -29,23 +87,138 @@ impl From<&Token> for Literal {
 #[derive(Debug)]
 pub struct Identifier {
     pub name: String,
-    pub range: (usize, usize),
-    pub loc: FileLocation,
+    pub span: Span,
 }
 
-impl From<&Token> for Identifier {
-    fn from(token: &Token) -> Self {
+impl From<&Token<'_>> for Identifier {
+    fn from(token: &Token<'_>) -> Self {
         Self {
-            name: token.value.clone(),
-            range: token.range.clone(),
-            loc: token.loc.clone(),
+            name: token.value.to_string(),
+            span: Span::from(token),
         }
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub enum CommentKind {
+    Line,
+    Block,
+}
+
+#[derive(Debug)]
+pub struct Comment {
+    pub kind: CommentKind,
+    /// The comment text without its delimiters
+    pub value: String,
+    pub span: Span,
+}
+
+impl Comment {
+    pub fn from_trivia(trivia: &Trivia<'_>) -> Option<Self> {
+        // Hashbang and HTML-like comments run until the end of the line, like line comments
+        let kind = match trivia.kind {
+            TriviaKind::BlockComment => CommentKind::Block,
+            TriviaKind::Whitespace | TriviaKind::LineTerminator => return None,
+            _ => CommentKind::Line,
+        };
+
+        Some(Self {
+            kind,
+            value: trivia.value().to_string(),
+            span: Span::from(trivia),
+        })
+    }
+}
+
 #[derive(Debug)]
 pub enum AstNode {
     ExpressionStatement(ExpressionStatement),
     BlockStatement(BlockStatement),
     FunctionDeclaration(FunctionDeclaration),
+    VariableDeclaration(VariableDeclaration),
+    ReturnStatement(ReturnStatement),
+    ThrowStatement(ThrowStatement),
+    BreakStatement(BreakStatement),
+    ContinueStatement(ContinueStatement),
+    ForStatement(ForStatement),
+    ForInStatement(ForInStatement),
+    ForOfStatement(ForOfStatement),
+    DoWhileStatement(DoWhileStatement),
+    EmptyStatement(EmptyStatement),
+    Error(ErrorNode),
+}
+
+impl Spanned for Literal {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for Identifier {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for Comment {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for AstNode {
+    fn span(&self) -> &Span {
+        match self {
+            AstNode::ExpressionStatement(node) => node.span(),
+            AstNode::BlockStatement(node) => node.span(),
+            AstNode::FunctionDeclaration(node) => node.span(),
+            AstNode::VariableDeclaration(node) => node.span(),
+            AstNode::ReturnStatement(node) => node.span(),
+            AstNode::ThrowStatement(node) => node.span(),
+            AstNode::BreakStatement(node) => node.span(),
+            AstNode::ContinueStatement(node) => node.span(),
+            AstNode::ForStatement(node) => node.span(),
+            AstNode::ForInStatement(node) => node.span(),
+            AstNode::ForOfStatement(node) => node.span(),
+            AstNode::DoWhileStatement(node) => node.span(),
+            AstNode::EmptyStatement(node) => node.span(),
+            AstNode::Error(node) => node.span(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tokenizer::Tokenizer;
+
+    use super::{Literal, LiteralValue};
+
+    macro_rules! literal_value_tests {
+        ($($name:ident: $input:expr => $expected:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = String::from($input);
+                let token = Tokenizer::new(&input).next().unwrap().unwrap();
+
+                let literal = Literal::from(&token);
+
+                assert_eq!(literal.value, $expected);
+                assert_eq!(literal.raw, $input);
+            }
+        )*
+        };
+    }
+
+    literal_value_tests! {
+        integer_literal: "42" => LiteralValue::Number(42.0),
+        decimal_literal: "1.5e3" => LiteralValue::Number(1500.0),
+        hex_literal: "0xF_F" => LiteralValue::Number(255.0),
+        octal_literal: "0o17" => LiteralValue::Number(15.0),
+        binary_literal: "0b101" => LiteralValue::Number(5.0),
+        legacy_octal_literal: "010" => LiteralValue::Number(8.0),
+        non_octal_decimal_literal: "08" => LiteralValue::Number(8.0),
+        non_octal_decimal_with_fraction: "09.5" => LiteralValue::Number(9.5),
+        big_int_literal: "1_000n" => LiteralValue::BigInt(String::from("1000")),
+        hex_big_int_literal: "0xFFn" => LiteralValue::BigInt(String::from("0xFF")),
+        string_literal: "'a\\tb'" => LiteralValue::String(String::from("a\tb")),
+        true_literal: "true" => LiteralValue::Boolean(true),
+        false_literal: "false" => LiteralValue::Boolean(false),
+        null_literal: "null" => LiteralValue::Null,
+        regexp_literal: "/a[/]b/gi" => LiteralValue::RegExp { pattern: String::from("a[/]b"), flags: String::from("gi") },
+    }
 }
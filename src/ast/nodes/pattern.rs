@@ -0,0 +1,88 @@
+use crate::ast::span::{Span, Spanned};
+
+use super::{Identifier, expression_statement::{Expression, MemberExpression}};
+
+/// The target a value is bound to, e.g. a function parameter. A property can only be assigned to, not declared
+#[derive(Debug)]
+pub enum Pattern {
+    Identifier(Identifier),
+    MemberExpression(MemberExpression),
+    ArrayPattern(ArrayPattern),
+    ObjectPattern(ObjectPattern),
+    RestElement(RestElement),
+}
+
+/// `[a, , ...rest]`, binds the values of an iterable by their position
+#[derive(Debug)]
+pub struct ArrayPattern {
+    /// A hole is `None`, its value is skipped
+    pub elements: Vec<Option<Pattern>>,
+    pub span: Span,
+}
+
+/// `{ a, b: c, ...rest }`, binds the properties of an object by their key
+#[derive(Debug)]
+pub struct ObjectPattern {
+    pub properties: Vec<ObjectPatternProperty>,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub enum ObjectPatternProperty {
+    Property(AssignmentProperty),
+    Rest(RestElement),
+}
+
+/// `key: value` in an object pattern, the value is a binding named after the key when it's `shorthand`, e.g. `{ a }`
+#[derive(Debug)]
+pub struct AssignmentProperty {
+    pub key: Box<Expression>,
+    pub value: Box<Pattern>,
+    pub computed: bool,
+    pub shorthand: bool,
+    pub span: Span,
+}
+
+/// `...argument` as the last parameter or element, it's bound to the remaining values
+#[derive(Debug)]
+pub struct RestElement {
+    pub argument: Box<Pattern>,
+    pub span: Span,
+}
+
+impl Spanned for Pattern {
+    fn span(&self) -> &Span {
+        match self {
+            Pattern::Identifier(identifier) => identifier.span(),
+            Pattern::MemberExpression(expression) => expression.span(),
+            Pattern::ArrayPattern(pattern) => pattern.span(),
+            Pattern::ObjectPattern(pattern) => pattern.span(),
+            Pattern::RestElement(element) => element.span(),
+        }
+    }
+}
+
+impl Spanned for ArrayPattern {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for ObjectPattern {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for ObjectPatternProperty {
+    fn span(&self) -> &Span {
+        match self {
+            ObjectPatternProperty::Property(property) => property.span(),
+            ObjectPatternProperty::Rest(rest) => rest.span(),
+        }
+    }
+}
+
+impl Spanned for AssignmentProperty {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for RestElement {
+    fn span(&self) -> &Span { &self.span }
+}
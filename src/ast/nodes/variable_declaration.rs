@@ -1,8 +1,43 @@
-use super::{Identifier, expression_statement::Expression};
+use crate::ast::span::{Span, Spanned};
 
+use super::{pattern::Pattern, expression_statement::Expression};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VariableKind {
+    Var,
+    Let,
+    Const,
+}
+
+impl VariableKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VariableKind::Var => "var",
+            VariableKind::Let => "let",
+            VariableKind::Const => "const",
+        }
+    }
+}
+
+/// `var`, `let` or `const` with one or more declarators, e.g. `let a = 1, b`
 #[derive(Debug)]
 pub struct VariableDeclaration {
-    pub id: Identifier,
-    pub init: Expression,
-    pub range: (usize, usize)
+    pub kind: VariableKind,
+    pub declarations: Vec<VariableDeclarator>,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct VariableDeclarator {
+    pub id: Pattern,
+    pub init: Option<Expression>,
+    pub span: Span,
+}
+
+impl Spanned for VariableDeclaration {
+    fn span(&self) -> &Span { &self.span }
+}
+
+impl Spanned for VariableDeclarator {
+    fn span(&self) -> &Span { &self.span }
 }
@@ -1,18 +1,107 @@
-use crate::tokenizer::{Token, TokenType, Separator};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::tokenizer::{Token, TokenType, Separator, Keyword, TokenizeError, FileLocation, FileLocationPos};
+
+use super::{AstParseError, Expected, MAX_NESTING_DEPTH, ParserOptions, Program, RecoveredProgram, AstErrorType, span::Span, nodes::{AstNode, Comment, error::ErrorNode}, parsers::{expression_statements::parse_expression_statement, block_statements::{is_closed_block_statement, is_open_block_statement, parse_block_statement}, function_declaration::{is_function_declaration, parse_function_declaration}, jump_statements::{is_jump_statement, parse_jump_statement}, for_statements::{is_for_statement, parse_for_statement}, do_while_statements::{is_do_while_statement, parse_do_while_statement}, variable_declaration::{is_variable_declaration, parse_variable_statement}, util::{is_semicolon_terminator, parse_empty_statement}}};
+
+type TokenSource<'a> = Box<dyn Iterator<Item = Result<Token<'a>, TokenizeError>> + 'a>;
+
+/// Tokens that have been read from the source but haven't been released yet
+struct TokenBuffer<'a> {
+    source: TokenSource<'a>,
+    tokens: VecDeque<Rc<Token<'a>>>,
+    /// Index of the first buffered token in the token stream
+    offset: usize,
+    /// Tokenizer errors with the index of the token they belong to. The stream ends at an error unless the tokenizer
+    /// recovers from it, then the `Invalid` token that covers it has that index
+    errors: Vec<(usize, TokenizeError)>,
+    /// Comments from the trivia of every token read so far
+    comments: Vec<Comment>,
+    /// Span of the last token read from the source
+    last_span: Option<Span>,
+    /// Where the source ends, once it has been read to the end
+    end: Option<Span>,
+}
+
+impl<'a> TokenBuffer<'a> {
+    fn get(&mut self, index: usize) -> Option<Rc<Token<'a>>> {
+        if index < self.offset { return None }
+
+        while self.offset + self.tokens.len() <= index {
+            match self.source.next() {
+                Some(Ok(token)) => {
+                    let trivia = token.leading_trivia.iter().chain(token.trailing_trivia.iter());
+                    self.comments.extend(trivia.filter_map(Comment::from_trivia));
+
+                    // Only there to hand over trivia and mark the end, it isn't part of the program
+                    if token.token_type == TokenType::EndOfInput {
+                        self.end = Some(Span::from(&token));
+                        return None;
+                    }
+
+                    self.last_span = Some(Span::from(&token));
+
+                    self.tokens.push_back(Rc::new(token));
+                },
+                Some(Err(error)) => self.errors.push((self.offset + self.tokens.len(), error)),
+                None => return None,
+            }
+        }
 
-use super::{AstParseError, Program, AstErrorType, nodes::AstNode, parsers::{expression_statements::{parse_expression_statement, is_expression_statement}, block_statements::{is_closed_block_statement, is_open_block_statement, parse_block_statement}, function_declaration::{is_function_declaration, parse_function_declaration}, util::is_semicolon_terminator}};
+        self.tokens.get(index - self.offset).cloned()
+    }
+
+    fn release(&mut self, index: usize) {
+        while self.offset < index && !self.tokens.is_empty() {
+            self.tokens.pop_front();
+            self.offset += 1;
+        }
+    }
+}
 
-#[derive(Debug)]
 pub struct AstParser<'a> {
     index: usize,
-    tokens: &'a Vec<Token>
+    tokens: RefCell<TokenBuffer<'a>>,
+    /// Turns statements that fail to parse into error nodes instead of stopping
+    recover: bool,
+    diagnostics: Vec<AstParseError>,
+    /// How many nested constructs are being parsed, see `nested`
+    depth: usize,
+}
+
+impl fmt::Debug for AstParser<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AstParser")
+            .field("index", &self.index)
+            .field("buffered", &self.tokens.borrow().tokens.len())
+            .finish()
+    }
 }
 
 impl<'a> AstParser<'a> {
-    pub fn new(tokens: &'a Vec<Token>) -> AstParser {
-        AstParser { 
+    /// Creates a parser that pulls tokens from the source when they're needed
+    pub fn new<I>(tokens: I) -> AstParser<'a>
+    where
+        I: IntoIterator<Item = Result<Token<'a>, TokenizeError>>,
+        I::IntoIter: 'a,
+    {
+        AstParser {
             index: 0,
-            tokens
+            tokens: RefCell::new(TokenBuffer {
+                source: Box::new(tokens.into_iter()),
+                tokens: VecDeque::new(),
+                offset: 0,
+                errors: Vec::new(),
+                comments: Vec::new(),
+                last_span: None,
+                end: None,
+            }),
+            recover: false,
+            diagnostics: Vec::new(),
+            depth: 0,
         }
     }
 
@@ -20,139 +109,689 @@ impl<'a> AstParser<'a> {
         self.token().is_some()
     }
 
-    pub fn token(&self) -> Option<&Token> {
-        self.tokens.get(self.index)
+    pub fn token(&self) -> Option<Rc<Token<'a>>> {
+        self.token_at(self.index)
     }
 
-    pub fn token_at(&self, index: usize) -> Option<&Token> {
-        self.tokens.get(index)
+    /// Looks ahead in the token stream, tokens before the previous token have been released
+    pub fn token_at(&self, index: usize) -> Option<Rc<Token<'a>>> {
+        self.tokens.borrow_mut().get(index)
     }
 
-    pub fn peek_back(&self) -> Option<&Token> {
-        self.tokens.get(self.index - 1)
+    pub fn peek_back(&self) -> Option<Rc<Token<'a>>> {
+        self.token_at(self.index.checked_sub(1)?)
     }
 
     pub fn get_current_index(&self) -> usize {
-        return self.index;
+        self.index
     }
 
     pub fn consume_range(&mut self, range: usize) {
         self.index += range;
+        self.release();
     }
 
     pub fn next(&mut self) {
         self.index +=1;
+        self.release();
     }
 
-    pub fn can_insert_automatic_semicolon(&self, index: usize) -> bool {
-        // https://262.ecma-international.org/13.0/#sec-rules-of-automatic-semicolon-insertion
-        // TODO: missing "The previous token is ) and the inserted semicolon would then be parsed as the terminating semicolon of a do-while statement (14.7.2)."
-        let current_token   = self.tokens.get(index - 1);
-        let offending_token = self.tokens.get(index);
+    // Only the previous token is still needed once the parser has moved on
+    fn release(&mut self) {
+        let index = self.index.saturating_sub(1);
+        self.tokens.get_mut().release(index);
+    }
 
-        if current_token.is_none() || offending_token.is_none() { return false; }
+    /// Runs `parse` one level deeper, nesting beyond `MAX_NESTING_DEPTH` is an error instead of a stack overflow
+    pub fn nested<T>(&mut self, parse: impl FnOnce(&mut Self) -> Result<T, AstParseError>) -> Result<T, AstParseError> {
+        if self.depth == MAX_NESTING_DEPTH {
+            return Err(self.error_at(self.index, AstErrorType::NestingTooDeep));
+        }
 
-        let current_token   = current_token.unwrap();
-        let offending_token = offending_token.unwrap();
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
 
-        let offending_token_is_on_a_different_line = || {
-            current_token.loc.end.line != offending_token.loc.start.line
-        };
+        result
+    }
 
-        let offending_token_is_closing_bracket = || {
-            matches!(offending_token.token_type, TokenType::Separator(Separator::CurlyBrace)) && offending_token.raw_value == "}"
-        };
+    fn take_comments(&mut self) -> Vec<Comment> {
+        std::mem::take(&mut self.tokens.get_mut().comments)
+    }
 
-        offending_token_is_on_a_different_line() || offending_token_is_closing_bracket()
+    /// Builds an error pointing at the token at `index`, or just after the last token when the input ends before it
+    pub fn error_at(&self, index: usize, error_type: AstErrorType) -> AstParseError {
+        let mut error = AstParseError::new(index, error_type);
+
+        match self.token_at(index) {
+            Some(token) => {
+                error.span = Some(Box::new(Span::from(&*token)));
+                error.found = Some(token.raw_value.to_string());
+            },
+            None => {
+                error.span = self.tokens.borrow().last_span.as_ref().map(|last| Box::new(Span {
+                    range: (last.range.1, last.range.1),
+                    loc: FileLocation { start: last.loc.end.clone(), end: last.loc.end.clone() },
+                }));
+
+                if error.error_type == AstErrorType::UnexpectedToken {
+                    error.error_type = AstErrorType::UnexpectedEndOfInput;
+                }
+            },
+        }
+
+        error
+    }
+
+    /// The span of the source, or of everything read so far when it wasn't read to the end
+    fn read_span(&self) -> Span {
+        let start = FileLocationPos { line: 1, column: 0 };
+        let tokens = self.tokens.borrow();
+
+        match tokens.end.as_ref().or(tokens.last_span.as_ref()) {
+            Some(last) => Span {
+                range: (0, last.range.1),
+                loc: FileLocation { start, end: last.loc.end.clone() },
+            },
+            None => Span { range: (0, 0), loc: FileLocation { start: start.clone(), end: start } },
+        }
+    }
+
+    /// Takes the tokenizer errors the parser got to, as errors at the index of the token they belong to
+    fn take_tokenize_errors(&mut self) -> Vec<AstParseError> {
+        let errors = std::mem::take(&mut self.tokens.get_mut().errors);
+
+        errors.into_iter().map(|(index, error)| AstParseError::new(index, AstErrorType::InvalidToken(error))).collect()
+    }
+
+    /// Whether a line terminator, or a comment holding one, separates the token at `index` from the token before it
+    pub fn has_line_terminator_before(&self, index: usize) -> bool {
+        let Some(previous) = index.checked_sub(1).and_then(|index| self.token_at(index)) else { return false };
+
+        match self.token_at(index) {
+            Some(token) => previous.loc.end.line != token.loc.start.line,
+            None => false,
+        }
+    }
+
+    /// Whether a semicolon can be inserted before the token at `index`, the offending token. Callers have to parse as
+    /// far as the grammar allows first, an offending token is a token the grammar doesn't allow at that point.
+    ///
+    /// Restricted productions check `has_line_terminator_before` themselves: the jump statements, postfix `++` and
+    /// `--`, which leave `a\n++b` as `a; ++b`, and the `=>` of an arrow function. A semicolon is never inserted within
+    /// a `for` header or as an empty statement, and the one after a do-while statement is optional even without a
+    /// line break, those are up to the parsers of these statements.
+    pub fn can_insert_automatic_semicolon(&self, index: usize) -> bool {
+        // https://262.ecma-international.org/13.0/#sec-rules-of-automatic-semicolon-insertion
+        match self.token_at(index) {
+            Some(token) => {
+                matches!(token.token_type, TokenType::Separator(Separator::CloseCurlyBrace)) || self.has_line_terminator_before(index)
+            },
+            // The end of the input always ends a statement
+            None => true,
+        }
     }
 
     fn parse(&mut self) -> Result<AstNode, AstParseError> {
-        if is_function_declaration(&self) {
+        if is_semicolon_terminator(self) {
+            return Ok(AstNode::EmptyStatement(parse_empty_statement(self)));
+        }
+
+        if is_open_block_statement(self) {
+            let block_statement = parse_block_statement(self)?;
+            return Ok(AstNode::BlockStatement(block_statement));
+        }
+
+        if is_function_declaration(self) {
             let function_declaration = parse_function_declaration(self)?;
             return Ok(AstNode::FunctionDeclaration(function_declaration));
         }
 
-        if is_expression_statement(&self) {
-            let expression_statement = parse_expression_statement(self)?;
-            return Ok(AstNode::ExpressionStatement(expression_statement));
+        if is_variable_declaration(self) {
+            let variable_declaration = parse_variable_statement(self)?;
+            return Ok(AstNode::VariableDeclaration(variable_declaration));
+        }
+
+        if is_jump_statement(self) {
+            return parse_jump_statement(self);
+        }
+
+        if is_for_statement(self) {
+            return parse_for_statement(self);
         }
 
-        return Err(AstParseError {
-            index: self.get_current_index(),
-            error_type: AstErrorType::UnexpectedToken
-        })
+        if is_do_while_statement(self) {
+            let do_while_statement = parse_do_while_statement(self)?;
+            return Ok(AstNode::DoWhileStatement(do_while_statement));
+        }
+
+        parse_expression_statement(self).map(AstNode::ExpressionStatement)
     }
 
-    pub fn parse_program(&mut self) -> Result<Vec<AstNode>, AstParseError> {
-        let mut body: Vec<AstNode> = Vec::new();
+    /// Parses the next statement, a failed statement becomes an error node when the parser recovers. There has to be
+    /// a token left
+    pub fn parse_statement(&mut self) -> Result<AstNode, AstParseError> {
+        let start_index = self.get_current_index();
+        let start = Span::from(&*self.token().unwrap());
 
-        while self.has_tokens() {
-            if is_semicolon_terminator(&self) {
-                self.next();
-                continue;
-            }
+        let result = if is_closed_block_statement(self) {
+            Err(self.error_at(start_index, AstErrorType::UnexpectedToken).with_expected([Expected::Construct("a statement")]))
+        } else {
+            self.nested(|parser| parser.parse())
+        };
 
-            if is_closed_block_statement(self) {
-                return Err(AstParseError {
-                    index: self.get_current_index(),
-                    error_type: AstErrorType::UnexpectedToken,
-                });
+        match result {
+            Err(error) if self.recover => {
+                self.diagnostics.push(error);
+                self.synchronize(start_index);
+
+                let end = self.peek_back().map(|token| Span::from(&*token)).unwrap_or_else(|| start.clone());
+
+                Ok(AstNode::Error(ErrorNode { span: Span::between(&start, &end) }))
+            },
+            result => result,
+        }
+    }
+
+    // Panic mode recovery, skips tokens until the start of the next statement
+    fn synchronize(&mut self, start_index: usize) {
+        // The statement failed on its first token, skip it so the parser always moves on
+        if self.get_current_index() == start_index && self.skip_balanced() {
+            return;
+        }
+
+        while let Some(token) = self.token() {
+            match token.token_type {
+                TokenType::Separator(Separator::Terminator) => {
+                    self.next();
+                    return;
+                },
+                TokenType::Separator(Separator::CloseCurlyBrace) => return,
+                TokenType::Keyword(keyword) if is_statement_keyword(keyword) => return,
+                _ => if self.skip_balanced() { return },
             }
+        }
+    }
+
+    // Skips a token, or a whole `{ ... }` when it's an opening brace so braces within it can't end the
+    // enclosing block. Returns whether a brace was skipped, which ends the statement like a `;`
+    fn skip_balanced(&mut self) -> bool {
+        let mut depth = 0;
 
-            if is_open_block_statement(self) {
-                let block = parse_block_statement(self)?;
-                let block_statement = AstNode::BlockStatement(block);
+        while let Some(token) = self.token() {
+            self.next();
 
-                body.push(block_statement);
-                continue;
+            match token.token_type {
+                TokenType::Separator(Separator::OpenCurlyBrace) => depth += 1,
+                TokenType::Separator(Separator::CloseCurlyBrace) => depth -= 1,
+                _ => {},
             }
-    
-            let node = self.parse()?;
+
+            if depth <= 0 { return matches!(token.token_type, TokenType::Separator(Separator::CloseCurlyBrace)); }
+        }
+
+        false
+    }
+
+    pub fn parse_program(&mut self) -> Result<Vec<AstNode>, AstParseError> {
+        let mut body: Vec<AstNode> = Vec::new();
+
+        while self.has_tokens() {
+            let node = self.parse_statement()?;
             body.push(node);
         }
 
         Ok(body)
     }
 
-    pub fn parse_block(&mut self) -> Result<Vec<AstNode>, AstParseError> {
+    /// Parses the statements of a block up to and including its closing brace, `start` is the span of the opening brace
+    pub fn parse_block(&mut self, start: &Span) -> Result<Vec<AstNode>, AstParseError> {
         let mut body = Vec::new();
 
         while self.has_tokens() {
-            if is_semicolon_terminator(&self) {
-                self.next();
-                continue;
-            }
-
             if is_closed_block_statement(self) {
                 self.next();
                 return Ok(body);
             }
 
-            if is_open_block_statement(self) {
-                let block = parse_block_statement(self)?;
-                let block_statement = AstNode::BlockStatement(block);
-
-                body.push(block_statement);
-                continue;
-            }
-    
-            let node = self.parse()?;
+            let node = self.parse_statement()?;
             body.push(node);
         }
 
-        return Err(AstParseError {
-            index: self.get_current_index(),
-            error_type: AstErrorType::UnexpectedEndOfInput
-        })
+        let error = self.error_at(self.get_current_index(), AstErrorType::UnexpectedEndOfInput)
+            .with_expected([Expected::Token("}")])
+            .with_help("missing `}` to close block started here", Some(start.clone()));
+
+        // Keep what has been parsed of an unclosed block
+        if self.recover {
+            self.diagnostics.push(error);
+            return Ok(body);
+        }
+
+        Err(error)
+    }
+
+}
+
+// Keywords that can only start a statement, recovery resumes at these
+fn is_statement_keyword(keyword: Keyword) -> bool {
+    matches!(keyword,
+        Keyword::Break | Keyword::Class | Keyword::Const | Keyword::Continue | Keyword::Debugger | Keyword::Do |
+        Keyword::Export | Keyword::For | Keyword::Function | Keyword::If | Keyword::Import | Keyword::Return |
+        Keyword::Switch | Keyword::Throw | Keyword::Try | Keyword::Var | Keyword::While | Keyword::With
+    )
+}
+
+pub fn parse<'a, I>(tokens: I, options: ParserOptions) -> Result<Program, AstParseError>
+where
+    I: IntoIterator<Item = Result<Token<'a>, TokenizeError>>,
+    I::IntoIter: 'a,
+{
+    let mut parser = AstParser::new(tokens);
+    let result = parser.parse_program();
+
+    // Once the parser had to read up to an invalid token, that is where parsing actually failed
+    if let Some(error) = parser.take_tokenize_errors().into_iter().next() {
+        return Err(error);
     }
 
+    let comments = parser.take_comments();
+    let span = parser.read_span();
+
+    Ok(Program { body: result?, comments, span, source_type: options.source_type })
 }
 
-pub fn parse(tokens: &Vec<Token>) -> Result<Program, AstParseError> {
+pub fn parse_recovering<'a, I>(tokens: I, options: ParserOptions) -> RecoveredProgram
+where
+    I: IntoIterator<Item = Result<Token<'a>, TokenizeError>>,
+    I::IntoIter: 'a,
+{
     let mut parser = AstParser::new(tokens);
-    let mut program = Program::default();
+    parser.recover = true;
+
+    // Every error is turned into a diagnostic while recovering
+    let body = parser.parse_program().unwrap_or_default();
+    let mut diagnostics = std::mem::take(&mut parser.diagnostics);
+
+    // The parser trips over the token a tokenizer error belongs to, or over the end of the tokens when the tokenizer
+    // stopped there. The tokenizer's error says what's actually wrong
+    let tokenize_errors = parser.take_tokenize_errors();
+
+    diagnostics.retain(|diagnostic| tokenize_errors.iter().all(|error| error.index != diagnostic.index));
+    diagnostics.extend(tokenize_errors);
+    diagnostics.sort_by_key(|diagnostic| diagnostic.index);
 
-    program.body = parser.parse_program()?;
+    let comments = parser.take_comments();
+    let span = parser.read_span();
 
-    Ok(program)
+    RecoveredProgram { program: Program { body, comments, span, source_type: options.source_type }, diagnostics }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use crate::{tokenizer::{Tokenizer, TokenizerOptions, TokenErrorType}, source::{SourceFile, OffsetEncoding}, ast::{parse, parse_recovering, AstErrorType, Expected, MAX_NESTING_DEPTH, span::Spanned, nodes::{CommentKind, AstNode, expression_statement::Expression}}};
+
+    use super::{AstParser, AstParseError};
+
+    #[test]
+    fn parser_only_reads_the_tokens_it_needs() {
+        let content = String::from("foo bar baz");
+        let read = Rc::new(Cell::new(0));
+        let counter = read.clone();
+
+        let parser = AstParser::new(Tokenizer::new(&content).inspect(move |_| counter.set(counter.get() + 1)));
+
+        parser.token();
+        assert_eq!(read.get(), 1);
+
+        parser.token_at(1);
+        assert_eq!(read.get(), 2);
+    }
+
+    #[test]
+    fn parser_releases_consumed_tokens() {
+        let content = String::from("a b c d e");
+        let mut parser = AstParser::new(Tokenizer::new(&content));
+
+        parser.token_at(4);
+        parser.consume_range(3);
+
+        assert!(parser.token_at(1).is_none());
+        assert_eq!(parser.peek_back().unwrap().value, "c");
+        assert_eq!(parser.token().unwrap().value, "d");
+    }
+
+    #[test]
+    fn tokenize_error_is_returned_from_parse() {
+        let content = String::from("foo(1);\nfoo(#);");
+
+        let error = parse(Tokenizer::new(&content)).unwrap_err();
+
+        assert_eq!(error.index, 7);
+
+        match error.error_type {
+            AstErrorType::InvalidToken(error) => {
+                assert_eq!(error.error_type, TokenErrorType::UnexpectedToken);
+                assert_eq!(error.index, 12);
+            },
+            _ => panic!("Expected a tokenize error"),
+        }
+    }
+
+    #[test]
+    fn parse_error_before_invalid_token_is_returned() {
+        let content = String::from("} #");
+
+        let error = parse(Tokenizer::new(&content)).unwrap_err();
+
+        assert_eq!(error.index, 0);
+        assert_eq!(error.error_type, AstErrorType::UnexpectedToken);
+    }
+
+    #[test]
+    fn program_has_comments_when_trivia_is_preserved() {
+        let content = String::from("/** Docs */\nfoo(1); // Call\n");
+        let options = TokenizerOptions { preserve_trivia: true, ..Default::default() };
+
+        let program = parse(Tokenizer::with_options(&content, options)).unwrap();
+
+        assert_eq!(program.body.len(), 1);
+        assert_eq!(program.comments.len(), 2);
+
+        assert_eq!(program.comments[0].kind, CommentKind::Block);
+        assert_eq!(program.comments[0].value, "* Docs ");
+        assert_eq!(program.comments[0].span.range, (0, 11));
+
+        assert_eq!(program.comments[1].kind, CommentKind::Line);
+        assert_eq!(program.comments[1].value, " Call");
+        assert_eq!(program.comments[1].span.range, (20, 27));
+        assert_eq!(program.comments[1].span.loc.start.line, 2);
+    }
+
+    macro_rules! trailing_comment_tests {
+        ($($name:ident: $content:expr => $statements:expr, $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let options = TokenizerOptions { preserve_trivia: true, ..Default::default() };
+
+                let program = parse(Tokenizer::with_options($content, options)).unwrap();
+
+                assert_eq!(program.body.len(), $statements);
+                assert_eq!(program.comments.len(), 1);
+                assert_eq!(program.comments[0].value, $value);
+            }
+        )*
+        };
+    }
+
+    trailing_comment_tests! {
+        comment_without_statements_is_kept: "// only" => 0, " only",
+        comment_after_the_last_statement_is_kept: "a; /* trailing */" => 1, " trailing ",
+    }
+
+    #[test]
+    fn program_runs_to_the_end_of_the_source() {
+        let content = String::from("foo;\n// end\n");
+
+        let program = parse(Tokenizer::new(&content)).unwrap();
+
+        assert_eq!(program.span.range, (0, content.len()));
+        assert_eq!((program.span.loc.end.line, program.span.loc.end.column), (3, 0));
+    }
+
+    #[test]
+    fn program_has_no_comments_by_default() {
+        let content = String::from("// Comment\nfoo(1);");
+
+        let program = parse(Tokenizer::new(&content)).unwrap();
+
+        assert!(program.comments.is_empty());
+    }
+
+    fn source_text<'a>(node: &impl Spanned, source: &SourceFile<'a>) -> &'a str {
+        node.span().source_text(source, OffsetEncoding::Char)
+    }
+
+    #[test]
+    fn statements_map_back_to_source_text() {
+        let content = String::from("1 + 2 - 3;\nfoo(a, 'b');\n{ bar; }\nfunction baz(a) { a; }");
+        let source = SourceFile::new(&content);
+
+        let program = parse(Tokenizer::new(&content)).unwrap();
+        let texts: Vec<_> = program.body.iter().map(|node| source_text(node, &source)).collect();
+
+        assert_eq!(texts, vec!["1 + 2 - 3;", "foo(a, 'b');", "{ bar; }", "function baz(a) { a; }"]);
+        assert_eq!(source_text(&program, &source), content);
+    }
+
+    #[test]
+    fn expressions_map_back_to_source_text() {
+        let content = String::from("1 + 2 - 3;\nfoo(a, 'b');");
+        let source = SourceFile::new(&content);
+
+        let program = parse(Tokenizer::new(&content)).unwrap();
+
+        let expressions: Vec<_> = program.body.iter().map(|node| match node {
+            AstNode::ExpressionStatement(statement) => &statement.expression,
+            _ => panic!("Expected an expression statement"),
+        }).collect();
+
+        let Expression::BinaryExpression(binary) = expressions[0] else { panic!("Expected a binary expression") };
+        assert_eq!(source_text(binary, &source), "1 + 2 - 3");
+        assert_eq!(source_text(binary.left.as_ref(), &source), "1 + 2");
+
+        let Expression::CallExpression(call) = expressions[1] else { panic!("Expected a call expression") };
+        assert_eq!(source_text(call, &source), "foo(a, 'b')");
+        assert_eq!(source_text(&call.arguments[1], &source), "'b'");
+        assert_eq!(call.loc().start.line, 2);
+        assert_eq!(call.loc().end.column, 11);
+    }
+
+    fn node_kinds(body: &[AstNode]) -> Vec<&'static str> {
+        body.iter().map(|node| match node {
+            AstNode::ExpressionStatement(_) => "ExpressionStatement",
+            AstNode::BlockStatement(_) => "BlockStatement",
+            AstNode::FunctionDeclaration(_) => "FunctionDeclaration",
+            AstNode::VariableDeclaration(_) => "VariableDeclaration",
+            AstNode::ReturnStatement(_) => "ReturnStatement",
+            AstNode::ThrowStatement(_) => "ThrowStatement",
+            AstNode::BreakStatement(_) => "BreakStatement",
+            AstNode::ContinueStatement(_) => "ContinueStatement",
+            AstNode::ForStatement(_) => "ForStatement",
+            AstNode::ForInStatement(_) => "ForInStatement",
+            AstNode::ForOfStatement(_) => "ForOfStatement",
+            AstNode::DoWhileStatement(_) => "DoWhileStatement",
+            AstNode::EmptyStatement(_) => "EmptyStatement",
+            AstNode::Error(_) => "Error",
+        }).collect()
+    }
+
+    #[test]
+    fn recovering_parser_reports_every_error() {
+        let content = String::from("foo(1;\nbar;\n) baz;\nqux;");
+
+        let result = parse_recovering(Tokenizer::new(&content));
+        let indexes: Vec<_> = result.diagnostics.iter().map(|diagnostic| diagnostic.index).collect();
+
+        assert_eq!(node_kinds(&result.program.body), vec!["Error", "ExpressionStatement", "Error", "ExpressionStatement"]);
+        assert_eq!(indexes, vec![3, 6]);
+        assert_eq!(result.program.body[0].range(), (0, 6));
+        assert_eq!(result.program.body[2].range(), (12, 18));
+    }
+
+    #[test]
+    fn recovering_parser_keeps_statements_within_a_block() {
+        let content = String::from("{\n  foo(;\n  bar;\n}\nbaz;");
+
+        let result = parse_recovering(Tokenizer::new(&content));
+
+        assert_eq!(node_kinds(&result.program.body), vec!["BlockStatement", "ExpressionStatement"]);
+        assert_eq!(result.diagnostics.len(), 1);
+
+        let AstNode::BlockStatement(block) = &result.program.body[0] else { panic!("Expected a block statement") };
+        assert_eq!(node_kinds(&block.body), vec!["Error", "ExpressionStatement"]);
+    }
+
+    #[test]
+    fn recovery_resumes_at_a_statement_keyword() {
+        let content = String::from("1 2 function foo() {\n}");
+
+        let result = parse_recovering(Tokenizer::new(&content));
+
+        assert_eq!(node_kinds(&result.program.body), vec!["Error", "FunctionDeclaration"]);
+        assert_eq!(result.program.body[0].range(), (0, 3));
+    }
+
+    #[test]
+    fn recovery_skips_a_block_as_a_whole() {
+        let content = String::from("function foo(a {\n  bar;\n}\nbaz;");
+
+        let result = parse_recovering(Tokenizer::new(&content));
+
+        assert_eq!(node_kinds(&result.program.body), vec!["Error", "ExpressionStatement"]);
+        assert_eq!(result.program.body[0].range(), (0, 25));
+    }
+
+    #[test]
+    fn stray_closing_brace_is_an_error_node() {
+        let content = String::from("}\nfoo;");
+
+        let result = parse_recovering(Tokenizer::new(&content));
+
+        assert_eq!(node_kinds(&result.program.body), vec!["Error", "ExpressionStatement"]);
+        assert_eq!(result.diagnostics[0].error_type, AstErrorType::UnexpectedToken);
+    }
+
+    #[test]
+    fn unclosed_block_is_kept() {
+        let content = String::from("foo;\n{ bar;");
+
+        let result = parse_recovering(Tokenizer::new(&content));
+
+        assert_eq!(node_kinds(&result.program.body), vec!["ExpressionStatement", "BlockStatement"]);
+        assert_eq!(result.diagnostics[0].error_type, AstErrorType::UnexpectedEndOfInput);
+    }
+
+    #[test]
+    fn recovering_parser_continues_after_invalid_tokens() {
+        let content = String::from("foo;\nbar #;\nbaz;");
+        let options = TokenizerOptions { recover_errors: true, ..Default::default() };
+
+        let result = parse_recovering(Tokenizer::with_options(&content, options));
+
+        assert_eq!(node_kinds(&result.program.body), vec!["ExpressionStatement", "Error", "ExpressionStatement"]);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].index, 3);
+        assert_eq!(result.diagnostics[0].to_string(), "Unexpected character");
+    }
+
+    #[test]
+    fn recovering_parser_keeps_every_tokenize_error() {
+        let content = String::from("foo(;\nbar @;\n'baz\n/* qux");
+        let options = TokenizerOptions { recover_errors: true, ..Default::default() };
+
+        let result = parse_recovering(Tokenizer::with_options(&content, options));
+
+        let codes: Vec<_> = result.diagnostics.iter().map(|diagnostic| diagnostic.code()).collect();
+        assert_eq!(codes, vec!["E1001", "E0001", "E0002", "E0007"]);
+    }
+
+    #[test]
+    fn recovering_parser_stops_at_a_tokenize_error() {
+        let content = String::from("foo;\nbar #;\nbaz;");
+
+        let result = parse_recovering(Tokenizer::new(&content));
+
+        assert_eq!(result.diagnostics.len(), 1);
+        assert!(matches!(result.diagnostics[0].error_type, AstErrorType::InvalidToken(_)));
+    }
+
+    #[test]
+    fn unclosed_call_points_at_the_offending_token() {
+        let content = String::from("foo(1;");
+
+        let error = parse(Tokenizer::new(&content)).unwrap_err();
+        let help = error.help.as_ref().unwrap();
+
+        assert_eq!(error.index, 3);
+        assert_eq!(error.code(), "E1001");
+        assert_eq!(error.span.as_ref().unwrap().range, (5, 6));
+        assert_eq!(error.found.as_deref(), Some(";"));
+        assert_eq!(error.expected, vec![Expected::Token(")"), Expected::Token(",")]);
+        assert_eq!(help.message, "missing `)` to close call started here");
+        assert_eq!(help.span.as_ref().unwrap().range, (3, 4));
+        assert_eq!(error.to_string(), "Unexpected token `;`, expected `)` or `,`");
+    }
+
+    #[test]
+    fn unclosed_block_points_past_the_last_token() {
+        let content = String::from("{\nfoo;");
+
+        let error = parse(Tokenizer::new(&content)).unwrap_err();
+        let span = error.span.as_ref().unwrap();
+
+        assert_eq!(error.error_type, AstErrorType::UnexpectedEndOfInput);
+        assert_eq!(error.code(), "E1003");
+        assert_eq!(span.range, (6, 6));
+        assert_eq!((span.loc.start.line, span.loc.start.column), (2, 4));
+        assert_eq!(error.found, None);
+        assert_eq!(error.help.as_ref().unwrap().span.as_ref().unwrap().range, (0, 1));
+        assert_eq!(error.to_string(), "Unexpected end of input, expected `}`");
+    }
+
+    #[test]
+    fn missing_function_name_expects_an_identifier() {
+        let content = String::from("function (a) {}");
+
+        let error = parse(Tokenizer::new(&content)).unwrap_err();
+
+        assert_eq!(error.found.as_deref(), Some("("));
+        assert_eq!(error.to_string(), "Unexpected token `(`, expected an identifier");
+    }
+
+    #[test]
+    fn unended_statement_expects_a_semicolon() {
+        let content = String::from("foo bar;");
+
+        let error = parse(Tokenizer::new(&content)).unwrap_err();
+
+        assert_eq!(error.index, 1);
+        assert_eq!(error.expected, vec![Expected::Token(";")]);
+    }
+
+    #[test]
+    fn tokenize_errors_keep_their_code() {
+        let content = String::from("foo('bar);");
+
+        let error = parse(Tokenizer::new(&content)).unwrap_err();
+
+        assert_eq!(error.code(), "E0002");
+        assert_eq!(error.to_string(), TokenErrorType::UnterminatedStringLiteral.to_string());
+    }
+
+    // Nesting every kind of bracket and block up to the limit takes more stack than a test thread has in a debug build
+    fn parse_nested(depth: usize) -> Result<(), AstParseError> {
+        let content = format!("{}a{}; {}{}", "([".repeat(depth), "])".repeat(depth), "{".repeat(depth), "}".repeat(depth));
+
+        std::thread::Builder::new().stack_size(64 << 20)
+            .spawn(move || parse(Tokenizer::new(&content)).map(|_| ()))
+            .unwrap().join().unwrap()
+    }
+
+    #[test]
+    fn nesting_within_the_limit_is_parsed() {
+        assert!(parse_nested(MAX_NESTING_DEPTH / 2 - 1).is_ok());
+    }
+
+    #[test]
+    fn nesting_deeper_than_the_limit_is_an_error() {
+        let error = parse_nested(MAX_NESTING_DEPTH / 2).unwrap_err();
+
+        assert_eq!(error.error_type, AstErrorType::NestingTooDeep);
+        assert_eq!(error.code(), "E1004");
+        assert_eq!(error.to_string(), format!("Nesting is deeper than {} levels", MAX_NESTING_DEPTH));
+    }
 }
@@ -1,47 +1,44 @@
-use crate::{ast::{parser::AstParser, nodes::block_statement::BlockStatement, AstParseError, AstErrorType}, tokenizer::{Separator, TokenType}, ast_error};
+use crate::{ast::{span::Span, parser::AstParser, nodes::block_statement::BlockStatement, AstParseError, AstErrorType, Expected}, tokenizer::{Separator, TokenType}, ast_error};
+
+use super::{get_current_token_span, get_previous_token_span};
 
 pub fn is_open_block_statement(parser: &AstParser) -> bool {
     let token = parser.token().unwrap();
 
-    let is_type = matches!(token.token_type, TokenType::Separator(Separator::CurlyBrace));
-    let is_value = token.value == "{";
-
-    is_type && is_value
+    matches!(token.token_type, TokenType::Separator(Separator::OpenCurlyBrace))
 }
 
 pub fn is_closed_block_statement(parser: &AstParser) -> bool {
     let token = parser.token().unwrap();
 
-    let is_type = matches!(token.token_type, TokenType::Separator(Separator::CurlyBrace));
-    let is_value = token.value == "}";
-
-    is_type && is_value
+    matches!(token.token_type, TokenType::Separator(Separator::CloseCurlyBrace))
 }
 
 pub fn parse_block_statement(parser: &mut AstParser) -> Result<BlockStatement, AstParseError> {
-    if !is_open_block_statement(&parser) {
-        return ast_error!(AstErrorType::UnexpectedToken, parser);
+    if !is_open_block_statement(parser) {
+        return ast_error!(AstErrorType::UnexpectedToken, parser, parser.get_current_index(), [Expected::Token("{")]);
     }
 
-    let start = parser.token().unwrap().range.0;
+    let start = get_current_token_span(parser)?;
 
     // Skip the current opening block
     parser.next();
 
     // Fetch all expressions within the block
-    let body = parser.parse_block()?;
+    let body = parser.parse_block(&start)?;
 
-    let end = parser.peek_back().unwrap().range.1;
+    // The closing brace has been consumed by the block
+    let end = get_previous_token_span(parser)?;
 
     Ok(BlockStatement {
         body,
-        range: (start, end)
+        span: Span::between(&start, &end),
     })
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{tokenizer, ast::{parser::AstParser, parsers::block_statements::{is_open_block_statement, is_closed_block_statement}, AstErrorType}};
+    use crate::{tokenizer::Tokenizer, ast::{parser::AstParser, parsers::block_statements::{is_open_block_statement, is_closed_block_statement}, nodes::AstNode, AstErrorType}};
 
     use super::parse_block_statement;
 
@@ -49,56 +46,62 @@ mod tests {
     fn open_bracket_is_open_block_statement() {
         let content = String::from("{");
 
-        let tokens = tokenizer::parse(&content).unwrap();
-        let parser = AstParser::new(&tokens);
+        let parser = AstParser::new(Tokenizer::new(&content));
 
         let result = is_open_block_statement(&parser);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
     fn closed_bracket_is_not_open_block_statement() {
         let content = String::from("}");
 
-        let tokens = tokenizer::parse(&content).unwrap();
-        let parser = AstParser::new(&tokens);
+        let parser = AstParser::new(Tokenizer::new(&content));
 
         let result = is_open_block_statement(&parser);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
     fn closed_bracket_is_closed_block_statement() {
         let content = String::from("}");
 
-        let tokens = tokenizer::parse(&content).unwrap();
-        let parser = AstParser::new(&tokens);
+        let parser = AstParser::new(Tokenizer::new(&content));
 
         let result = is_closed_block_statement(&parser);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
     fn open_bracket_is_not_closed_block_statement() {
         let content = String::from("{");
 
-        let tokens = tokenizer::parse(&content).unwrap();
-        let parser = AstParser::new(&tokens);
+        let parser = AstParser::new(Tokenizer::new(&content));
 
         let result = is_closed_block_statement(&parser);
 
-        assert_eq!(result, false);
+        assert!(!result);
+    }
+
+    #[test]
+    fn semicolons_in_a_block_are_empty_statements() {
+        let content = String::from("{ ; 123; }");
+
+        let mut parser = AstParser::new(Tokenizer::new(&content));
+
+        let block = parse_block_statement(&mut parser).unwrap();
+
+        assert!(matches!(block.body[..], [AstNode::EmptyStatement(_), AstNode::ExpressionStatement(_)]));
     }
 
     #[test]
     fn block_with_literal_is_block_statement() {
         let content = String::from("{ 123 }");
 
-        let tokens = tokenizer::parse(&content).unwrap();
-        let mut parser = AstParser::new(&tokens);
+        let mut parser = AstParser::new(Tokenizer::new(&content));
 
         let block = parse_block_statement(&mut parser).unwrap();
 
@@ -109,8 +112,7 @@ mod tests {
     fn block_with_subblock_with_literal_is_block_statement() {
         let content = String::from("{ { 123 } }");
 
-        let tokens = tokenizer::parse(&content).unwrap();
-        let mut parser = AstParser::new(&tokens);
+        let mut parser = AstParser::new(Tokenizer::new(&content));
 
         let block = parse_block_statement(&mut parser).unwrap();
 
@@ -121,8 +123,7 @@ mod tests {
     fn block_without_closing_tag_throws_an_error() {
         let content = String::from("{ { 123 }");
 
-        let tokens = tokenizer::parse(&content).unwrap();
-        let mut parser = AstParser::new(&tokens);
+        let mut parser = AstParser::new(Tokenizer::new(&content));
 
         let err = parse_block_statement(&mut parser).unwrap_err();
 
@@ -0,0 +1,120 @@
+use crate::{ast::{span::Span, parser::AstParser, nodes::do_while_statement::DoWhileStatement, AstParseError, AstErrorType, Expected}, tokenizer::{TokenType, Keyword, Separator}};
+
+use super::{get_current_token_span, get_previous_token_span, expressions::parse_expression, util::{is_separator, parse_loop_body}};
+
+pub fn is_do_while_statement(parser: &AstParser) -> bool {
+    match parser.token() {
+        Some(token) => matches!(token.token_type, TokenType::Keyword(Keyword::Do)),
+        None => false,
+    }
+}
+
+// The `;` after the condition is optional, a semicolon is inserted there even without a line break, so
+// `do x; while (y) z` is two statements
+// https://262.ecma-international.org/13.0/#sec-rules-of-automatic-semicolon-insertion
+pub fn parse_do_while_statement(parser: &mut AstParser) -> Result<DoWhileStatement, AstParseError> {
+    let start = get_current_token_span(parser)?;
+
+    // Skip the keyword
+    parser.next();
+
+    let body = parse_loop_body(parser)?;
+
+    if !matches!(parser.token(), Some(token) if token.token_type == TokenType::Keyword(Keyword::While)) {
+        let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+            .with_expected([Expected::Token("while")])
+            .with_help("missing `while` to end the `do` loop started here", Some(start));
+
+        return Err(error);
+    }
+
+    // Skip the `while` keyword
+    parser.next();
+
+    if !is_separator(parser, Separator::OpenParenthesis) {
+        let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+            .with_expected([Expected::Token("(")]);
+
+        return Err(error);
+    }
+
+    let open = get_current_token_span(parser)?;
+
+    // Skip the opening parenthesis
+    parser.next();
+
+    let test = parse_expression(parser)?;
+
+    if !is_separator(parser, Separator::CloseParenthesis) {
+        let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+            .with_expected([Expected::Token(")")])
+            .with_help("missing `)` to close the condition started here", Some(open));
+
+        return Err(error);
+    }
+
+    parser.next();
+
+    if is_separator(parser, Separator::Terminator) {
+        parser.next();
+    }
+
+    let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+    Ok(DoWhileStatement { body, test, span })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tokenizer::Tokenizer, ast::{parser::AstParser, nodes::{AstNode, expression_statement::Expression}}};
+
+    use super::{is_do_while_statement, parse_do_while_statement};
+
+    #[test]
+    fn do_keyword_is_a_do_while_statement() {
+        let parser = AstParser::new(Tokenizer::new("do"));
+
+        assert!(is_do_while_statement(&parser));
+    }
+
+    #[test]
+    fn do_while_statement_has_its_parts() {
+        let mut parser = AstParser::new(Tokenizer::new("do { a; } while (a < b);"));
+
+        let statement = parse_do_while_statement(&mut parser).unwrap();
+
+        assert!(matches!(*statement.body, AstNode::BlockStatement(_)));
+        assert!(matches!(statement.test, Expression::BinaryExpression(_)));
+        assert_eq!(statement.span.range, (0, 24));
+        assert!(!parser.has_tokens());
+    }
+
+    #[test]
+    fn semicolon_after_the_condition_is_optional() {
+        let mut parser = AstParser::new(Tokenizer::new("do x; while (y) z"));
+
+        let statement = parse_do_while_statement(&mut parser).unwrap();
+
+        assert_eq!(statement.span.range, (0, 15));
+        assert_eq!(parser.token().unwrap().value, "z");
+    }
+
+    #[test]
+    fn missing_while_is_an_error() {
+        let mut parser = AstParser::new(Tokenizer::new("do {} (a)"));
+
+        let error = parse_do_while_statement(&mut parser).unwrap_err();
+
+        assert_eq!(error.to_string(), "Unexpected token `(`, expected `while`");
+        assert_eq!(error.help.unwrap().message, "missing `while` to end the `do` loop started here");
+    }
+
+    #[test]
+    fn unclosed_condition_is_an_error() {
+        let mut parser = AstParser::new(Tokenizer::new("do {} while (a;"));
+
+        let error = parse_do_while_statement(&mut parser).unwrap_err();
+
+        assert_eq!(error.to_string(), "Unexpected token `;`, expected `)`");
+    }
+}
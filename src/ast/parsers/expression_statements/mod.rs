@@ -1,76 +1,95 @@
-use crate::{ast::{parser::AstParser, AstParseError, nodes::expression_statement::ExpressionStatement, AstErrorType, SearchResult}, ast_error, tokenizer::{TokenType, Separator}};
+use crate::ast::{parsers::{expressions::parse_expression, util::consume_statement_end}, parser::AstParser, AstParseError, nodes::expression_statement::ExpressionStatement, span::Span};
 
-use self::{literal_expression::{is_literal_expression_statement}, identifier_expression::is_identifier_expression_statement, call_expression::{is_call_expression_statement}, sequence_expression::is_sequence_expression_statement, binary_expression::is_binary_expression_statement};
+use super::{get_current_token_span, get_previous_token_span};
 
-pub mod identifier_expression;
-pub mod literal_expression;
-pub mod call_expression;
-pub mod sequence_expression;
-pub mod binary_expression;
+pub fn parse_expression_statement(parser: &mut AstParser) -> Result<ExpressionStatement, AstParseError> {
+    let start = get_current_token_span(parser)?;
+    let expression = parse_expression(parser)?;
+
+    consume_statement_end(parser)?;
 
-type FindResult<T> = Result<Option<SearchResult<T>>, AstParseError>;
+    // A statement includes its semicolon and the parentheses around its expression
+    let span = Span::between(&start, &get_previous_token_span(parser)?);
 
-pub fn is_expression_statement(parser: &AstParser) -> bool {
-    is_binary_expression_statement(parser) ||
-    is_sequence_expression_statement(parser) ||
-    is_literal_expression_statement(parser) ||
-    is_identifier_expression_statement(parser) ||
-    is_call_expression_statement(parser)
+    Ok(ExpressionStatement { expression, span })
 }
 
-pub fn consume_result(parser: &mut AstParser, result: SearchResult<ExpressionStatement>) -> ExpressionStatement {
-    let delta = result.ast_range.1 - result.ast_range.0;
-    
-    parser.consume_range(delta);
+#[cfg(test)]
+mod tests {
+    use crate::{ast::{parser::AstParser, nodes::{LiteralValue, expression_statement::Expression}, AstErrorType}, tokenizer::Tokenizer, cast_expression_statement};
 
-    result.value
-}
+    use super::parse_expression_statement;
 
-pub fn parse_expression_statement(parser: &mut AstParser) -> Result<ExpressionStatement, AstParseError> {
-    if let Some(result) = binary_expression::find(parser)? {
-        return Ok(consume_result(parser, result));
-    }
+    #[test]
+    fn statement_includes_its_semicolon() {
+        let mut parser = AstParser::new(Tokenizer::new("'Foobar';'Bar';'Foo';"));
 
-    if let Some(result) = call_expression::find(parser)? {
-        return Ok(consume_result(parser, result));
+        parse_expression_statement(&mut parser).unwrap();
+        let second = parse_expression_statement(&mut parser).unwrap();
+        let third = parse_expression_statement(&mut parser).unwrap();
+
+        assert_eq!(second.span.range, (9, 15));
+        assert_eq!(third.span.range, (15, 21));
+        assert!(!parser.has_tokens());
     }
 
-    if let Some(result) = literal_expression::find(parser)? {
-        return Ok(consume_result(parser, result));
+    #[test]
+    fn literal_statements_are_parsed() {
+        for (content, value) in [("'foo'", LiteralValue::String(String::from("foo"))), ("123", LiteralValue::Number(123.0)), ("null", LiteralValue::Null), ("true", LiteralValue::Boolean(true))] {
+            let mut parser = AstParser::new(Tokenizer::new(content));
+
+            let result = parse_expression_statement(&mut parser).unwrap();
+
+            assert_eq!(cast_expression_statement!(result, Expression::Literal).unwrap().value.value, value);
+        }
     }
 
-    if let Some(result) = identifier_expression::find(parser)? {
-        return Ok(consume_result(parser, result));
+    #[test]
+    fn identifier_statement_is_parsed() {
+        let mut parser = AstParser::new(Tokenizer::new("foobar"));
+
+        let result = parse_expression_statement(&mut parser).unwrap();
+
+        assert_eq!(result.span.range, (0, 6));
+        assert!(matches!(result.expression, Expression::Identifier(_)));
     }
 
-    if let Some(result) = sequence_expression::find(parser)? {
-        return Ok(consume_result(parser, result));
+    #[test]
+    fn statements_on_separate_lines_are_parsed() {
+        let mut parser = AstParser::new(Tokenizer::new("x\ny"));
+
+        parse_expression_statement(&mut parser).unwrap();
+        let second = parse_expression_statement(&mut parser).unwrap();
+
+        assert_eq!(second.span.range, (2, 3));
     }
 
-    return ast_error!(AstErrorType::UnexpectedToken, parser);
-}
+    #[test]
+    fn sequence_without_terminator_is_parsed() {
+        let mut parser = AstParser::new(Tokenizer::new("'foo', 123"));
 
-fn expression_has_ended(parser: &AstParser, start_index: usize) -> bool {
+        let result = parse_expression_statement(&mut parser).unwrap();
 
-    let index = start_index + 1;
-    let end_marker = parser.token_at(index);
+        assert_eq!(cast_expression_statement!(result, Expression::SequenceExpression).unwrap().expressions.len(), 2);
+    }
+
+    #[test]
+    fn identifiers_on_the_same_line_are_an_error() {
+        let mut parser = AstParser::new(Tokenizer::new("foobar foo"));
 
-    match end_marker {
-        Some(marker) => {
-            if matches!(marker.token_type, TokenType::Separator(Separator::Terminator)) {
-                return true;
-            }
+        let error = parse_expression_statement(&mut parser).unwrap_err();
+
+        assert_eq!(error.error_type, AstErrorType::UnexpectedToken);
+        assert_eq!(error.index, 1);
+    }
 
-            if matches!(marker.token_type, TokenType::Separator(Separator::Comma)) {
-                return true;
-            }
+    #[test]
+    fn function_in_a_sequence_is_an_error() {
+        let mut parser = AstParser::new(Tokenizer::new("'foo', function bar() {};"));
 
-            if index > 0 && parser.can_insert_automatic_semicolon(index) {
-                return true;
-            }
+        let error = parse_expression_statement(&mut parser).unwrap_err();
 
-            return false
-        },
-        None => return true,
+        assert_eq!(error.error_type, AstErrorType::UnexpectedToken);
+        assert_eq!(error.index, 2);
     }
 }
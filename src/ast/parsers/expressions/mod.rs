@@ -0,0 +1,1363 @@
+use crate::{ast::{span::{Span, Spanned}, parser::AstParser, nodes::{Identifier, Literal, pattern::Pattern, expression_statement::{Expression, BinaryExpression, BinaryOperator, LogicalExpression, UpdateExpression, ArrowFunctionExpression, ArrowFunctionBody, CallExpression, MemberExpression, ChainExpression, SequenceExpression, IdentifierExpression, LiteralExpression, ArrayExpression, ObjectExpression, ObjectProperty, Property, ExpressionOrSpread, SpreadElement}}, AstParseError, AstErrorType, Expected}, tokenizer::{TokenType, Operator, Separator, Keyword, Literal as TokenLiteral}, ast_error};
+
+use super::{get_current_token_span, get_previous_token_span, block_statements::parse_block_statement, patterns::{binding_pattern_from_expression, parse_rest_element, is_rest_element}, util::{is_separator, is_identifier_token, parse_identifier}};
+
+/// Parses an expression, the comma operator binds loosest and joins the expressions around it into a sequence
+pub fn parse_expression(parser: &mut AstParser) -> Result<Expression, AstParseError> {
+    parse_sequence_expression(parser, true)
+}
+
+/// Parses an expression where `in` isn't an operator, the initializer of a `for` statement ends before it so that
+/// `for (a in b)` is a `for…in` loop
+pub fn parse_expression_without_in(parser: &mut AstParser) -> Result<Expression, AstParseError> {
+    parse_sequence_expression(parser, false)
+}
+
+/// Parses an expression that can't be a sequence, e.g. a call argument. The spec calls it an AssignmentExpression,
+/// a comma after it is left to the caller
+pub fn parse_assignment_expression(parser: &mut AstParser) -> Result<Expression, AstParseError> {
+    parse_assignment(parser, true)
+}
+
+/// `parse_assignment_expression` where `in` isn't an operator, e.g. the initializer of a variable in a `for` header
+pub fn parse_assignment_expression_without_in(parser: &mut AstParser) -> Result<Expression, AstParseError> {
+    parse_assignment(parser, false)
+}
+
+// `allow_in` is the `In` parameter of the grammar. It only reaches the operators outside of brackets,
+// `for (a, (b in c);;)` has an `in` operator
+fn parse_sequence_expression(parser: &mut AstParser, allow_in: bool) -> Result<Expression, AstParseError> {
+    let start = expression_start(parser)?;
+    let first = parse_assignment(parser, allow_in)?;
+
+    if !is_separator(parser, Separator::Comma) {
+        return Ok(first);
+    }
+
+    let mut expressions = vec![first];
+
+    while is_separator(parser, Separator::Comma) {
+        parser.next();
+        expressions.push(parse_assignment(parser, allow_in)?);
+    }
+
+    let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+    Ok(Expression::SequenceExpression(SequenceExpression { expressions, span }))
+}
+
+// An arrow function or a binary expression, assignments and conditional expressions aren't supported yet. Every
+// bracket nests through here, which is what the nesting limit counts
+fn parse_assignment(parser: &mut AstParser, allow_in: bool) -> Result<Expression, AstParseError> {
+    parser.nested(|parser| parse_arrow_function_or_binary(parser, allow_in))
+}
+
+fn parse_arrow_function_or_binary(parser: &mut AstParser, allow_in: bool) -> Result<Expression, AstParseError> {
+    let index = parser.get_current_index();
+
+    if parser.token().is_some_and(|token| is_identifier_token(&token))
+        && parser.token_at(index + 1).is_some_and(|token| token.token_type == TokenType::Separator(Separator::Arrow)) {
+        let start = get_current_token_span(parser)?;
+        let params = vec![Pattern::Identifier(parse_identifier(parser)?)];
+
+        return parse_arrow_function(parser, start, params, allow_in).map(Expression::ArrowFunctionExpression);
+    }
+
+    if is_separator(parser, Separator::OpenParenthesis) {
+        let start = get_current_token_span(parser)?;
+
+        return match parse_parenthesized_or_parameters(parser)? {
+            Parenthesized::Parameters(params) => parse_arrow_function(parser, start, params, allow_in).map(Expression::ArrowFunctionExpression),
+            Parenthesized::Expression(expression) => parse_binary_expression(parser, 0, allow_in, Some(Operand { start, expression })),
+        };
+    }
+
+    parse_binary_expression(parser, 0, allow_in, None)
+}
+
+// A parenthesized list is only known to be the parameters of an arrow function once the `=>` after it is reached
+enum Parenthesized {
+    Expression(Expression),
+    Parameters(Vec<Pattern>),
+}
+
+// An operand that has been parsed before the operators around it, the parenthesized expression at the start of an
+// assignment expression. `start` is the opening parenthesis
+struct Operand {
+    start: Span,
+    expression: Expression,
+}
+
+// https://262.ecma-international.org/13.0/#prod-CoverParenthesizedExpressionAndArrowParameterList, the list is parsed
+// once as expressions and converted to patterns when `=>` follows it. `()`, a trailing comma and a rest element can
+// only be parameters
+fn parse_parenthesized_or_parameters(parser: &mut AstParser) -> Result<Parenthesized, AstParseError> {
+    let open = get_current_token_span(parser)?;
+    let mut expressions = Vec::new();
+    let mut rest = None;
+    let mut trailing_comma = false;
+
+    // Skip the opening parenthesis
+    parser.next();
+
+    while !is_separator(parser, Separator::CloseParenthesis) {
+        if is_rest_element(parser) {
+            rest = Some(parse_rest_element(parser)?);
+
+            if !is_separator(parser, Separator::CloseParenthesis) {
+                let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+                    .with_expected([Expected::Token(")")])
+                    .with_help("a rest parameter has to be the last parameter", None);
+
+                return Err(error);
+            }
+
+            break;
+        }
+
+        expressions.push(parse_assignment(parser, true)?);
+        trailing_comma = false;
+
+        if is_separator(parser, Separator::CloseParenthesis) { break; }
+
+        if !is_separator(parser, Separator::Comma) {
+            let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+                .with_expected([Expected::Token(")"), Expected::Token(",")])
+                .with_help("missing `)` to close parenthesis opened here", Some(open));
+
+            return Err(error);
+        }
+
+        parser.next();
+        trailing_comma = true;
+    }
+
+    // Skip the closing parenthesis
+    parser.next();
+
+    if is_separator(parser, Separator::Arrow) {
+        let mut params = Vec::with_capacity(expressions.len() + 1);
+
+        for expression in expressions {
+            let pattern = binding_pattern_from_expression(expression).map_err(|span| {
+                parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+                    .with_help("the parameters of an arrow function have to be identifiers or destructuring patterns", Some(span))
+            })?;
+
+            params.push(pattern);
+        }
+
+        params.extend(rest.map(Pattern::RestElement));
+
+        return Ok(Parenthesized::Parameters(params));
+    }
+
+    if expressions.is_empty() || rest.is_some() || trailing_comma {
+        return ast_error!(AstErrorType::UnexpectedToken, parser, parser.get_current_index(), [Expected::Token("=>")]);
+    }
+
+    if expressions.len() == 1 {
+        return Ok(Parenthesized::Expression(expressions.remove(0)));
+    }
+
+    let span = Span::between(expressions[0].span(), expressions[expressions.len() - 1].span());
+
+    Ok(Parenthesized::Expression(Expression::SequenceExpression(SequenceExpression { expressions, span })))
+}
+
+// `=>` is a restricted production, `a\n=> b` is a syntax error instead of two statements. The body is an assignment
+// expression, it keeps the `In` parameter of the arrow function
+fn parse_arrow_function(parser: &mut AstParser, start: Span, params: Vec<Pattern>, allow_in: bool) -> Result<ArrowFunctionExpression, AstParseError> {
+    let arrow = parser.get_current_index();
+
+    if parser.has_line_terminator_before(arrow) {
+        let error = parser.error_at(arrow, AstErrorType::UnexpectedToken)
+            .with_help("`=>` has to be on the same line as the parameters of the arrow function", None);
+
+        return Err(error);
+    }
+
+    // Skip the arrow
+    parser.next();
+
+    let body = if is_separator(parser, Separator::OpenCurlyBrace) {
+        ArrowFunctionBody::BlockStatement(Box::new(parse_block_statement(parser)?))
+    } else {
+        ArrowFunctionBody::Expression(Box::new(parse_assignment(parser, allow_in)?))
+    };
+
+    let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+    Ok(ArrowFunctionExpression { params, body, span })
+}
+
+// https://262.ecma-international.org/13.0/#sec-binary-logical-operators, operators with a higher precedence bind tighter
+fn binary_precedence(operator: BinaryOperator) -> Option<u8> {
+    // `in` and `instanceof` are relational operators
+    let BinaryOperator::Operator(operator) = operator else { return Some(7) };
+
+    let precedence = match operator {
+        Operator::LogicalOr | Operator::NullishCoalescing => 1,
+        Operator::LogicalAnd => 2,
+        Operator::BitwiseOr => 3,
+        Operator::BitwiseXor => 4,
+        Operator::BitwiseAnd => 5,
+        Operator::Equal | Operator::NotEqual | Operator::StrictEqual | Operator::StrictNotEqual => 6,
+        Operator::LessThan | Operator::LessThanOrEqual | Operator::GreaterThan | Operator::GreaterThanOrEqual => 7,
+        Operator::LeftShift | Operator::RightShift | Operator::UnsignedRightShift => 8,
+        Operator::Plus | Operator::Minus => 9,
+        Operator::Multiply | Operator::Divide | Operator::Remainder => 10,
+        Operator::Exponent => 11,
+        _ => return None,
+    };
+
+    Some(precedence)
+}
+
+fn binary_operator(parser: &AstParser, allow_in: bool) -> Option<(BinaryOperator, u8)> {
+    let operator = match parser.token()?.token_type {
+        TokenType::Operator(operator) => BinaryOperator::Operator(operator),
+        TokenType::Keyword(Keyword::In) if allow_in => BinaryOperator::In,
+        TokenType::Keyword(Keyword::Instanceof) => BinaryOperator::Instanceof,
+        _ => return None,
+    };
+
+    Some((operator, binary_precedence(operator)?))
+}
+
+// `&&`, `||` and `??` build a LogicalExpression instead of a BinaryExpression
+fn logical_operator(operator: BinaryOperator) -> Option<Operator> {
+    match operator {
+        BinaryOperator::Operator(operator @ (Operator::LogicalAnd | Operator::LogicalOr | Operator::NullishCoalescing)) => Some(operator),
+        _ => None,
+    }
+}
+
+// Precedence climbing, operators that bind tighter than `min_precedence` are folded into the left operand. Each token
+// is read once, a chain of operators of the same precedence is folded in the loop instead of through recursion
+fn parse_binary_expression(parser: &mut AstParser, min_precedence: u8, allow_in: bool, first: Option<Operand>) -> Result<Expression, AstParseError> {
+    let start = operand_start(parser, &first)?;
+    let mut left = parse_update_expression(parser, first)?;
+
+    // The operator of `left` when it's a logical expression without parentheses around it
+    let mut left_operator = None;
+
+    while let Some((operator, precedence)) = binary_operator(parser, allow_in) {
+        if precedence <= min_precedence { break; }
+
+        let nullish_coalescing = operator == BinaryOperator::Operator(Operator::NullishCoalescing);
+
+        if left_operator.is_some_and(|previous| (previous == Operator::NullishCoalescing) != nullish_coalescing) {
+            return Err(mixed_nullish_coalescing(parser));
+        }
+
+        parser.next();
+
+        let right_precedence = match operator {
+            // `**` is right-associative, `a ** b ** c` is `a ** (b ** c)`
+            BinaryOperator::Operator(Operator::Exponent) => precedence - 1,
+            // The right operand of `??` binds tighter than `&&`, so `a ?? b && c` stops before `&&` and is reported
+            // as mixing the operators
+            BinaryOperator::Operator(Operator::NullishCoalescing) => precedence + 1,
+            _ => precedence,
+        };
+
+        let right = Box::new(parser.nested(|parser| parse_binary_expression(parser, right_precedence, allow_in, None))?);
+
+        // A parenthesized operand is part of the expression, `(a) + b` starts at the parenthesis
+        let span = Span::between(&start, &get_previous_token_span(parser)?);
+        let left_operand = Box::new(left);
+
+        left_operator = logical_operator(operator);
+
+        if let Some(operator) = left_operator {
+            left = Expression::LogicalExpression(LogicalExpression { operator, left: left_operand, right, span });
+        } else {
+            left = Expression::BinaryExpression(BinaryExpression { operator, left: left_operand, right, span });
+        }
+    }
+
+    Ok(left)
+}
+
+// https://262.ecma-international.org/13.0/#sec-binary-logical-operators, `??` can't share an operand with `&&` or `||`
+fn mixed_nullish_coalescing(parser: &AstParser) -> AstParseError {
+    parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+        .with_help("`??` can't be mixed with `&&` or `||` without parentheses", None)
+}
+
+fn is_assignment_operator(operator: Operator) -> bool {
+    matches!(operator,
+        Operator::Assign | Operator::AddAssign | Operator::SubtractAssign | Operator::MultiplyAssign |
+        Operator::DivideAssign | Operator::RemainderAssign | Operator::ExponentAssign | Operator::LeftShiftAssign |
+        Operator::RightShiftAssign | Operator::UnsignedRightShiftAssign | Operator::BitwiseAndAssign |
+        Operator::BitwiseXorAssign | Operator::BitwiseOrAssign | Operator::LogicalAndAssign | Operator::LogicalOrAssign |
+        Operator::NullishAssign
+    )
+}
+
+fn update_operator(parser: &AstParser) -> Option<Operator> {
+    match parser.token()?.token_type {
+        TokenType::Operator(operator @ (Operator::Increment | Operator::Decrement)) => Some(operator),
+        _ => None,
+    }
+}
+
+// `++a` or `a++`. A postfix operator is a restricted production, it has to be on the same line as its argument so
+// `a\n++b` is `a; ++b`
+// https://262.ecma-international.org/13.0/#sec-update-expressions
+fn parse_update_expression(parser: &mut AstParser, first: Option<Operand>) -> Result<Expression, AstParseError> {
+    let start = operand_start(parser, &first)?;
+
+    if let (None, Some(operator)) = (&first, update_operator(parser)) {
+        parser.next();
+
+        // The argument can be an update expression itself, `++a++` is `++(a++)` and can't be assigned to
+        let argument = parser.nested(|parser| parse_update_expression(parser, None))?;
+        check_update_target(parser, &argument)?;
+
+        let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+        return Ok(Expression::UpdateExpression(UpdateExpression { operator, prefix: true, argument: Box::new(argument), span }));
+    }
+
+    let argument = parse_left_hand_side_expression(parser, first)?;
+
+    let Some(operator) = update_operator(parser) else { return Ok(argument) };
+
+    if parser.has_line_terminator_before(parser.get_current_index()) {
+        return Ok(argument);
+    }
+
+    check_update_target(parser, &argument)?;
+    parser.next();
+
+    let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+    Ok(Expression::UpdateExpression(UpdateExpression { operator, prefix: false, argument: Box::new(argument), span }))
+}
+
+// https://262.ecma-international.org/13.0/#sec-static-semantics-assignmenttargettype, the error is reported at the
+// token after the argument, the tokens of the argument have been released
+fn check_update_target(parser: &AstParser, argument: &Expression) -> Result<(), AstParseError> {
+    if matches!(argument, Expression::Identifier(_) | Expression::MemberExpression(_)) {
+        return Ok(());
+    }
+
+    let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+        .with_help("only a variable or a property can be incremented or decremented", Some(argument.span().clone()));
+
+    Err(error)
+}
+
+// Member accesses and calls, e.g. `a.b[c](d)`. A chain with a `?.` anywhere in it is wrapped in a ChainExpression
+fn parse_left_hand_side_expression(parser: &mut AstParser, first: Option<Operand>) -> Result<Expression, AstParseError> {
+    let (start, mut expression) = match first {
+        Some(Operand { start, expression }) => (start, expression),
+        None => (expression_start(parser)?, parse_primary_expression(parser)?),
+    };
+    let mut optional_chain = false;
+
+    loop {
+        let optional = is_separator(parser, Separator::OptionalChaining);
+
+        if optional {
+            optional_chain = true;
+            parser.next();
+        }
+
+        if is_separator(parser, Separator::OpenParenthesis) {
+            let arguments = parse_arguments(parser)?;
+            let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+            expression = Expression::CallExpression(CallExpression { callee: Box::new(expression), arguments, optional, span });
+        } else if is_separator(parser, Separator::OpenBracket) {
+            let property = parse_computed_property(parser)?;
+            let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+            expression = Expression::MemberExpression(MemberExpression {
+                object: Box::new(expression), property: Box::new(property), computed: true, optional, span,
+            });
+        } else if optional || is_separator(parser, Separator::Period) {
+            // `?.` is directly followed by the name, `.` is skipped first
+            if !optional { parser.next(); }
+
+            let property = Expression::Identifier(IdentifierExpression { identifier: parse_property_name(parser)? });
+            let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+            expression = Expression::MemberExpression(MemberExpression {
+                object: Box::new(expression), property: Box::new(property), computed: false, optional, span,
+            });
+        } else {
+            break;
+        }
+    }
+
+    if !optional_chain {
+        return Ok(expression);
+    }
+
+    let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+    // https://262.ecma-international.org/13.0/#sec-static-semantics-assignmenttargettype, `a?.b = 1` is an early error
+    if matches!(parser.token(), Some(token) if matches!(token.token_type, TokenType::Operator(operator) if is_assignment_operator(operator))) {
+        let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+            .with_help("an optional chain can't be assigned to", Some(span));
+
+        return Err(error);
+    }
+
+    Ok(Expression::ChainExpression(ChainExpression { expression: Box::new(expression), span }))
+}
+
+// The arguments are a list of their own, not a sequence, `f((a, b), c)` has two arguments
+fn parse_arguments(parser: &mut AstParser) -> Result<Vec<ExpressionOrSpread>, AstParseError> {
+    let open = get_current_token_span(parser)?;
+    let mut arguments = Vec::new();
+
+    // Skip the opening parenthesis
+    parser.next();
+
+    // A trailing comma is allowed, the closing parenthesis is checked again after every comma
+    while !is_separator(parser, Separator::CloseParenthesis) {
+        arguments.push(parse_expression_or_spread(parser)?);
+
+        if is_separator(parser, Separator::CloseParenthesis) { break; }
+
+        if !is_separator(parser, Separator::Comma) {
+            let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+                .with_expected([Expected::Token(")"), Expected::Token(",")])
+                .with_help("missing `)` to close call started here", Some(open));
+
+            return Err(error);
+        }
+
+        parser.next();
+    }
+
+    // Skip the closing parenthesis
+    parser.next();
+
+    Ok(arguments)
+}
+
+fn parse_expression_or_spread(parser: &mut AstParser) -> Result<ExpressionOrSpread, AstParseError> {
+    if is_separator(parser, Separator::Ellipsis) {
+        return Ok(ExpressionOrSpread::Spread(parse_spread_element(parser)?));
+    }
+
+    Ok(ExpressionOrSpread::Expression(parse_assignment_expression(parser)?))
+}
+
+fn parse_spread_element(parser: &mut AstParser) -> Result<SpreadElement, AstParseError> {
+    let start = get_current_token_span(parser)?;
+
+    // Skip the ellipsis
+    parser.next();
+
+    let argument = Box::new(parse_assignment_expression(parser)?);
+    let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+    Ok(SpreadElement { argument, span })
+}
+
+fn parse_computed_property(parser: &mut AstParser) -> Result<Expression, AstParseError> {
+    let open = get_current_token_span(parser)?;
+
+    // Skip the opening bracket
+    parser.next();
+
+    let property = parse_expression(parser)?;
+
+    if !is_separator(parser, Separator::CloseBracket) {
+        let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+            .with_expected([Expected::Token("]")])
+            .with_help("missing `]` to close property access started here", Some(open));
+
+        return Err(error);
+    }
+
+    parser.next();
+
+    Ok(property)
+}
+
+// Any word can be a property name after a period, reserved words included, e.g. `promise.catch` or `value.null`
+fn parse_property_name(parser: &mut AstParser) -> Result<Identifier, AstParseError> {
+    let identifier = match parser.token() {
+        Some(token) if matches!(token.token_type, TokenType::Identifier | TokenType::Keyword(_)) => Identifier::from(&*token),
+        // The value of these literals isn't their name
+        Some(token) if matches!(token.token_type, TokenType::Literal(TokenLiteral::Boolean | TokenLiteral::Null)) => {
+            Identifier { name: token.raw_value.to_string(), span: Span::from(&*token) }
+        },
+        _ => return ast_error!(AstErrorType::UnexpectedToken, parser, parser.get_current_index(), [Expected::Construct("a property name")]),
+    };
+
+    parser.next();
+
+    Ok(identifier)
+}
+
+fn parse_primary_expression(parser: &mut AstParser) -> Result<Expression, AstParseError> {
+    if is_separator(parser, Separator::OpenParenthesis) {
+        return parse_parenthesized_expression(parser);
+    }
+
+    if is_separator(parser, Separator::OpenBracket) {
+        return parse_array_expression(parser).map(Expression::ArrayExpression);
+    }
+
+    if is_separator(parser, Separator::OpenCurlyBrace) {
+        return parse_object_expression(parser).map(Expression::ObjectExpression);
+    }
+
+    let index = parser.get_current_index();
+
+    let expression = match parser.token() {
+        Some(token) if is_identifier_token(&token) => {
+            Expression::Identifier(IdentifierExpression { identifier: Identifier::from(&*token) })
+        },
+        Some(token) if matches!(token.token_type, TokenType::Literal(_)) => {
+            Expression::Literal(LiteralExpression { value: Literal::from(&*token) })
+        },
+        _ => return ast_error!(AstErrorType::UnexpectedToken, parser, index, [Expected::Construct("an expression")]),
+    };
+
+    parser.next();
+
+    Ok(expression)
+}
+
+fn parse_array_expression(parser: &mut AstParser) -> Result<ArrayExpression, AstParseError> {
+    let open = get_current_token_span(parser)?;
+    let mut elements = Vec::new();
+
+    // Skip the opening bracket
+    parser.next();
+
+    // A comma without an element before it leaves a hole, a single trailing comma doesn't, `[a, , b,]` has three elements
+    while !is_separator(parser, Separator::CloseBracket) {
+        if is_separator(parser, Separator::Comma) {
+            elements.push(None);
+            parser.next();
+            continue;
+        }
+
+        elements.push(Some(parse_expression_or_spread(parser)?));
+
+        if is_separator(parser, Separator::CloseBracket) { break; }
+
+        if !is_separator(parser, Separator::Comma) {
+            let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+                .with_expected([Expected::Token("]"), Expected::Token(",")])
+                .with_help("missing `]` to close array started here", Some(open));
+
+            return Err(error);
+        }
+
+        parser.next();
+    }
+
+    let span = Span::between(&open, &get_current_token_span(parser)?);
+    parser.next();
+
+    Ok(ArrayExpression { elements, span })
+}
+
+fn parse_object_expression(parser: &mut AstParser) -> Result<ObjectExpression, AstParseError> {
+    let open = get_current_token_span(parser)?;
+    let mut properties = Vec::new();
+
+    // Skip the opening curly brace
+    parser.next();
+
+    while !is_separator(parser, Separator::CloseCurlyBrace) {
+        if is_separator(parser, Separator::Ellipsis) {
+            properties.push(ObjectProperty::Spread(parse_spread_element(parser)?));
+        } else {
+            properties.push(ObjectProperty::Property(parse_property(parser)?));
+        }
+
+        if is_separator(parser, Separator::CloseCurlyBrace) { break; }
+
+        if !is_separator(parser, Separator::Comma) {
+            let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+                .with_expected([Expected::Token("}"), Expected::Token(",")])
+                .with_help("missing `}` to close object started here", Some(open));
+
+            return Err(error);
+        }
+
+        parser.next();
+    }
+
+    let span = Span::between(&open, &get_current_token_span(parser)?);
+    parser.next();
+
+    Ok(ObjectExpression { properties, span })
+}
+
+// `key: value`, `[key]: value` or the shorthand `key`. Methods and accessors aren't supported yet
+fn parse_property(parser: &mut AstParser) -> Result<Property, AstParseError> {
+    let start = get_current_token_span(parser)?;
+    let computed = is_separator(parser, Separator::OpenBracket);
+
+    // Only a plain identifier can be a shorthand property, `{ if }` isn't a valid reference
+    let identifier_key = parser.token().is_some_and(|token| is_identifier_token(&token));
+
+    let key = parse_property_key(parser)?;
+
+    if is_separator(parser, Separator::Colon) {
+        parser.next();
+
+        let value = Box::new(parse_assignment_expression(parser)?);
+        let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+        return Ok(Property { key: Box::new(key), value, computed, shorthand: false, span });
+    }
+
+    match key {
+        Expression::Identifier(IdentifierExpression { identifier }) if identifier_key => {
+            let value = Identifier { name: identifier.name.clone(), span: identifier.span.clone() };
+            let key = Box::new(Expression::Identifier(IdentifierExpression { identifier }));
+
+            Ok(Property { key, value: Box::new(Expression::Identifier(IdentifierExpression { identifier: value })), computed, shorthand: true, span: start })
+        },
+        _ => ast_error!(AstErrorType::UnexpectedToken, parser, parser.get_current_index(), [Expected::Token(":")]),
+    }
+}
+
+/// Parses the key of a property in an object literal or pattern, it's `computed` when it starts with a bracket
+pub fn parse_property_key(parser: &mut AstParser) -> Result<Expression, AstParseError> {
+    match parser.token() {
+        _ if is_separator(parser, Separator::OpenBracket) => parse_computed_property(parser),
+        Some(token) if matches!(token.token_type, TokenType::Literal(TokenLiteral::String | TokenLiteral::Number | TokenLiteral::BigInt)) => {
+            parser.next();
+            Ok(Expression::Literal(LiteralExpression { value: Literal::from(&*token) }))
+        },
+        _ => Ok(Expression::Identifier(IdentifierExpression { identifier: parse_property_name(parser)? })),
+    }
+}
+
+// The parentheses only group, the expression keeps its own span like in ESTree
+fn parse_parenthesized_expression(parser: &mut AstParser) -> Result<Expression, AstParseError> {
+    let open = get_current_token_span(parser)?;
+
+    // Skip the opening parenthesis
+    parser.next();
+
+    let expression = parse_expression(parser)?;
+
+    if !is_separator(parser, Separator::CloseParenthesis) {
+        let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+            .with_expected([Expected::Token(")")])
+            .with_help("missing `)` to close parenthesis opened here", Some(open));
+
+        return Err(error);
+    }
+
+    parser.next();
+
+    Ok(expression)
+}
+
+// Where the operand starts, at its opening parenthesis when it has been parsed already
+fn operand_start(parser: &AstParser, first: &Option<Operand>) -> Result<Span, AstParseError> {
+    match first {
+        Some(operand) => Ok(operand.start.clone()),
+        None => expression_start(parser),
+    }
+}
+
+// Where the next expression starts, the end of the input is reported as a missing expression
+fn expression_start(parser: &AstParser) -> Result<Span, AstParseError> {
+    match parser.token() {
+        Some(token) => Ok(Span::from(&*token)),
+        None => ast_error!(AstErrorType::UnexpectedToken, parser, parser.get_current_index(), [Expected::Construct("an expression")]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tokenizer::{Tokenizer, Operator}, ast::{parser::AstParser, span::Spanned, nodes::{LiteralValue, pattern::Pattern, expression_statement::{Expression, BinaryExpression, BinaryOperator, LogicalExpression, CallExpression, ExpressionOrSpread, ObjectProperty, ArrowFunctionBody}}, AstErrorType}, cast_expression};
+
+    use super::{parse_expression, parse_expression_without_in, parse_assignment_expression};
+
+    fn parse(content: &str) -> Expression {
+        let mut parser = AstParser::new(Tokenizer::new(content));
+
+        let expression = parse_expression(&mut parser).unwrap();
+        assert!(!parser.has_tokens(), "Expected every token to be parsed");
+
+        expression
+    }
+
+    fn argument(call: &CallExpression, index: usize) -> &Expression {
+        match &call.arguments[index] {
+            ExpressionOrSpread::Expression(expression) => expression,
+            ExpressionOrSpread::Spread(_) => panic!("Expected an expression"),
+        }
+    }
+
+    // Writes the expression with parentheses around every binary and logical expression
+    fn grouped(expression: &Expression) -> String {
+        match expression {
+            Expression::BinaryExpression(BinaryExpression { operator, left, right, .. }) => {
+                format!("({} {} {})", grouped(left), operator.as_str(), grouped(right))
+            },
+            Expression::LogicalExpression(LogicalExpression { operator, left, right, .. }) => {
+                format!("({} {} {})", grouped(left), operator.as_str(), grouped(right))
+            },
+            Expression::Identifier(expression) => expression.identifier.name.clone(),
+            Expression::Literal(expression) => expression.value.raw.clone(),
+            _ => String::from("?"),
+        }
+    }
+
+    macro_rules! precedence_tests {
+        ($($name:ident: $content:expr => $expected:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                assert_eq!(grouped(&parse($content)), $expected);
+            }
+        )*
+        };
+    }
+
+    precedence_tests! {
+        same_precedence_is_left_associative: "123 + 123 - 123" => "((123 + 123) - 123)",
+        await_and_yield_are_identifiers: "await * yield / 2" => "((await * yield) / 2)",
+        multiplication_binds_tighter: "1 + 2 * 3" => "(1 + (2 * 3))",
+        multiplication_on_the_left_binds_tighter: "1 * 2 + 3" => "((1 * 2) + 3)",
+        exponent_is_right_associative: "2 ** 3 ** 2" => "(2 ** (3 ** 2))",
+        comparison_binds_looser_than_shift: "a < b << c" => "(a < (b << c))",
+        equality_binds_looser_than_comparison: "a == b > c" => "(a == (b > c))",
+        bitwise_operators_keep_their_order: "a | b ^ c & d" => "(a | (b ^ (c & d)))",
+        logical_and_binds_tighter_than_or: "a || b && c" => "(a || (b && c))",
+        mixed_chain_is_grouped_by_precedence: "a + b * c - d / e" => "((a + (b * c)) - (d / e))",
+        parentheses_override_precedence: "(1 + 2) * 3" => "((1 + 2) * 3)",
+        parentheses_on_the_right_group_first: "a - (b - c)" => "(a - (b - c))",
+        nested_parentheses_are_unwrapped: "((a))" => "a",
+        nullish_coalescing_is_left_associative: "a ?? b ?? c" => "((a ?? b) ?? c)",
+        nullish_coalescing_binds_looser_than_bitwise_or: "a ?? b | c" => "(a ?? (b | c))",
+        parenthesized_logical_operand_of_nullish_coalescing: "(a || b) ?? c" => "((a || b) ?? c)",
+        parenthesized_logical_right_operand_of_nullish_coalescing: "a ?? (b && c)" => "(a ?? (b && c))",
+        parenthesized_nullish_coalescing_in_logical_or: "(a ?? b) || c" => "((a ?? b) || c)",
+        in_is_a_relational_operator: "a in b == c" => "((a in b) == c)",
+        instanceof_binds_looser_than_shift: "a instanceof b << c" => "(a instanceof (b << c))",
+        in_and_comparison_are_left_associative: "a < b in c" => "((a < b) in c)",
+    }
+
+    macro_rules! mixed_nullish_coalescing_tests {
+        ($($name:ident: $content:expr => $index:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let mut parser = AstParser::new(Tokenizer::new($content));
+
+                let error = parse_expression(&mut parser).unwrap_err();
+
+                assert_eq!(error.index, $index);
+                assert_eq!(error.help.unwrap().message, "`??` can't be mixed with `&&` or `||` without parentheses");
+            }
+        )*
+        };
+    }
+
+    mixed_nullish_coalescing_tests! {
+        nullish_coalescing_before_logical_or: "a ?? b || c" => 3,
+        nullish_coalescing_before_logical_and: "a ?? b && c" => 3,
+        logical_or_before_nullish_coalescing: "a || b ?? c" => 3,
+        logical_and_before_nullish_coalescing: "a && b ?? c" => 3,
+        nullish_coalescing_after_a_chain: "a || b && c ?? d" => 5,
+        logical_operator_with_a_parenthesized_operand: "x ?? (a) && b" => 5,
+    }
+
+    #[test]
+    fn logical_expression_is_parsed() {
+        let result = parse("a && b");
+
+        let expression = cast_expression!(&result, Expression::LogicalExpression).unwrap();
+
+        assert_eq!(expression.operator, Operator::LogicalAnd);
+        assert_eq!(expression.span.range, (0, 6));
+    }
+
+    #[test]
+    fn logical_operands_can_be_binary_expressions() {
+        let result = parse("a == b || c");
+
+        let expression = cast_expression!(&result, Expression::LogicalExpression).unwrap();
+
+        assert!(matches!(expression.left.as_ref(), Expression::BinaryExpression(_)));
+    }
+
+    #[test]
+    fn binary_expression_is_parsed() {
+        let result = parse("321 + 123");
+
+        let expression = cast_expression!(&result, Expression::BinaryExpression).unwrap();
+        let left = cast_expression!(expression.left.as_ref(), Expression::Literal).unwrap();
+        let right = cast_expression!(expression.right.as_ref(), Expression::Literal).unwrap();
+
+        assert_eq!(expression.operator, BinaryOperator::Operator(Operator::Plus));
+        assert_eq!(left.value.raw, "321");
+        assert_eq!(right.value.raw, "123");
+        assert_eq!(expression.span.range, (0, 9));
+    }
+
+    #[test]
+    fn binary_expression_without_right_operand_is_an_error() {
+        let mut parser = AstParser::new(Tokenizer::new("123 + 123 -"));
+
+        let error = parse_expression(&mut parser).unwrap_err();
+
+        assert_eq!(error.error_type, AstErrorType::UnexpectedEndOfInput);
+    }
+
+    #[test]
+    fn function_call_is_parsed() {
+        let result = parse("foobar()");
+
+        let call = cast_expression!(&result, Expression::CallExpression).unwrap();
+
+        assert_eq!(cast_expression!(call.callee.as_ref(), Expression::Identifier).unwrap().identifier.name, "foobar");
+        assert_eq!(call.arguments.len(), 0);
+        assert_eq!(call.span.range, (0, 8));
+    }
+
+    #[test]
+    fn function_call_with_params_is_parsed() {
+        let result = parse("call('123', 123, call())");
+
+        let call = cast_expression!(&result, Expression::CallExpression).unwrap();
+
+        assert_eq!(cast_expression!(call.callee.as_ref(), Expression::Identifier).unwrap().identifier.name, "call");
+        assert_eq!(call.arguments.len(), 3);
+        assert!(matches!(argument(call, 2), Expression::CallExpression(_)));
+    }
+
+    #[test]
+    fn function_call_allows_a_trailing_comma() {
+        let result = parse("call(a, b,)");
+
+        assert_eq!(cast_expression!(&result, Expression::CallExpression).unwrap().arguments.len(), 2);
+    }
+
+    #[test]
+    fn call_arguments_can_be_binary_expressions() {
+        let result = parse("call(a + b, c)");
+
+        let call = cast_expression!(&result, Expression::CallExpression).unwrap();
+
+        assert_eq!(grouped(argument(call, 0)), "(a + b)");
+    }
+
+    #[test]
+    fn half_function_call_is_an_error() {
+        let mut parser = AstParser::new(Tokenizer::new("call(123"));
+
+        let error = parse_expression(&mut parser).unwrap_err();
+
+        assert_eq!(error.error_type, AstErrorType::UnexpectedEndOfInput);
+        assert_eq!(error.help.unwrap().message, "missing `)` to close call started here");
+    }
+
+    #[test]
+    fn sequence_is_parsed() {
+        let result = parse("'Foobar', Bar");
+
+        let sequence = cast_expression!(&result, Expression::SequenceExpression).unwrap();
+        let first = cast_expression!(&sequence.expressions[0], Expression::Literal).unwrap();
+        let second = cast_expression!(&sequence.expressions[1], Expression::Identifier).unwrap();
+
+        assert_eq!(first.value.value, LiteralValue::String(String::from("Foobar")));
+        assert_eq!(second.identifier.name, "Bar");
+        assert_eq!(sequence.span.range, (0, 13));
+        assert_eq!(sequence.span.loc.end.column, 13);
+    }
+
+    #[test]
+    fn sequence_with_calls_is_parsed() {
+        let result = parse("foo(x, y, z), 'bar'");
+
+        let sequence = cast_expression!(&result, Expression::SequenceExpression).unwrap();
+
+        assert_eq!(sequence.expressions.len(), 2);
+    }
+
+    #[test]
+    fn call_arguments_are_not_a_sequence() {
+        let mut parser = AstParser::new(Tokenizer::new("foo(a, b), c"));
+
+        let result = parse_assignment_expression(&mut parser).unwrap();
+
+        assert!(matches!(result, Expression::CallExpression(_)));
+        assert_eq!(parser.token().unwrap().value, ",");
+    }
+
+    #[test]
+    fn parenthesized_sequence_is_one_argument() {
+        let result = parse("f((a, b), c)");
+
+        let call = cast_expression!(&result, Expression::CallExpression).unwrap();
+        let first = cast_expression!(argument(call, 0), Expression::SequenceExpression).unwrap();
+
+        assert_eq!(call.arguments.len(), 2);
+        assert_eq!(first.expressions.len(), 2);
+        assert_eq!(first.span.range, (3, 7));
+    }
+
+    #[test]
+    fn parenthesized_sequence_nests_in_a_sequence() {
+        let result = parse("(a, b), c");
+
+        let sequence = cast_expression!(&result, Expression::SequenceExpression).unwrap();
+
+        assert_eq!(sequence.expressions.len(), 2);
+        assert!(matches!(sequence.expressions[0], Expression::SequenceExpression(_)));
+        assert_eq!(sequence.span.range, (0, 9));
+    }
+
+    #[test]
+    fn sequence_is_an_operand_in_parentheses() {
+        let result = parse("(a, b) + c");
+
+        let expression = cast_expression!(&result, Expression::BinaryExpression).unwrap();
+
+        assert!(matches!(expression.left.as_ref(), Expression::SequenceExpression(_)));
+        assert_eq!(expression.span.range, (0, 10));
+    }
+
+    #[test]
+    fn parenthesized_expression_keeps_its_own_span() {
+        let result = parse("(foo)");
+
+        assert_eq!(result.span().range, (1, 4));
+    }
+
+    #[test]
+    fn unclosed_parenthesis_is_an_error() {
+        let mut parser = AstParser::new(Tokenizer::new("(a, b;"));
+
+        let error = parse_expression(&mut parser).unwrap_err();
+
+        assert_eq!(error.index, 4);
+        assert_eq!(error.to_string(), "Unexpected token `;`, expected `)` or `,`");
+        assert_eq!(error.help.unwrap().message, "missing `)` to close parenthesis opened here");
+    }
+
+    #[test]
+    fn empty_parentheses_are_an_error() {
+        let mut parser = AstParser::new(Tokenizer::new("()"));
+
+        let error = parse_expression(&mut parser).unwrap_err();
+
+        assert_eq!(error.to_string(), "Unexpected end of input, expected `=>`");
+    }
+
+    #[test]
+    fn parameters_only_syntax_is_an_error_without_an_arrow() {
+        for input in ["(a, ...b) + c", "(a,) + c"] {
+            let mut parser = AstParser::new(Tokenizer::new(input));
+
+            let error = parse_expression(&mut parser).unwrap_err();
+
+            assert_eq!(error.to_string(), "Unexpected token `+`, expected `=>`", "{input}");
+        }
+    }
+
+    // Writes a chain of member accesses and calls and `chain(...)` the chain expression
+    fn chain(expression: &Expression) -> String {
+        match expression {
+            Expression::MemberExpression(member) if member.computed => {
+                format!("{}{}[{}]", chain(&member.object), if member.optional { "?." } else { "" }, chain(&member.property))
+            },
+            Expression::MemberExpression(member) => {
+                format!("{}{}{}", chain(&member.object), if member.optional { "?." } else { "." }, chain(&member.property))
+            },
+            Expression::CallExpression(call) => {
+                format!("{}{}()", chain(&call.callee), if call.optional { "?." } else { "" })
+            },
+            Expression::ChainExpression(expression) => format!("chain({})", chain(&expression.expression)),
+            expression => grouped(expression),
+        }
+    }
+
+    macro_rules! chain_tests {
+        ($($name:ident: $content:expr => $expected:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                assert_eq!(chain(&parse($content)), $expected);
+            }
+        )*
+        };
+    }
+
+    chain_tests! {
+        member_access_is_parsed: "a.b.c" => "a.b.c",
+        computed_member_access_is_parsed: "a[b + c]" => "a[(b + c)]",
+        method_call_is_parsed: "a.b()" => "a.b()",
+        calls_can_be_chained: "f()()" => "f()()",
+        reserved_word_is_a_property_name: "promise.catch.null" => "promise.catch.null",
+        optional_member_is_a_chain: "a?.b" => "chain(a?.b)",
+        optional_computed_member_is_a_chain: "a?.[k]" => "chain(a?.[k])",
+        optional_call_is_a_chain: "f?.()" => "chain(f?.())",
+        chain_continues_after_the_optional_link: "a?.b.c()" => "chain(a?.b.c())",
+        only_the_marked_links_are_optional: "a.b?.c.d?.()" => "chain(a.b?.c.d?.())",
+        parentheses_end_a_chain: "(a?.b).c" => "chain(a?.b).c",
+    }
+
+    #[test]
+    fn member_access_binds_tighter_than_binary_operators() {
+        let result = parse("a.b + c[0]");
+
+        let expression = cast_expression!(&result, Expression::BinaryExpression).unwrap();
+
+        assert!(matches!(expression.left.as_ref(), Expression::MemberExpression(_)));
+        assert!(matches!(expression.right.as_ref(), Expression::MemberExpression(_)));
+    }
+
+    #[test]
+    fn chain_spans_its_links() {
+        let result = parse("foo?.bar(baz)");
+
+        let chain = cast_expression!(&result, Expression::ChainExpression).unwrap();
+        let call = cast_expression!(chain.expression.as_ref(), Expression::CallExpression).unwrap();
+
+        assert_eq!(chain.span.range, (0, 13));
+        assert_eq!(call.span.range, (0, 13));
+        assert_eq!(call.callee.span().range, (0, 8));
+        assert!(!call.optional);
+    }
+
+    #[test]
+    fn assignment_to_optional_chain_is_an_error() {
+        let mut parser = AstParser::new(Tokenizer::new("a?.b = 1"));
+
+        let error = parse_expression(&mut parser).unwrap_err();
+        let help = error.help.unwrap();
+
+        assert_eq!(error.index, 3);
+        assert_eq!(help.message, "an optional chain can't be assigned to");
+        assert_eq!(help.span.unwrap().range, (0, 4));
+    }
+
+    #[test]
+    fn compound_assignment_to_optional_chain_is_an_error() {
+        let mut parser = AstParser::new(Tokenizer::new("a?.[0] += 1"));
+
+        let error = parse_expression(&mut parser).unwrap_err();
+
+        assert_eq!(error.index, 5);
+        assert!(error.help.is_some());
+    }
+
+    #[test]
+    fn missing_property_name_is_an_error() {
+        let mut parser = AstParser::new(Tokenizer::new("a?.;"));
+
+        let error = parse_expression(&mut parser).unwrap_err();
+
+        assert_eq!(error.to_string(), "Unexpected token `;`, expected a property name");
+    }
+
+    #[test]
+    fn unclosed_computed_member_is_an_error() {
+        let mut parser = AstParser::new(Tokenizer::new("a[b;"));
+
+        let error = parse_expression(&mut parser).unwrap_err();
+
+        assert_eq!(error.to_string(), "Unexpected token `;`, expected `]`");
+        assert_eq!(error.help.unwrap().message, "missing `]` to close property access started here");
+    }
+
+    #[test]
+    fn expression_without_in_ends_before_in() {
+        let mut parser = AstParser::new(Tokenizer::new("a < b in c"));
+
+        let expression = parse_expression_without_in(&mut parser).unwrap();
+
+        assert_eq!(grouped(&expression), "(a < b)");
+        assert_eq!(parser.token().unwrap().value, "in");
+    }
+
+    #[test]
+    fn expression_without_in_allows_in_within_brackets() {
+        let mut parser = AstParser::new(Tokenizer::new("f(a in b), [a in b], (a in b)"));
+
+        parse_expression_without_in(&mut parser).unwrap();
+
+        assert!(!parser.has_tokens());
+    }
+
+    #[test]
+    fn spread_argument_is_parsed() {
+        let result = parse("f(...args, a)");
+
+        let call = cast_expression!(&result, Expression::CallExpression).unwrap();
+        let ExpressionOrSpread::Spread(spread) = &call.arguments[0] else { panic!("Expected a spread element") };
+
+        assert_eq!(spread.span.range, (2, 9));
+        assert!(matches!(spread.argument.as_ref(), Expression::Identifier(_)));
+        assert!(matches!(call.arguments[1], ExpressionOrSpread::Expression(_)));
+    }
+
+    #[test]
+    fn array_keeps_its_holes() {
+        let result = parse("[a, , ...xs,]");
+
+        let array = cast_expression!(&result, Expression::ArrayExpression).unwrap();
+
+        assert_eq!(array.elements.len(), 3);
+        assert!(array.elements[1].is_none());
+        assert!(matches!(array.elements[2], Some(ExpressionOrSpread::Spread(_))));
+        assert_eq!(array.span.range, (0, 13));
+    }
+
+    #[test]
+    fn array_of_holes_is_parsed() {
+        let result = parse("[,,]");
+
+        let array = cast_expression!(&result, Expression::ArrayExpression).unwrap();
+
+        assert_eq!(array.elements.len(), 2);
+        assert!(array.elements.iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn object_properties_are_parsed() {
+        let result = parse("({ a, b: 1, [k]: v, ...rest, 'c': 2, if: 3, })");
+
+        let object = cast_expression!(&result, Expression::ObjectExpression).unwrap();
+        let properties: Vec<(bool, bool)> = object.properties.iter().filter_map(|property| match property {
+            ObjectProperty::Property(property) => Some((property.shorthand, property.computed)),
+            ObjectProperty::Spread(_) => None,
+        }).collect();
+
+        assert_eq!(object.properties.len(), 6);
+        assert!(matches!(object.properties[3], ObjectProperty::Spread(_)));
+        assert_eq!(properties, vec![(true, false), (false, false), (false, true), (false, false), (false, false)]);
+        assert_eq!(object.span.range, (1, 45));
+    }
+
+    #[test]
+    fn shorthand_property_has_to_be_an_identifier() {
+        let mut parser = AstParser::new(Tokenizer::new("({ if })"));
+
+        let error = parse_expression(&mut parser).unwrap_err();
+
+        assert_eq!(error.to_string(), "Unexpected token `}`, expected `:`");
+    }
+
+    #[test]
+    fn unclosed_array_is_an_error() {
+        let mut parser = AstParser::new(Tokenizer::new("[a b]"));
+
+        let error = parse_expression(&mut parser).unwrap_err();
+
+        assert_eq!(error.to_string(), "Unexpected token `b`, expected `]` or `,`");
+        assert_eq!(error.help.unwrap().message, "missing `]` to close array started here");
+    }
+
+    #[test]
+    fn spread_is_not_an_operand() {
+        let mut parser = AstParser::new(Tokenizer::new("a + ...b"));
+
+        let error = parse_expression(&mut parser).unwrap_err();
+
+        assert_eq!(error.to_string(), "Unexpected token `...`, expected an expression");
+    }
+
+    #[test]
+    fn keyword_is_not_an_expression() {
+        let mut parser = AstParser::new(Tokenizer::new("while"));
+
+        let error = parse_expression(&mut parser).unwrap_err();
+
+        assert_eq!(error.to_string(), "Unexpected token `while`, expected an expression");
+    }
+
+    #[test]
+    fn long_chains_are_parsed_in_one_pass() {
+        let content = vec!["a"; 10_000].join(" + ");
+
+        let result = parse(&content);
+
+        assert_eq!(result.span().range, (0, content.len()));
+    }
+
+    #[test]
+    fn prefix_update_is_parsed() {
+        let result = parse("++a.b");
+
+        let update = cast_expression!(&result, Expression::UpdateExpression).unwrap();
+
+        assert_eq!(update.operator, Operator::Increment);
+        assert!(update.prefix);
+        assert!(matches!(update.argument.as_ref(), Expression::MemberExpression(_)));
+        assert_eq!(update.span.range, (0, 5));
+    }
+
+    #[test]
+    fn postfix_update_is_an_operand() {
+        let result = parse("a-- + 1");
+
+        let expression = cast_expression!(&result, Expression::BinaryExpression).unwrap();
+        let update = cast_expression!(expression.left.as_ref(), Expression::UpdateExpression).unwrap();
+
+        assert_eq!(update.operator, Operator::Decrement);
+        assert!(!update.prefix);
+        assert_eq!(update.span.range, (0, 3));
+    }
+
+    #[test]
+    fn postfix_operator_on_the_next_line_is_not_parsed() {
+        let mut parser = AstParser::new(Tokenizer::new("a\n++b"));
+
+        let result = parse_expression(&mut parser).unwrap();
+
+        assert!(matches!(result, Expression::Identifier(_)));
+        assert_eq!(parser.token().unwrap().value, "++");
+    }
+
+    macro_rules! invalid_update_target_tests {
+        ($($name:ident: $content:expr => $index:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let mut parser = AstParser::new(Tokenizer::new($content));
+
+                let error = parse_expression(&mut parser).unwrap_err();
+
+                assert_eq!(error.index, $index);
+                assert_eq!(error.help.unwrap().message, "only a variable or a property can be incremented or decremented");
+            }
+        )*
+        };
+    }
+
+    invalid_update_target_tests! {
+        literal_can_not_be_incremented: "1++" => 1,
+        call_can_not_be_decremented: "--f();" => 4,
+        update_can_not_be_updated: "++a++" => 3,
+        optional_chain_can_not_be_incremented: "a?.b++" => 3,
+    }
+
+    #[test]
+    fn arrow_function_with_a_single_parameter_is_parsed() {
+        let result = parse("x => x + 1");
+
+        let arrow = cast_expression!(&result, Expression::ArrowFunctionExpression).unwrap();
+        let ArrowFunctionBody::Expression(body) = &arrow.body else { panic!("Expected an expression body") };
+
+        assert!(matches!(&arrow.params[..], [Pattern::Identifier(_)]));
+        assert_eq!(grouped(body), "(x + 1)");
+        assert_eq!(arrow.span.range, (0, 10));
+    }
+
+    #[test]
+    fn arrow_function_with_parameters_and_a_block_is_parsed() {
+        let result = parse("(a, ...b) => { a; }");
+
+        let arrow = cast_expression!(&result, Expression::ArrowFunctionExpression).unwrap();
+
+        assert_eq!(arrow.params.len(), 2);
+        assert!(matches!(arrow.params[1], Pattern::RestElement(_)));
+        assert!(matches!(arrow.body, ArrowFunctionBody::BlockStatement(_)));
+        assert_eq!(arrow.span.range, (0, 19));
+    }
+
+    #[test]
+    fn arrow_function_with_destructured_parameters_is_parsed() {
+        let result = parse("([a, ...b], { c }) => a");
+
+        let arrow = cast_expression!(&result, Expression::ArrowFunctionExpression).unwrap();
+
+        assert!(matches!(&arrow.params[..], [Pattern::ArrayPattern(_), Pattern::ObjectPattern(_)]));
+        assert_eq!(arrow.span.range, (0, 23));
+    }
+
+    #[test]
+    fn arrow_function_without_parameters_is_an_argument() {
+        let result = parse("f(() => a, b)");
+
+        let call = cast_expression!(&result, Expression::CallExpression).unwrap();
+        let arrow = cast_expression!(argument(call, 0), Expression::ArrowFunctionExpression).unwrap();
+
+        assert!(arrow.params.is_empty());
+        assert_eq!(call.arguments.len(), 2);
+    }
+
+    #[test]
+    fn arrow_function_body_ends_before_a_comma() {
+        let result = parse("x => y => z, w");
+
+        let sequence = cast_expression!(&result, Expression::SequenceExpression).unwrap();
+        let arrow = cast_expression!(&sequence.expressions[0], Expression::ArrowFunctionExpression).unwrap();
+
+        assert!(matches!(arrow.body, ArrowFunctionBody::Expression(ref body) if matches!(body.as_ref(), Expression::ArrowFunctionExpression(_))));
+        assert_eq!(sequence.expressions.len(), 2);
+    }
+
+    #[test]
+    fn parenthesized_expression_before_an_operator_is_not_an_arrow_function() {
+        assert_eq!(grouped(&parse("(a) + (b)")), "(a + b)");
+    }
+
+    #[test]
+    fn line_break_before_arrow_is_an_error() {
+        let mut parser = AstParser::new(Tokenizer::new("(a)\n=> a"));
+
+        let error = parse_expression(&mut parser).unwrap_err();
+
+        assert_eq!(error.index, 3);
+        assert_eq!(error.help.unwrap().message, "`=>` has to be on the same line as the parameters of the arrow function");
+    }
+
+    #[test]
+    fn arrow_function_parameters_have_to_be_bindings() {
+        let mut parser = AstParser::new(Tokenizer::new("(a + b) => a"));
+
+        let error = parse_expression(&mut parser).unwrap_err();
+        let help = error.help.unwrap();
+
+        assert_eq!(error.index, 5);
+        assert_eq!(help.message, "the parameters of an arrow function have to be identifiers or destructuring patterns");
+        assert_eq!(help.span.unwrap().range, (1, 6));
+    }
+
+    #[test]
+    fn nested_literals_are_converted_to_parameters() {
+        let result = parse("([a, [b], ...{ c }], { d: { e }, ...f }) => a");
+        let arrow = cast_expression!(&result, Expression::ArrowFunctionExpression).unwrap();
+
+        let [Pattern::ArrayPattern(array), Pattern::ObjectPattern(object)] = &arrow.params[..] else { panic!("Expected two patterns") };
+
+        assert!(matches!(array.elements[1], Some(Pattern::ArrayPattern(_))));
+        assert!(matches!(&array.elements[2], Some(Pattern::RestElement(rest)) if matches!(*rest.argument, Pattern::ObjectPattern(_))));
+        assert_eq!(object.properties.len(), 2);
+    }
+
+    #[test]
+    fn spread_before_the_last_element_is_not_a_parameter() {
+        let mut parser = AstParser::new(Tokenizer::new("([...a, b]) => a"));
+
+        let error = parse_expression(&mut parser).unwrap_err();
+
+        assert_eq!(error.help.unwrap().span.unwrap().range, (2, 6));
+    }
+
+    #[test]
+    fn parenthesized_sequence_continues_as_an_operand() {
+        let result = parse("(a, b)(c) + d");
+        let binary = cast_expression!(&result, Expression::BinaryExpression).unwrap();
+        let call = cast_expression!(&*binary.left, Expression::CallExpression).unwrap();
+
+        assert!(matches!(*call.callee, Expression::SequenceExpression(_)));
+        assert_eq!(call.span.range, (0, 9));
+        assert_eq!(binary.span.range, (0, 13));
+    }
+}
@@ -0,0 +1,409 @@
+use crate::{ast::{span::{Span, Spanned}, parser::AstParser, nodes::{AstNode, pattern::Pattern, variable_declaration::VariableDeclaration, for_statement::{ForStatement, ForInit, ForInStatement, ForOfStatement, ForLeft}, expression_statement::Expression}, AstParseError, AstErrorType, Expected}, tokenizer::{TokenType, Keyword, Separator}, ast_error};
+
+use super::{get_current_token_span, get_previous_token_span, expressions::{parse_expression, parse_expression_without_in, parse_assignment_expression}, variable_declaration::{is_variable_declaration, parse_variable_declaration, check_initializers}, util::{is_separator, parse_loop_body}};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Iteration {
+    In,
+    Of,
+}
+
+impl Iteration {
+    fn loop_name(&self) -> &'static str {
+        match self {
+            Iteration::In => "`for…in` loop",
+            Iteration::Of => "`for…of` loop",
+        }
+    }
+}
+
+pub fn is_for_statement(parser: &AstParser) -> bool {
+    match parser.token() {
+        Some(token) => matches!(token.token_type, TokenType::Keyword(Keyword::For)),
+        None => false,
+    }
+}
+
+// The head decides the kind of loop, the classic loop has a `;` where the other loops have `in` or `of`. In the
+// initializer `in` isn't an operator, so it always ends the head of a `for…in` loop
+// https://262.ecma-international.org/13.0/#sec-iteration-statements
+pub fn parse_for_statement(parser: &mut AstParser) -> Result<AstNode, AstParseError> {
+    let start = get_current_token_span(parser)?;
+
+    // Skip the keyword
+    parser.next();
+
+    if !is_separator(parser, Separator::OpenParenthesis) {
+        return ast_error!(AstErrorType::UnexpectedToken, parser, parser.get_current_index(), [Expected::Token("(")]);
+    }
+
+    let open = get_current_token_span(parser)?;
+
+    // Skip the opening parenthesis
+    parser.next();
+
+    if is_separator(parser, Separator::Terminator) {
+        return parse_for_rest(parser, start, open, None);
+    }
+
+    if is_variable_declaration(parser) {
+        let declaration = parse_variable_declaration(parser, false)?;
+
+        if let Some(iteration) = iteration(parser) {
+            check_declaration_head(parser, &declaration, iteration)?;
+            return parse_for_in_of_rest(parser, start, open, ForLeft::VariableDeclaration(declaration), iteration);
+        }
+
+        check_initializers(parser, &declaration)?;
+
+        return parse_for_rest(parser, start, open, Some(ForInit::VariableDeclaration(declaration)));
+    }
+
+    let starts_with_let = matches!(parser.token(), Some(token) if token.token_type == TokenType::Identifier && token.raw_value == "let");
+    let init = parse_expression_without_in(parser)?;
+
+    let Some(iteration) = iteration(parser) else {
+        return parse_for_rest(parser, start, open, Some(ForInit::Expression(init)));
+    };
+
+    // `for (let of` would be ambiguous with a declaration of a variable named `of`
+    if starts_with_let && iteration == Iteration::Of {
+        let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+            .with_help("the left side of a `for…of` loop can't start with `let`", Some(init.span().clone()));
+
+        return Err(error);
+    }
+
+    let target = match assignment_target(init) {
+        Ok(target) => target,
+        Err(init) => {
+            let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+                .with_help(format!("the left side of a {} has to be a variable or a property", iteration.loop_name()), Some(init.span().clone()));
+
+            return Err(error);
+        },
+    };
+
+    parse_for_in_of_rest(parser, start, open, ForLeft::Pattern(target), iteration)
+}
+
+// `in` or `of` after the left side of the loop, `of` is an identifier everywhere else
+fn iteration(parser: &AstParser) -> Option<Iteration> {
+    let token = parser.token()?;
+
+    match token.token_type {
+        TokenType::Keyword(Keyword::In) => Some(Iteration::In),
+        TokenType::Identifier if token.raw_value == "of" => Some(Iteration::Of),
+        _ => None,
+    }
+}
+
+// https://262.ecma-international.org/13.0/#sec-static-semantics-assignmenttargettype, gives the expression back when
+// it can't be assigned to. Destructuring assignments aren't supported yet
+fn assignment_target(expression: Expression) -> Result<Pattern, Expression> {
+    match expression {
+        Expression::Identifier(expression) => Ok(Pattern::Identifier(expression.identifier)),
+        Expression::MemberExpression(expression) => Ok(Pattern::MemberExpression(expression)),
+        expression => Err(expression),
+    }
+}
+
+// The loop assigns to a single variable, an initializer would be overwritten before the first iteration. The error
+// is reported at `in` or `of`, the tokens of the declaration have been released
+fn check_declaration_head(parser: &AstParser, declaration: &VariableDeclaration, iteration: Iteration) -> Result<(), AstParseError> {
+    let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken);
+
+    if declaration.declarations.len() > 1 {
+        return Err(error.with_help(format!("a {} can only declare one variable", iteration.loop_name()), Some(declaration.span.clone())));
+    }
+
+    match declaration.declarations.first() {
+        Some(declarator) if declarator.init.is_some() => {
+            Err(error.with_help(format!("the variable of a {} can't have an initializer", iteration.loop_name()), Some(declarator.span.clone())))
+        },
+        _ => Ok(()),
+    }
+}
+
+// The rest of `for (init; test; update) body` after the initializer
+fn parse_for_rest(parser: &mut AstParser, start: Span, open: Span, init: Option<ForInit>) -> Result<AstNode, AstParseError> {
+    consume_header_semicolon(parser)?;
+
+    let test = if is_separator(parser, Separator::Terminator) { None } else { Some(parse_expression(parser)?) };
+
+    consume_header_semicolon(parser)?;
+
+    let update = if is_separator(parser, Separator::CloseParenthesis) { None } else { Some(parse_expression(parser)?) };
+
+    consume_close_parenthesis(parser, open)?;
+
+    let body = parse_loop_body(parser)?;
+    let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+    Ok(AstNode::ForStatement(ForStatement { init, test, update, body, span }))
+}
+
+// The rest of the loop from `in` or `of`. The object of `for…in` can be a sequence, the iterable of `for…of` can't
+fn parse_for_in_of_rest(parser: &mut AstParser, start: Span, open: Span, left: ForLeft, iteration: Iteration) -> Result<AstNode, AstParseError> {
+    // Skip `in` or `of`
+    parser.next();
+
+    let right = match iteration {
+        Iteration::In => parse_expression(parser)?,
+        Iteration::Of => parse_assignment_expression(parser)?,
+    };
+
+    consume_close_parenthesis(parser, open)?;
+
+    let body = parse_loop_body(parser)?;
+    let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+    match iteration {
+        Iteration::In => Ok(AstNode::ForInStatement(ForInStatement { left, right, body, span })),
+        Iteration::Of => Ok(AstNode::ForOfStatement(ForOfStatement { left, right, body, span })),
+    }
+}
+
+// A semicolon is never inserted in the header, not even before a line break
+// https://262.ecma-international.org/13.0/#sec-rules-of-automatic-semicolon-insertion
+fn consume_header_semicolon(parser: &mut AstParser) -> Result<(), AstParseError> {
+    if is_separator(parser, Separator::Terminator) {
+        parser.next();
+        return Ok(());
+    }
+
+    let index = parser.get_current_index();
+    let error = parser.error_at(index, AstErrorType::UnexpectedToken).with_expected([Expected::Token(";")]);
+
+    if parser.has_line_terminator_before(index) {
+        return Err(error.with_help("semicolons aren't inserted in the header of a `for` loop", None));
+    }
+
+    Err(error)
+}
+
+fn consume_close_parenthesis(parser: &mut AstParser, open: Span) -> Result<(), AstParseError> {
+    if is_separator(parser, Separator::CloseParenthesis) {
+        parser.next();
+        return Ok(());
+    }
+
+    let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+        .with_expected([Expected::Token(")")])
+        .with_help("missing `)` to close the `for` header started here", Some(open));
+
+    Err(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tokenizer::Tokenizer, ast::{parser::AstParser, span::Spanned, nodes::{AstNode, pattern::Pattern, variable_declaration::VariableKind, for_statement::{ForInit, ForLeft}, expression_statement::Expression}, AstErrorType}};
+
+    use super::{is_for_statement, parse_for_statement};
+
+    fn parse(content: &str) -> AstNode {
+        let mut parser = AstParser::new(Tokenizer::new(content));
+
+        let statement = parse_for_statement(&mut parser).unwrap();
+        assert!(!parser.has_tokens(), "Expected every token to be parsed");
+
+        statement
+    }
+
+    fn parse_error(content: &str) -> crate::ast::AstParseError {
+        let mut parser = AstParser::new(Tokenizer::new(content));
+
+        parse_for_statement(&mut parser).unwrap_err()
+    }
+
+    #[test]
+    fn for_keyword_is_a_for_statement() {
+        let parser = AstParser::new(Tokenizer::new("for"));
+
+        assert!(is_for_statement(&parser));
+    }
+
+    macro_rules! loop_kind_tests {
+        ($($name:ident: $content:expr => $expected:pat,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                assert!(matches!(parse($content), $expected));
+            }
+        )*
+        };
+    }
+
+    loop_kind_tests! {
+        empty_header_is_a_for_statement: "for (;;) {}" => AstNode::ForStatement(_),
+        declaration_with_semicolon_is_a_for_statement: "for (let i = 0; i < n; i) {}" => AstNode::ForStatement(_),
+        expression_with_semicolon_is_a_for_statement: "for (i; i < n;) {}" => AstNode::ForStatement(_),
+        declaration_with_in_is_a_for_in_statement: "for (const key in object) {}" => AstNode::ForInStatement(_),
+        variable_with_in_is_a_for_in_statement: "for (key in object) {}" => AstNode::ForInStatement(_),
+        declaration_with_of_is_a_for_of_statement: "for (const value of values) {}" => AstNode::ForOfStatement(_),
+        property_with_of_is_a_for_of_statement: "for (a.b of values) {}" => AstNode::ForOfStatement(_),
+        declaration_of_a_variable_named_of: "for (let of of values) {}" => AstNode::ForOfStatement(_),
+        let_variable_with_in_is_a_for_in_statement: "for (let in object) {}" => AstNode::ForInStatement(_),
+        parenthesized_in_is_an_operator: "for (var a = (b in c);;) {}" => AstNode::ForStatement(_),
+        in_operator_in_test_and_update: "for (; a in b; a in b) {}" => AstNode::ForStatement(_),
+        destructuring_declaration_with_of_is_a_for_of_statement: "for (const [key, value] of entries) {}" => AstNode::ForOfStatement(_),
+        destructuring_declaration_with_in_is_a_for_in_statement: "for (var { length } in object) {}" => AstNode::ForInStatement(_),
+    }
+
+    #[test]
+    fn for_statement_has_its_parts() {
+        let AstNode::ForStatement(statement) = parse("for (var i = 0, j = 1; i < j; i) a;") else { panic!("Expected a for statement") };
+
+        let Some(ForInit::VariableDeclaration(declaration)) = &statement.init else { panic!("Expected a declaration") };
+
+        assert_eq!(declaration.kind, VariableKind::Var);
+        assert_eq!(declaration.declarations.len(), 2);
+        assert!(matches!(statement.test, Some(Expression::BinaryExpression(_))));
+        assert!(matches!(statement.update, Some(Expression::Identifier(_))));
+        assert!(matches!(*statement.body, AstNode::ExpressionStatement(_)));
+        assert_eq!(statement.span.range, (0, 35));
+    }
+
+    #[test]
+    fn call_is_an_update() {
+        let AstNode::ForStatement(statement) = parse("for (var i = 0; i < n; f(i)) ;") else { panic!("Expected a for statement") };
+
+        assert!(matches!(statement.update, Some(Expression::CallExpression(_))));
+        assert_eq!(statement.update.unwrap().span().range, (23, 27));
+    }
+
+    #[test]
+    fn increment_is_an_update() {
+        let AstNode::ForStatement(statement) = parse("for (var i = 0; i < n; i++) ;") else { panic!("Expected a for statement") };
+
+        let Some(Expression::UpdateExpression(update)) = &statement.update else { panic!("Expected an update expression") };
+
+        assert!(!update.prefix);
+        assert_eq!(update.span.range, (23, 26));
+    }
+
+    #[test]
+    fn destructuring_declaration_is_an_initializer() {
+        let AstNode::ForStatement(statement) = parse("for (let [a, ...b] = c; a;) {}") else { panic!("Expected a for statement") };
+
+        let Some(ForInit::VariableDeclaration(declaration)) = &statement.init else { panic!("Expected a declaration") };
+
+        assert!(matches!(declaration.declarations[0].id, Pattern::ArrayPattern(_)));
+    }
+
+    #[test]
+    fn empty_header_has_no_parts() {
+        let AstNode::ForStatement(statement) = parse("for (;;);") else { panic!("Expected a for statement") };
+
+        assert!(statement.init.is_none() && statement.test.is_none() && statement.update.is_none());
+        assert!(matches!(*statement.body, AstNode::EmptyStatement(_)));
+        assert_eq!(statement.body.span().range, (8, 9));
+    }
+
+    #[test]
+    fn for_of_statement_has_its_parts() {
+        let AstNode::ForOfStatement(statement) = parse("for (const x of xs) { x; }") else { panic!("Expected a for…of statement") };
+
+        let ForLeft::VariableDeclaration(declaration) = &statement.left else { panic!("Expected a declaration") };
+
+        assert_eq!(declaration.kind, VariableKind::Const);
+        assert_eq!(declaration.span.range, (5, 12));
+        assert!(matches!(statement.right, Expression::Identifier(_)));
+        assert!(matches!(*statement.body, AstNode::BlockStatement(_)));
+        assert_eq!(statement.span.range, (0, 26));
+    }
+
+    #[test]
+    fn for_of_body_declares_variables() {
+        let AstNode::ForOfStatement(statement) = parse("for (const x of xs) { const y = x; }") else { panic!("Expected a for…of statement") };
+        let AstNode::BlockStatement(body) = &*statement.body else { panic!("Expected a block") };
+        let AstNode::VariableDeclaration(declaration) = &body.body[0] else { panic!("Expected a declaration") };
+
+        assert_eq!(declaration.kind, VariableKind::Const);
+        assert_eq!(declaration.span.range, (22, 34));
+    }
+
+    #[test]
+    fn var_declaration_is_a_loop_body() {
+        let AstNode::ForStatement(statement) = parse("for (;;) var x = 1;") else { panic!("Expected a for statement") };
+
+        assert!(matches!(*statement.body, AstNode::VariableDeclaration(_)));
+    }
+
+    #[test]
+    fn for_in_statement_assigns_to_a_property() {
+        let AstNode::ForInStatement(statement) = parse("for (a[0] in b, c);") else { panic!("Expected a for…in statement") };
+
+        assert!(matches!(statement.left, ForLeft::Pattern(Pattern::MemberExpression(_))));
+        assert!(matches!(statement.right, Expression::SequenceExpression(_)));
+    }
+
+    #[test]
+    fn in_operator_is_restricted_in_the_initializer() {
+        let AstNode::ForInStatement(statement) = parse("for (a in b in c);") else { panic!("Expected a for…in statement") };
+
+        let ForLeft::Pattern(Pattern::Identifier(left)) = &statement.left else { panic!("Expected an identifier") };
+
+        assert_eq!(left.name, "a");
+        assert!(matches!(statement.right, Expression::BinaryExpression(_)));
+    }
+
+    #[test]
+    fn for_of_iterable_is_not_a_sequence() {
+        let error = parse_error("for (x of a, b);");
+
+        assert_eq!(error.to_string(), "Unexpected token `,`, expected `)`");
+        assert_eq!(error.help.unwrap().message, "missing `)` to close the `for` header started here");
+    }
+
+    macro_rules! error_help_tests {
+        ($($name:ident: $content:expr => ($found:expr, $help:expr),)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let error = parse_error($content);
+
+                assert_eq!(error.found.as_deref(), Some($found));
+                assert_eq!(error.help.unwrap().message, $help);
+            }
+        )*
+        };
+    }
+
+    error_help_tests! {
+        for_of_declaration_with_initializer: "for (let x = 1 of xs);" => ("of", "the variable of a `for…of` loop can't have an initializer"),
+        for_in_declaration_with_initializer: "for (var x = 1 in o);" => ("in", "the variable of a `for…in` loop can't have an initializer"),
+        for_of_with_two_declarators: "for (let x, y of xs);" => ("of", "a `for…of` loop can only declare one variable"),
+        for_of_call_target: "for (f() of xs);" => ("of", "the left side of a `for…of` loop has to be a variable or a property"),
+        for_in_optional_chain_target: "for (a?.b in o);" => ("in", "the left side of a `for…in` loop has to be a variable or a property"),
+        for_of_target_starting_with_let: "for (let.a of xs);" => ("of", "the left side of a `for…of` loop can't start with `let`"),
+        const_without_initializer: "for (const a; a;);" => (";", "a `const` variable has to be initialized"),
+        destructuring_without_initializer: "for (let { a }; a;);" => (";", "a destructuring declaration has to be initialized"),
+        semicolon_is_not_inserted_in_the_header: "for (a\nb; c)" => ("b", "semicolons aren't inserted in the header of a `for` loop"),
+        function_declaration_body: "for (;;) function f() {}" => ("function", "a function declaration can't be the body of a loop, wrap it in a block"),
+        lexical_declaration_body: "for (;;) let x = 1;" => ("let", "a `let` or `const` declaration can't be the body of a loop, wrap it in a block"),
+    }
+
+    #[test]
+    fn missing_parenthesis_is_an_error() {
+        let error = parse_error("for x of xs {}");
+
+        assert_eq!(error.to_string(), "Unexpected token `x`, expected `(`");
+    }
+
+    #[test]
+    fn missing_semicolon_is_an_error() {
+        let error = parse_error("for (a b)");
+
+        assert_eq!(error.to_string(), "Unexpected token `b`, expected `;`");
+        assert!(error.help.is_none());
+    }
+
+    #[test]
+    fn missing_body_is_an_error() {
+        let error = parse_error("for (;;)");
+
+        assert_eq!(error.error_type, AstErrorType::UnexpectedEndOfInput);
+        assert_eq!(error.to_string(), "Unexpected end of input, expected a statement");
+    }
+}
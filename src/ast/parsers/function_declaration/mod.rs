@@ -1,15 +1,10 @@
-use crate::{ast::{AstParseError, parser::AstParser, nodes::{function_declaration::FunctionDeclaration, Identifier, block_statement::BlockStatement}, AstErrorType, parsers::{block_statements::parse_block_statement, util::{is_closed_param_bracket, is_param_separator}, parts::identifier::parse_identifier}}, tokenizer::{TokenType}, ast_error};
+use crate::{ast::{span::Span, AstParseError, parser::AstParser, nodes::{function_declaration::FunctionDeclaration, pattern::Pattern, block_statement::BlockStatement}, AstErrorType, Expected, parsers::{block_statements::parse_block_statement, patterns::{is_rest_element, parse_rest_element, parse_binding_pattern}, util::{is_closed_param_bracket, is_param_separator}}}, tokenizer::{TokenType, Keyword}, ast_error};
 
-use super::{get_start_position, get_end_position_of_previous_token, util::{parse_function_name, is_open_param_bracket}};
+use super::{get_current_token_span, util::{parse_function_name, is_open_param_bracket}};
 
 pub fn is_function_declaration(parser: &AstParser) -> bool {
     match parser.token() {
-        Some(token) => {
-            let is_type = matches!(token.token_type, TokenType::Keyword);
-            let is_value = token.value == "function";
-
-            is_type && is_value
-        },
+        Some(token) => matches!(token.token_type, TokenType::Keyword(Keyword::Function)),
         None => false,
     }
 }
@@ -24,79 +19,90 @@ pub fn parse_function_declaration(parser: &mut AstParser) -> Result<FunctionDecl
         Ok(())
     };
 
-    let parse_parameters = |parser: &mut AstParser| -> Result<Vec<Identifier>, AstParseError> {
-        if !is_open_param_bracket(parser, parser.get_current_index()) { return ast_error!(AstErrorType::UnexpectedToken, parser) }
-        let mut params = Vec::<Identifier>::new();
-
-        // Skip opening bracket
-        parser.next();
-
-        // Exit early if we don't have any params
-        if is_closed_param_bracket(parser, parser.get_current_index()) {
-            parser.next();
-            return Ok(params);
-        }
-
-        loop {
-            // Validate if we got an identifier expression
-            let identifier_expression = parse_identifier(parser, parser.get_current_index(), &mut 0)?;
-            params.push(identifier_expression.identifier);
-
-            parser.next();
-
-            // Validate if we have a closing bracket, if so close the loop
-            if is_closed_param_bracket(parser, parser.get_current_index()) {
-                parser.next();
-                break;
-            }
-
-            // Validate if we have "," separator
-            if is_param_separator(parser, parser.get_current_index()) {
-                parser.next();
-                continue;
-            }
-            
-            return ast_error!(AstErrorType::UnexpectedToken, parser)
-        }
-
-        Ok(params)
-    };
-
     let parse_block_scope = |parser: &mut AstParser| -> Result<BlockStatement, AstParseError> {
         parse_block_statement(parser)
     };
 
-    let start = get_start_position(parser)?;
-
-    dbg!(start);
+    let start = get_current_token_span(parser)?;
 
     // 1. Parse the function keyword
-    _ = parse_function_keyword(parser)?;
+    parse_function_keyword(parser)?;
 
     // 2. Parse the function name
     let function_name = parse_function_name(parser)?;
-    parser.next(); // Temporary until rewrite
-
-    dbg!(&function_name);
 
     // 3. Parse the parameters
     let params = parse_parameters(parser)?;
 
     // 4. Parse the block scope
     let body = parse_block_scope(parser)?;
-    let end = get_end_position_of_previous_token(parser)?;
+    let span = Span::between(&start, &body.span);
 
     Ok(FunctionDeclaration {
         id: function_name,
         params,
         body,
-        range: (start, end)
+        span,
     })
 }
 
+/// Parses the parameter list from its opening parenthesis, arrow functions share it with function declarations
+pub fn parse_parameters(parser: &mut AstParser) -> Result<Vec<Pattern>, AstParseError> {
+    if !is_open_param_bracket(parser, parser.get_current_index()) {
+        return ast_error!(AstErrorType::UnexpectedToken, parser, parser.get_current_index(), [Expected::Token("(")]);
+    }
+
+    let mut params = Vec::<Pattern>::new();
+
+    // Skip opening bracket
+    parser.next();
+
+    // Exit early if we don't have any params
+    if is_closed_param_bracket(parser, parser.get_current_index()) {
+        parser.next();
+        return Ok(params);
+    }
+
+    loop {
+        if is_rest_element(parser) {
+            params.push(Pattern::RestElement(parse_rest_element(parser)?));
+
+            // Nothing can follow the rest parameter, not even a trailing comma
+            if !is_closed_param_bracket(parser, parser.get_current_index()) {
+                let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+                    .with_expected([Expected::Token(")")])
+                    .with_help("a rest parameter has to be the last parameter", None);
+
+                return Err(error);
+            }
+
+            parser.next();
+            break;
+        }
+
+        params.push(parse_binding_pattern(parser)?);
+
+        // Validate if we have a closing bracket, if so close the loop
+        if is_closed_param_bracket(parser, parser.get_current_index()) {
+            parser.next();
+            break;
+        }
+
+        // Validate if we have "," separator
+        if is_param_separator(parser, parser.get_current_index()) {
+            parser.next();
+            continue;
+        }
+
+        return ast_error!(AstErrorType::UnexpectedToken, parser, parser.get_current_index(), [Expected::Token(")"), Expected::Token(",")])
+    }
+
+    Ok(params)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{tokenizer, ast::{parser::AstParser, AstErrorType}};
+    use crate::{tokenizer::Tokenizer, ast::{parser::AstParser, nodes::pattern::{Pattern, ObjectPatternProperty}, AstErrorType}};
 
     use super::{is_function_declaration, parse_function_declaration};
 
@@ -104,32 +110,29 @@ mod tests {
     fn function_keyword_is_start_function_declaration() {
         let content = String::from("function");
 
-        let tokens = tokenizer::parse(&content).unwrap();
-        let parser = AstParser::new(&tokens);
+        let parser = AstParser::new(Tokenizer::new(&content));
 
         let result = is_function_declaration(&parser);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
     
     #[test]
     fn string_is_not_a_start_function_declaration() {
         let content = String::from("'Foobar'");
 
-        let tokens = tokenizer::parse(&content).unwrap();
-        let parser = AstParser::new(&tokens);
+        let parser = AstParser::new(Tokenizer::new(&content));
 
         let result = is_function_declaration(&parser);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
     fn valid_function_parses_as_a_function() {
         let content = String::from("function x() {}");
 
-        let tokens = tokenizer::parse(&content).unwrap();
-        let mut parser = AstParser::new(&tokens);
+        let mut parser = AstParser::new(Tokenizer::new(&content));
 
         let result = parse_function_declaration(&mut parser).unwrap();
 
@@ -141,8 +144,7 @@ mod tests {
     fn valid_function_with_params_parses_as_a_function() {
         let content = String::from("function x(x, y, z) {}");
 
-        let tokens = tokenizer::parse(&content).unwrap();
-        let mut parser = AstParser::new(&tokens);
+        let mut parser = AstParser::new(Tokenizer::new(&content));
 
         let result = parse_function_declaration(&mut parser).unwrap();
 
@@ -150,16 +152,106 @@ mod tests {
         assert_eq!(result.params.len(), 3);
     }
 
+    #[test]
+    fn await_and_yield_can_be_parameters() {
+        let content = String::from("function x(await, { yield }) {}");
+
+        let mut parser = AstParser::new(Tokenizer::new(&content));
+
+        let result = parse_function_declaration(&mut parser).unwrap();
+
+        let Pattern::Identifier(param) = &result.params[0] else { panic!("Expected an identifier") };
+
+        assert_eq!(param.name, "await");
+        assert!(matches!(result.params[1], Pattern::ObjectPattern(_)));
+    }
+
     #[test]
     fn not_valid_function_gives_an_error() {
         let content = String::from("function x(123) {}");
 
-        let tokens = tokenizer::parse(&content).unwrap();
-        let mut parser = AstParser::new(&tokens);
+        let mut parser = AstParser::new(Tokenizer::new(&content));
 
         let result = parse_function_declaration(&mut parser).unwrap_err();
 
         assert_eq!(result.error_type, AstErrorType::UnexpectedToken);
     }
 
+    #[test]
+    fn rest_parameter_is_parsed() {
+        let content = String::from("function x(a, ...rest) {}");
+
+        let mut parser = AstParser::new(Tokenizer::new(&content));
+
+        let result = parse_function_declaration(&mut parser).unwrap();
+
+        let Pattern::RestElement(rest) = &result.params[1] else { panic!("Expected a rest element") };
+        let Pattern::Identifier(argument) = rest.argument.as_ref() else { panic!("Expected an identifier") };
+
+        assert_eq!(argument.name, "rest");
+        assert_eq!(rest.span.range, (14, 21));
+    }
+
+    #[test]
+    fn array_pattern_parameter_with_rest_is_parsed() {
+        let content = String::from("function f([a, ...b]) {}");
+
+        let mut parser = AstParser::new(Tokenizer::new(&content));
+
+        let result = parse_function_declaration(&mut parser).unwrap();
+
+        let Pattern::ArrayPattern(pattern) = &result.params[0] else { panic!("Expected an array pattern") };
+
+        assert!(matches!(pattern.elements[..], [Some(Pattern::Identifier(_)), Some(Pattern::RestElement(_))]));
+    }
+
+    #[test]
+    fn object_pattern_parameter_with_rest_is_parsed() {
+        let content = String::from("function f({a, ...b}) {}");
+
+        let mut parser = AstParser::new(Tokenizer::new(&content));
+
+        let result = parse_function_declaration(&mut parser).unwrap();
+
+        let Pattern::ObjectPattern(pattern) = &result.params[0] else { panic!("Expected an object pattern") };
+
+        assert!(matches!(pattern.properties[..], [ObjectPatternProperty::Property(_), ObjectPatternProperty::Rest(_)]));
+        assert_eq!(pattern.span.range, (11, 20));
+    }
+
+    #[test]
+    fn rest_parameter_can_be_a_pattern() {
+        let content = String::from("function f(...[a, b]) {}");
+
+        let mut parser = AstParser::new(Tokenizer::new(&content));
+
+        let result = parse_function_declaration(&mut parser).unwrap();
+
+        let Pattern::RestElement(rest) = &result.params[0] else { panic!("Expected a rest element") };
+
+        assert!(matches!(*rest.argument, Pattern::ArrayPattern(_)));
+    }
+
+    #[test]
+    fn parameter_after_rest_parameter_is_an_error() {
+        let content = String::from("function x(...rest, a) {}");
+
+        let mut parser = AstParser::new(Tokenizer::new(&content));
+
+        let result = parse_function_declaration(&mut parser).unwrap_err();
+
+        assert_eq!(result.index, 5);
+        assert_eq!(result.help.unwrap().message, "a rest parameter has to be the last parameter");
+    }
+
+    #[test]
+    fn trailing_comma_after_rest_parameter_is_an_error() {
+        let content = String::from("function x(...rest,) {}");
+
+        let mut parser = AstParser::new(Tokenizer::new(&content));
+
+        let result = parse_function_declaration(&mut parser).unwrap_err();
+
+        assert_eq!(result.to_string(), "Unexpected token `,`, expected `)`");
+    }
 }
\ No newline at end of file
@@ -0,0 +1,179 @@
+use crate::{ast::{span::Span, parser::AstParser, nodes::{AstNode, Identifier, jump_statement::{ReturnStatement, ThrowStatement, BreakStatement, ContinueStatement}, expression_statement::Expression}, parsers::{expressions::parse_expression, util::{consume_statement_end, is_identifier_token}}, AstParseError, AstErrorType, Expected}, tokenizer::{TokenType, Keyword, Separator}, ast_error};
+
+use super::{get_current_token_span, get_previous_token_span};
+
+// These statements have a restricted production, a line break after the keyword ends the statement
+// https://262.ecma-international.org/13.0/#sec-rules-of-automatic-semicolon-insertion
+pub fn is_jump_statement(parser: &AstParser) -> bool {
+    match parser.token() {
+        Some(token) => matches!(token.token_type, TokenType::Keyword(Keyword::Return | Keyword::Throw | Keyword::Break | Keyword::Continue)),
+        None => false,
+    }
+}
+
+pub fn parse_jump_statement(parser: &mut AstParser) -> Result<AstNode, AstParseError> {
+    let Some(token) = parser.token() else { return ast_error!(AstErrorType::UnexpectedToken, parser) };
+
+    let start = get_current_token_span(parser)?;
+
+    // Skip the keyword
+    parser.next();
+
+    match token.token_type {
+        TokenType::Keyword(Keyword::Return) => {
+            let argument = parse_argument(parser)?;
+            let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+            Ok(AstNode::ReturnStatement(ReturnStatement { argument, span }))
+        },
+        TokenType::Keyword(Keyword::Throw) => {
+            if !has_operand(parser) {
+                return Err(throw_without_argument(parser, start));
+            }
+
+            let argument = parse_expression(parser)?;
+            consume_statement_end(parser)?;
+
+            let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+            Ok(AstNode::ThrowStatement(ThrowStatement { argument, span }))
+        },
+        TokenType::Keyword(Keyword::Break) => {
+            let label = parse_label(parser)?;
+            let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+            Ok(AstNode::BreakStatement(BreakStatement { label, span }))
+        },
+        TokenType::Keyword(Keyword::Continue) => {
+            let label = parse_label(parser)?;
+            let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+            Ok(AstNode::ContinueStatement(ContinueStatement { label, span }))
+        },
+        _ => ast_error!(AstErrorType::UnexpectedToken, parser, parser.get_current_index() - 1),
+    }
+}
+
+// Whether the token after the keyword belongs to the statement, instead of a semicolon being inserted before it
+fn has_operand(parser: &AstParser) -> bool {
+    let index = parser.get_current_index();
+
+    match parser.token() {
+        Some(token) => {
+            !matches!(token.token_type, TokenType::Separator(Separator::Terminator | Separator::CloseCurlyBrace)) &&
+            !parser.has_line_terminator_before(index)
+        },
+        None => false,
+    }
+}
+
+// Parses the rest of the statement, returns the expression after the keyword if there is one
+fn parse_argument(parser: &mut AstParser) -> Result<Option<Expression>, AstParseError> {
+    let argument = if has_operand(parser) { Some(parse_expression(parser)?) } else { None };
+
+    consume_statement_end(parser)?;
+
+    Ok(argument)
+}
+
+fn parse_label(parser: &mut AstParser) -> Result<Option<Identifier>, AstParseError> {
+    let label = match parser.token() {
+        Some(token) if has_operand(parser) && is_identifier_token(&token) => {
+            parser.next();
+            Some(Identifier::from(&*token))
+        },
+        _ => None,
+    };
+
+    consume_statement_end(parser)?;
+
+    Ok(label)
+}
+
+// `throw` has to be followed by an expression on the same line, a semicolon inserted after it leaves `throw;`
+fn throw_without_argument(parser: &AstParser, keyword: Span) -> AstParseError {
+    let index = parser.get_current_index();
+    let error = parser.error_at(index, AstErrorType::UnexpectedToken).with_expected([Expected::Construct("an expression")]);
+
+    if parser.has_line_terminator_before(index) {
+        return error.with_help("the expression has to start on the same line as `throw`", Some(keyword));
+    }
+
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tokenizer::Tokenizer, ast::{parser::AstParser, nodes::{AstNode, expression_statement::Expression}, AstErrorType}};
+
+    use super::{is_jump_statement, parse_jump_statement};
+
+    #[test]
+    fn return_keyword_is_a_jump_statement() {
+        let content = String::from("return");
+
+        let parser = AstParser::new(Tokenizer::new(&content));
+
+        assert!(is_jump_statement(&parser));
+    }
+
+    #[test]
+    fn return_with_argument_is_parsed() {
+        let content = String::from("return foo;");
+
+        let mut parser = AstParser::new(Tokenizer::new(&content));
+
+        let AstNode::ReturnStatement(statement) = parse_jump_statement(&mut parser).unwrap() else { panic!("Expected a return statement") };
+
+        assert!(matches!(statement.argument, Some(Expression::Identifier(_))));
+        assert_eq!(statement.span.range, (0, 11));
+        assert!(!parser.has_tokens());
+    }
+
+    #[test]
+    fn return_without_argument_ends_at_its_semicolon() {
+        let content = String::from("return; foo");
+
+        let mut parser = AstParser::new(Tokenizer::new(&content));
+
+        let AstNode::ReturnStatement(statement) = parse_jump_statement(&mut parser).unwrap() else { panic!("Expected a return statement") };
+
+        assert!(statement.argument.is_none());
+        assert_eq!(statement.span.range, (0, 7));
+        assert_eq!(parser.token().unwrap().value, "foo");
+    }
+
+    #[test]
+    fn break_takes_a_label_on_the_same_line() {
+        let content = String::from("break outer;");
+
+        let mut parser = AstParser::new(Tokenizer::new(&content));
+
+        let AstNode::BreakStatement(statement) = parse_jump_statement(&mut parser).unwrap() else { panic!("Expected a break statement") };
+
+        assert_eq!(statement.label.unwrap().name, "outer");
+    }
+
+    #[test]
+    fn continue_without_label_is_parsed() {
+        let content = String::from("continue");
+
+        let mut parser = AstParser::new(Tokenizer::new(&content));
+
+        let AstNode::ContinueStatement(statement) = parse_jump_statement(&mut parser).unwrap() else { panic!("Expected a continue statement") };
+
+        assert!(statement.label.is_none());
+    }
+
+    #[test]
+    fn throw_without_argument_is_an_error() {
+        let content = String::from("throw;");
+
+        let mut parser = AstParser::new(Tokenizer::new(&content));
+
+        let error = parse_jump_statement(&mut parser).unwrap_err();
+
+        assert_eq!(error.error_type, AstErrorType::UnexpectedToken);
+        assert!(error.help.is_none());
+    }
+}
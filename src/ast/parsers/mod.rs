@@ -1,39 +1,40 @@
-use super::{AstParseError, parser::AstParser, AstErrorType};
+use super::{AstParseError, parser::AstParser, AstErrorType, span::Span};
 
-mod parts;
+pub mod expressions;
 pub mod expression_statements;
 pub mod block_statements;
 pub mod function_declaration;
+pub mod jump_statements;
+pub mod for_statements;
+pub mod do_while_statements;
+pub mod variable_declaration;
+pub mod patterns;
 pub mod util;
 
 
-#[macro_export]
-macro_rules! handle_allowed_find_error {
-    ($a: expr) => {
-        match $a {
-            Ok(token) => token,
-            Err(_) => return Ok(None),
-        }
-    };
-}
-
 #[macro_export]
 macro_rules! ast_error {
     ($a: expr, $b: expr) => {
-        Err(AstParseError { error_type: $a, index: $b.get_current_index() })
+        Err($b.error_at($b.get_current_index(), $a))
+    };
+    ($a: expr, $b: expr, $index: expr) => {
+        Err($b.error_at($index, $a))
+    };
+    ($a: expr, $b: expr, $index: expr, $expected: expr) => {
+        Err($b.error_at($index, $a).with_expected($expected))
     };
 }
 
-fn get_start_position(parser: &AstParser) -> Result<usize, AstParseError> {
+fn get_current_token_span(parser: &AstParser) -> Result<Span, AstParseError> {
     match parser.token() {
-        Some(token) => Ok(token.range.0),
+        Some(token) => Ok(Span::from(&*token)),
         None => ast_error!(AstErrorType::UnexpectedToken, parser),
     } 
 }
 
-fn get_end_position_of_previous_token(parser: &AstParser) -> Result<usize, AstParseError> {
+fn get_previous_token_span(parser: &AstParser) -> Result<Span, AstParseError> {
     match parser.peek_back() {
-        Some(token) => Ok(token.range.1),
+        Some(token) => Ok(Span::from(&*token)),
         None => ast_error!(AstErrorType::UnexpectedToken, parser),
     } 
 }
@@ -0,0 +1,315 @@
+use crate::{ast::{span::{Span, Spanned}, parser::AstParser, nodes::{Identifier, pattern::{Pattern, ArrayPattern, ObjectPattern, ObjectPatternProperty, AssignmentProperty, RestElement}, expression_statement::{Expression, IdentifierExpression, ExpressionOrSpread, ObjectProperty}}, AstParseError, AstErrorType, Expected}, tokenizer::Separator, ast_error};
+
+use super::{get_current_token_span, get_previous_token_span, expressions::parse_property_key, util::{is_separator, is_identifier_token, parse_identifier}};
+
+// https://262.ecma-international.org/13.0/#sec-destructuring-binding-patterns
+/// Parses the target of a parameter or a declarator, an identifier or an array or object pattern
+pub fn parse_binding_pattern(parser: &mut AstParser) -> Result<Pattern, AstParseError> {
+    if is_separator(parser, Separator::OpenBracket) {
+        return parser.nested(parse_array_pattern).map(Pattern::ArrayPattern);
+    }
+
+    if is_separator(parser, Separator::OpenCurlyBrace) {
+        return parser.nested(parse_object_pattern).map(Pattern::ObjectPattern);
+    }
+
+    Ok(Pattern::Identifier(parse_identifier(parser)?))
+}
+
+/// Parses `...target`, the caller checks that nothing follows it
+pub fn parse_rest_element(parser: &mut AstParser) -> Result<RestElement, AstParseError> {
+    let start = get_current_token_span(parser)?;
+
+    // Skip the ellipsis
+    parser.next();
+
+    let argument = Box::new(parse_binding_pattern(parser)?);
+    let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+    Ok(RestElement { argument, span })
+}
+
+pub fn is_rest_element(parser: &AstParser) -> bool {
+    is_separator(parser, Separator::Ellipsis)
+}
+
+// https://262.ecma-international.org/13.0/#sec-static-semantics-coveredformalslist
+/// Converts an expression parsed before `=>` into the binding pattern it covers, e.g. the array literal `[a, ...b]`
+/// into an array pattern. The error is the span of the part that can't be bound
+pub fn binding_pattern_from_expression(expression: Expression) -> Result<Pattern, Span> {
+    match expression {
+        Expression::Identifier(IdentifierExpression { identifier }) => Ok(Pattern::Identifier(identifier)),
+        Expression::ArrayExpression(array) => {
+            let last = array.elements.len().saturating_sub(1);
+            let mut elements = Vec::with_capacity(array.elements.len());
+
+            for (index, element) in array.elements.into_iter().enumerate() {
+                let element = match element {
+                    None => None,
+                    Some(ExpressionOrSpread::Expression(expression)) => Some(binding_pattern_from_expression(expression)?),
+                    // Only the last element can collect the rest
+                    Some(ExpressionOrSpread::Spread(spread)) if index == last => {
+                        let argument = Box::new(binding_pattern_from_expression(*spread.argument)?);
+
+                        Some(Pattern::RestElement(RestElement { argument, span: spread.span }))
+                    },
+                    Some(ExpressionOrSpread::Spread(spread)) => return Err(spread.span),
+                };
+
+                elements.push(element);
+            }
+
+            Ok(Pattern::ArrayPattern(ArrayPattern { elements, span: array.span }))
+        },
+        Expression::ObjectExpression(object) => {
+            let last = object.properties.len().saturating_sub(1);
+            let mut properties = Vec::with_capacity(object.properties.len());
+
+            for (index, property) in object.properties.into_iter().enumerate() {
+                let property = match property {
+                    ObjectProperty::Property(property) => {
+                        let value = Box::new(binding_pattern_from_expression(*property.value)?);
+
+                        ObjectPatternProperty::Property(AssignmentProperty {
+                            key: property.key, value, computed: property.computed, shorthand: property.shorthand, span: property.span,
+                        })
+                    },
+                    // A rest property can't be destructured any further
+                    ObjectProperty::Spread(spread) if index == last && matches!(*spread.argument, Expression::Identifier(_)) => {
+                        let argument = Box::new(binding_pattern_from_expression(*spread.argument)?);
+
+                        ObjectPatternProperty::Rest(RestElement { argument, span: spread.span })
+                    },
+                    ObjectProperty::Spread(spread) => return Err(spread.span),
+                };
+
+                properties.push(property);
+            }
+
+            Ok(Pattern::ObjectPattern(ObjectPattern { properties, span: object.span }))
+        },
+        expression => Err(expression.span().clone()),
+    }
+}
+
+fn parse_array_pattern(parser: &mut AstParser) -> Result<ArrayPattern, AstParseError> {
+    let open = get_current_token_span(parser)?;
+    let mut elements = Vec::new();
+
+    // Skip the opening bracket
+    parser.next();
+
+    // Holes work like in an array literal, `[a, , b,]` has three elements
+    while !is_separator(parser, Separator::CloseBracket) {
+        if is_separator(parser, Separator::Comma) {
+            elements.push(None);
+            parser.next();
+            continue;
+        }
+
+        if is_rest_element(parser) {
+            elements.push(Some(Pattern::RestElement(parse_rest_element(parser)?)));
+
+            // Nothing can follow the rest element, not even a trailing comma
+            if !is_separator(parser, Separator::CloseBracket) {
+                let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+                    .with_expected([Expected::Token("]")])
+                    .with_help("a rest element has to be the last element of an array pattern", None);
+
+                return Err(error);
+            }
+
+            break;
+        }
+
+        elements.push(Some(parse_binding_pattern(parser)?));
+
+        if is_separator(parser, Separator::CloseBracket) { break; }
+
+        if !is_separator(parser, Separator::Comma) {
+            let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+                .with_expected([Expected::Token("]"), Expected::Token(",")])
+                .with_help("missing `]` to close array pattern started here", Some(open));
+
+            return Err(error);
+        }
+
+        parser.next();
+    }
+
+    let span = Span::between(&open, &get_current_token_span(parser)?);
+    parser.next();
+
+    Ok(ArrayPattern { elements, span })
+}
+
+fn parse_object_pattern(parser: &mut AstParser) -> Result<ObjectPattern, AstParseError> {
+    let open = get_current_token_span(parser)?;
+    let mut properties = Vec::new();
+
+    // Skip the opening curly brace
+    parser.next();
+
+    while !is_separator(parser, Separator::CloseCurlyBrace) {
+        if is_rest_element(parser) {
+            // The remaining properties are collected into a new object, which can't be destructured any further
+            let index = parser.get_current_index() + 1;
+
+            if !parser.token_at(index).is_some_and(|token| is_identifier_token(&token)) {
+                return ast_error!(AstErrorType::UnexpectedToken, parser, index, [Expected::Construct("an identifier")]);
+            }
+
+            properties.push(ObjectPatternProperty::Rest(parse_rest_element(parser)?));
+
+            if !is_separator(parser, Separator::CloseCurlyBrace) {
+                let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+                    .with_expected([Expected::Token("}")])
+                    .with_help("a rest property has to be the last property of an object pattern", None);
+
+                return Err(error);
+            }
+
+            break;
+        }
+
+        properties.push(ObjectPatternProperty::Property(parse_assignment_property(parser)?));
+
+        if is_separator(parser, Separator::CloseCurlyBrace) { break; }
+
+        if !is_separator(parser, Separator::Comma) {
+            let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+                .with_expected([Expected::Token("}"), Expected::Token(",")])
+                .with_help("missing `}` to close object pattern started here", Some(open));
+
+            return Err(error);
+        }
+
+        parser.next();
+    }
+
+    let span = Span::between(&open, &get_current_token_span(parser)?);
+    parser.next();
+
+    Ok(ObjectPattern { properties, span })
+}
+
+// `key: target`, `[key]: target` or the shorthand `name`. Default values aren't supported yet
+fn parse_assignment_property(parser: &mut AstParser) -> Result<AssignmentProperty, AstParseError> {
+    let start = get_current_token_span(parser)?;
+    let computed = is_separator(parser, Separator::OpenBracket);
+
+    // Only a plain identifier can be a shorthand property, `{ if }` can't be bound
+    let identifier_key = parser.token().is_some_and(|token| is_identifier_token(&token));
+
+    let key = parse_property_key(parser)?;
+
+    if is_separator(parser, Separator::Colon) {
+        parser.next();
+
+        let value = Box::new(parse_binding_pattern(parser)?);
+        let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+        return Ok(AssignmentProperty { key: Box::new(key), value, computed, shorthand: false, span });
+    }
+
+    match key {
+        Expression::Identifier(IdentifierExpression { identifier }) if identifier_key => {
+            let value = Identifier { name: identifier.name.clone(), span: identifier.span.clone() };
+            let key = Box::new(Expression::Identifier(IdentifierExpression { identifier }));
+
+            Ok(AssignmentProperty { key, value: Box::new(Pattern::Identifier(value)), computed, shorthand: true, span: start })
+        },
+        _ => ast_error!(AstErrorType::UnexpectedToken, parser, parser.get_current_index(), [Expected::Token(":")]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tokenizer::Tokenizer, ast::{parser::AstParser, nodes::{pattern::{Pattern, ObjectPatternProperty}, expression_statement::Expression}}};
+
+    use super::parse_binding_pattern;
+
+    macro_rules! invalid_binding_pattern_tests {
+        ($($name:ident: $input:expr => $index:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let mut parser = AstParser::new(Tokenizer::new($input));
+
+                let error = parse_binding_pattern(&mut parser).unwrap_err();
+
+                assert_eq!(error.index, $index);
+            }
+        )*
+        };
+    }
+
+    #[test]
+    fn array_pattern_has_holes_and_a_rest_element() {
+        let mut parser = AstParser::new(Tokenizer::new("[a, , [b], ...{ length }]"));
+
+        let Pattern::ArrayPattern(pattern) = parse_binding_pattern(&mut parser).unwrap() else { panic!("Expected an array pattern") };
+
+        assert_eq!(pattern.elements.len(), 4);
+        assert!(matches!(pattern.elements[0], Some(Pattern::Identifier(_))));
+        assert!(pattern.elements[1].is_none());
+        assert!(matches!(pattern.elements[2], Some(Pattern::ArrayPattern(_))));
+
+        let Some(Pattern::RestElement(rest)) = &pattern.elements[3] else { panic!("Expected a rest element") };
+
+        assert!(matches!(*rest.argument, Pattern::ObjectPattern(_)));
+        assert_eq!(pattern.span.range, (0, 25));
+        assert!(!parser.has_tokens());
+    }
+
+    #[test]
+    fn object_pattern_has_its_properties() {
+        let mut parser = AstParser::new(Tokenizer::new("{ a, b: [c], [k]: d, 'e': f, ...rest }"));
+
+        let Pattern::ObjectPattern(pattern) = parse_binding_pattern(&mut parser).unwrap() else { panic!("Expected an object pattern") };
+
+        let [
+            ObjectPatternProperty::Property(shorthand),
+            ObjectPatternProperty::Property(nested),
+            ObjectPatternProperty::Property(computed),
+            ObjectPatternProperty::Property(string),
+            ObjectPatternProperty::Rest(rest),
+        ] = &pattern.properties[..] else { panic!("Expected four properties and a rest property") };
+
+        assert!(shorthand.shorthand);
+        assert!(matches!(*shorthand.value, Pattern::Identifier(_)));
+        assert!(matches!(*nested.value, Pattern::ArrayPattern(_)));
+        assert!(computed.computed);
+        assert!(matches!(*string.key, Expression::Literal(_)));
+        assert!(matches!(*rest.argument, Pattern::Identifier(_)));
+        assert_eq!(rest.span.range, (29, 36));
+    }
+
+    #[test]
+    fn rest_element_has_to_be_last() {
+        let mut parser = AstParser::new(Tokenizer::new("[...a, b]"));
+
+        let error = parse_binding_pattern(&mut parser).unwrap_err();
+
+        assert_eq!(error.index, 3);
+        assert_eq!(error.help.unwrap().message, "a rest element has to be the last element of an array pattern");
+    }
+
+    #[test]
+    fn rest_property_has_to_be_last() {
+        let mut parser = AstParser::new(Tokenizer::new("{ ...a, }"));
+
+        let error = parse_binding_pattern(&mut parser).unwrap_err();
+
+        assert_eq!(error.index, 3);
+        assert_eq!(error.help.unwrap().message, "a rest property has to be the last property of an object pattern");
+    }
+
+    invalid_binding_pattern_tests! {
+        rest_property_can_not_be_a_pattern: "{ ...[a] }" => 2,
+        shorthand_property_has_to_be_an_identifier: "{ if }" => 2,
+        literal_is_not_a_binding: "[1]" => 1,
+        unclosed_array_pattern: "[a b]" => 2,
+        unclosed_object_pattern: "{ a b }" => 2,
+    }
+}
@@ -1,16 +1,11 @@
-use crate::{ast::{parser::AstParser, nodes::Identifier, AstParseError}, tokenizer::{TokenType, Separator}};
+use crate::{ast::{span::Span, parser::AstParser, nodes::{AstNode, Identifier, for_statement::EmptyStatement}, AstParseError, AstErrorType, Expected}, tokenizer::{Token, TokenType, Keyword, Separator}, ast_error};
 
-use super::parts::identifier::parse_identifier;
+use super::{function_declaration::is_function_declaration, variable_declaration::is_variable_declaration};
 
 // Parsing util functions
 pub fn is_open_param_bracket(parser: &AstParser, index: usize) -> bool {
     match parser.token_at(index) {
-        Some(token) => {
-            let is_type = matches!(token.token_type, TokenType::Separator(Separator::Parenthesis));
-            let is_value = token.value == "(";
-
-            is_type && is_value
-        },
+        Some(token) => matches!(token.token_type, TokenType::Separator(Separator::OpenParenthesis)),
         None => false,
     }
 }
@@ -24,18 +19,36 @@ pub fn is_param_separator(parser: &AstParser, index: usize) -> bool {
 
 pub fn is_closed_param_bracket(parser: &AstParser, index: usize) -> bool {
     match parser.token_at(index) {
-        Some(token) => {
-            let is_type = matches!(token.token_type, TokenType::Separator(Separator::Parenthesis));
-            let is_value = token.value == ")";
+        Some(token) => matches!(token.token_type, TokenType::Separator(Separator::CloseParenthesis)),
+        None => false,
+    }
+}
 
-            is_type && is_value
-        },
+pub fn is_separator(parser: &AstParser, separator: Separator) -> bool {
+    match parser.token() {
+        Some(token) => token.token_type == TokenType::Separator(separator),
         None => false,
     }
 }
 
+/// Whether the token is an identifier. `await` and `yield` are only reserved in async functions and generators, which
+/// aren't supported yet, so they're identifiers everywhere else
+pub fn is_identifier_token(token: &Token) -> bool {
+    matches!(token.token_type, TokenType::Identifier | TokenType::Keyword(Keyword::Await | Keyword::Yield))
+}
+
+pub fn parse_identifier(parser: &mut AstParser) -> Result<Identifier, AstParseError> {
+    match parser.token() {
+        Some(token) if is_identifier_token(&token) => {
+            parser.next();
+            Ok(Identifier::from(&*token))
+        },
+        _ => ast_error!(AstErrorType::UnexpectedToken, parser, parser.get_current_index(), [Expected::Construct("an identifier")]),
+    }
+}
+
 pub fn parse_function_name(parser: &mut AstParser) -> Result<Identifier, AstParseError> {
-    Ok(parse_identifier(parser, parser.get_current_index(), &mut 0)?.identifier)
+    parse_identifier(parser)
 }
 
 pub fn is_semicolon_terminator(parser: &AstParser) -> bool {
@@ -46,3 +59,61 @@ pub fn is_semicolon_terminator(parser: &AstParser) -> bool {
         None => false,
     }
 }
+
+/// Ends the statement at `index` and returns the number of tokens that ends it, 1 for a `;` and 0 when a semicolon is
+/// inserted
+pub fn parse_statement_end(parser: &AstParser, index: usize) -> Result<usize, AstParseError> {
+    match parser.token_at(index) {
+        Some(token) if matches!(token.token_type, TokenType::Separator(Separator::Terminator)) => Ok(1),
+        _ if parser.can_insert_automatic_semicolon(index) => Ok(0),
+        _ => ast_error!(AstErrorType::UnexpectedToken, parser, index, [Expected::Token(";")]),
+    }
+}
+
+/// Ends the statement at the current token and consumes its semicolon, if it has one
+pub fn consume_statement_end(parser: &mut AstParser) -> Result<(), AstParseError> {
+    let terminator = parse_statement_end(parser, parser.get_current_index())?;
+    parser.consume_range(terminator);
+
+    Ok(())
+}
+
+/// Parses a lone `;`, the caller checks that it's there
+pub fn parse_empty_statement(parser: &mut AstParser) -> EmptyStatement {
+    let span = Span::from(&*parser.token().unwrap());
+    parser.next();
+
+    EmptyStatement { span }
+}
+
+// `var` declares a variable of the function, `let` and `const` would declare one for the loop body alone
+fn is_lexical_declaration(parser: &AstParser) -> bool {
+    is_variable_declaration(parser) && parser.token().is_some_and(|token| token.token_type != TokenType::Keyword(Keyword::Var))
+}
+
+/// Parses the body of a loop, it's a statement so a declaration has to be wrapped in a block
+pub fn parse_loop_body(parser: &mut AstParser) -> Result<Box<AstNode>, AstParseError> {
+    let index = parser.get_current_index();
+
+    if !parser.has_tokens() {
+        return ast_error!(AstErrorType::UnexpectedToken, parser, index, [Expected::Construct("a statement")]);
+    }
+
+    if is_function_declaration(parser) {
+        let error = parser.error_at(index, AstErrorType::UnexpectedToken)
+            .with_expected([Expected::Construct("a statement")])
+            .with_help("a function declaration can't be the body of a loop, wrap it in a block", None);
+
+        return Err(error);
+    }
+
+    if is_lexical_declaration(parser) {
+        let error = parser.error_at(index, AstErrorType::UnexpectedToken)
+            .with_expected([Expected::Construct("a statement")])
+            .with_help("a `let` or `const` declaration can't be the body of a loop, wrap it in a block", None);
+
+        return Err(error);
+    }
+
+    Ok(Box::new(parser.parse_statement()?))
+}
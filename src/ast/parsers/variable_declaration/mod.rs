@@ -0,0 +1,219 @@
+use crate::{ast::{span::Span, parser::AstParser, nodes::{pattern::Pattern, variable_declaration::{VariableDeclaration, VariableDeclarator, VariableKind}}, AstParseError, AstErrorType, Expected}, tokenizer::{TokenType, Keyword, Operator, Separator}, ast_error};
+
+use super::{get_current_token_span, get_previous_token_span, expressions::{parse_assignment_expression, parse_assignment_expression_without_in}, patterns::parse_binding_pattern, util::{is_separator, is_identifier_token, consume_statement_end}};
+
+/// Whether a `var`, `let` or `const` declaration starts at the current token. `let` isn't a reserved word, it only
+/// starts a declaration when a binding follows it, `let in obj` uses a variable named `let`
+pub fn is_variable_declaration(parser: &AstParser) -> bool {
+    let Some(token) = parser.token() else { return false };
+
+    match token.token_type {
+        TokenType::Keyword(Keyword::Var | Keyword::Const) => true,
+        TokenType::Identifier if token.raw_value == "let" => {
+            let next = parser.token_at(parser.get_current_index() + 1);
+
+            matches!(next, Some(next) if is_identifier_token(&next) || matches!(next.token_type,
+                TokenType::Separator(Separator::OpenBracket | Separator::OpenCurlyBrace)
+            ))
+        },
+        _ => false,
+    }
+}
+
+/// Parses the declaration up to its last declarator, ending it is up to the caller. `in` is only an operator in the
+/// initializers when `allow_in` is set, it ends the declaration in the head of a `for…in` loop
+pub fn parse_variable_declaration(parser: &mut AstParser, allow_in: bool) -> Result<VariableDeclaration, AstParseError> {
+    let Some(token) = parser.token() else { return ast_error!(AstErrorType::UnexpectedToken, parser) };
+
+    let start = Span::from(&*token);
+
+    let kind = match token.token_type {
+        TokenType::Keyword(Keyword::Var) => VariableKind::Var,
+        TokenType::Keyword(Keyword::Const) => VariableKind::Const,
+        _ => VariableKind::Let,
+    };
+
+    // Skip the keyword
+    parser.next();
+
+    let mut declarations = vec![parse_variable_declarator(parser, allow_in)?];
+
+    while is_separator(parser, Separator::Comma) {
+        parser.next();
+        declarations.push(parse_variable_declarator(parser, allow_in)?);
+    }
+
+    let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+    Ok(VariableDeclaration { kind, declarations, span })
+}
+
+// Only the declaration of a `for…in` or `for…of` loop gets its value from the loop, a `const` variable or a pattern
+// needs an initializer everywhere else
+pub fn check_initializers(parser: &AstParser, declaration: &VariableDeclaration) -> Result<(), AstParseError> {
+    for declarator in declaration.declarations.iter().filter(|declarator| declarator.init.is_none()) {
+        let help = match declarator.id {
+            Pattern::ArrayPattern(_) | Pattern::ObjectPattern(_) => "a destructuring declaration has to be initialized",
+            _ if declaration.kind == VariableKind::Const => "a `const` variable has to be initialized",
+            _ => continue,
+        };
+
+        let error = parser.error_at(parser.get_current_index(), AstErrorType::UnexpectedToken)
+            .with_expected([Expected::Token("=")])
+            .with_help(help, Some(declarator.span.clone()));
+
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Parses a declaration as a statement, unlike in the head of a `for` loop it ends with a semicolon that is part of it
+pub fn parse_variable_statement(parser: &mut AstParser) -> Result<VariableDeclaration, AstParseError> {
+    let mut declaration = parse_variable_declaration(parser, true)?;
+
+    check_initializers(parser, &declaration)?;
+    consume_statement_end(parser)?;
+    declaration.span = Span::between(&declaration.span, &get_previous_token_span(parser)?);
+
+    Ok(declaration)
+}
+
+fn parse_variable_declarator(parser: &mut AstParser, allow_in: bool) -> Result<VariableDeclarator, AstParseError> {
+    let start = get_current_token_span(parser)?;
+    let id = parse_binding_pattern(parser)?;
+
+    let init = if is_assign(parser) {
+        parser.next();
+
+        if allow_in {
+            Some(parse_assignment_expression(parser)?)
+        } else {
+            Some(parse_assignment_expression_without_in(parser)?)
+        }
+    } else {
+        None
+    };
+
+    let span = Span::between(&start, &get_previous_token_span(parser)?);
+
+    Ok(VariableDeclarator { id, init, span })
+}
+
+fn is_assign(parser: &AstParser) -> bool {
+    match parser.token() {
+        Some(token) => token.token_type == TokenType::Operator(Operator::Assign),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tokenizer::Tokenizer, ast::{parser::AstParser, nodes::{pattern::Pattern, variable_declaration::VariableKind, expression_statement::Expression}}};
+
+    use super::{is_variable_declaration, parse_variable_declaration, parse_variable_statement};
+
+    macro_rules! is_variable_declaration_tests {
+        ($($name:ident: $content:expr => $expected:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let parser = AstParser::new(Tokenizer::new($content));
+
+                assert_eq!(is_variable_declaration(&parser), $expected);
+            }
+        )*
+        };
+    }
+
+    is_variable_declaration_tests! {
+        var_starts_a_declaration: "var a" => true,
+        const_starts_a_declaration: "const a" => true,
+        let_followed_by_a_binding_starts_a_declaration: "let a" => true,
+        let_followed_by_yield_starts_a_declaration: "let yield" => true,
+        let_followed_by_a_bracket_starts_a_declaration: "let [a]" => true,
+        let_followed_by_a_curly_brace_starts_a_declaration: "let { a }" => true,
+        let_followed_by_in_is_a_variable: "let in a" => false,
+        let_on_its_own_is_a_variable: "let" => false,
+        identifier_is_not_a_declaration: "a" => false,
+    }
+
+    #[test]
+    fn declarators_are_parsed() {
+        let mut parser = AstParser::new(Tokenizer::new("let a = 1, b"));
+
+        let declaration = parse_variable_declaration(&mut parser, true).unwrap();
+
+        assert_eq!(declaration.kind, VariableKind::Let);
+        assert_eq!(declaration.declarations.len(), 2);
+        assert_eq!(declaration.span.range, (0, 12));
+
+        let Pattern::Identifier(id) = &declaration.declarations[0].id else { panic!("Expected an identifier") };
+
+        assert_eq!(id.name, "a");
+        assert_eq!(declaration.declarations[0].span.range, (4, 9));
+        assert!(declaration.declarations[1].init.is_none());
+    }
+
+    #[test]
+    fn destructuring_declarators_are_parsed() {
+        let mut parser = AstParser::new(Tokenizer::new("let [a, ...b] = c, { d, ...e } = f"));
+
+        let declaration = parse_variable_declaration(&mut parser, true).unwrap();
+
+        assert!(matches!(declaration.declarations[0].id, Pattern::ArrayPattern(_)));
+        assert!(matches!(declaration.declarations[1].id, Pattern::ObjectPattern(_)));
+        assert_eq!(declaration.declarations[1].span.range, (19, 34));
+        assert!(!parser.has_tokens());
+    }
+
+    #[test]
+    fn initializer_without_in_ends_before_in() {
+        let mut parser = AstParser::new(Tokenizer::new("var a = b in c"));
+
+        let declaration = parse_variable_declaration(&mut parser, false).unwrap();
+
+        assert!(matches!(declaration.declarations[0].init, Some(Expression::Identifier(_))));
+        assert_eq!(parser.token().unwrap().value, "in");
+    }
+
+    #[test]
+    fn initializer_with_in_has_an_in_operator() {
+        let mut parser = AstParser::new(Tokenizer::new("var a = b in c"));
+
+        let declaration = parse_variable_declaration(&mut parser, true).unwrap();
+
+        assert!(matches!(declaration.declarations[0].init, Some(Expression::BinaryExpression(_))));
+        assert!(!parser.has_tokens());
+    }
+
+    #[test]
+    fn declaration_needs_a_binding() {
+        let mut parser = AstParser::new(Tokenizer::new("const = 1"));
+
+        let error = parse_variable_declaration(&mut parser, true).unwrap_err();
+
+        assert_eq!(error.to_string(), "Unexpected token `=`, expected an identifier");
+    }
+
+    #[test]
+    fn statement_includes_its_semicolon() {
+        let mut parser = AstParser::new(Tokenizer::new("var x = 1; x"));
+
+        let declaration = parse_variable_statement(&mut parser).unwrap();
+
+        assert_eq!(declaration.kind, VariableKind::Var);
+        assert_eq!(declaration.span.range, (0, 10));
+        assert_eq!(parser.token().unwrap().value, "x");
+    }
+
+    #[test]
+    fn const_statement_needs_an_initializer() {
+        let mut parser = AstParser::new(Tokenizer::new("const x;"));
+
+        let error = parse_variable_statement(&mut parser).unwrap_err();
+
+        assert_eq!(error.to_string(), "Unexpected token `;`, expected `=`");
+        assert_eq!(error.help.unwrap().message, "a `const` variable has to be initialized");
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{source::{SourceFile, OffsetEncoding}, tokenizer::{Token, Trivia, FileLocation}};
+
+/// The part of the source a node was parsed from, in the offset encoding of the tokenizer
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    pub range: (usize, usize),
+    pub loc: FileLocation,
+}
+
+impl Span {
+    /// A span that starts at `start` and ends where `end` ends
+    pub fn between(start: &Span, end: &Span) -> Span {
+        Span {
+            range: (start.range.0, end.range.1),
+            loc: FileLocation { start: start.loc.start.clone(), end: end.loc.end.clone() },
+        }
+    }
+
+    /// Maps the span back to the text it covers
+    pub fn source_text<'src>(&self, source: &SourceFile<'src>, encoding: OffsetEncoding) -> &'src str {
+        let start = source.convert(self.range.0, encoding, OffsetEncoding::Byte);
+        let end = source.convert(self.range.1, encoding, OffsetEncoding::Byte);
+
+        &source.content()[start..end]
+    }
+}
+
+impl From<&Token<'_>> for Span {
+    fn from(token: &Token<'_>) -> Self {
+        Span { range: token.range, loc: token.loc.clone() }
+    }
+}
+
+impl From<&Trivia<'_>> for Span {
+    fn from(trivia: &Trivia<'_>) -> Self {
+        Span { range: trivia.range, loc: trivia.loc.clone() }
+    }
+}
+
+/// Implemented by every node, so tools can map any node back to its source
+pub trait Spanned {
+    fn span(&self) -> &Span;
+
+    fn range(&self) -> (usize, usize) {
+        self.span().range
+    }
+
+    fn loc(&self) -> &FileLocation {
+        &self.span().loc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{source::{SourceFile, OffsetEncoding}, tokenizer::Tokenizer};
+
+    use super::Span;
+
+    #[test]
+    fn span_between_tokens_covers_both() {
+        let content = String::from("foo\n  bar");
+        let tokens: Vec<_> = Tokenizer::new(&content).map(Result::unwrap).collect();
+
+        let span = Span::between(&Span::from(&tokens[0]), &Span::from(&tokens[1]));
+
+        assert_eq!(span.range, (0, 9));
+        assert_eq!(span.loc.start.line, 1);
+        assert_eq!(span.loc.start.column, 0);
+        assert_eq!(span.loc.end.line, 2);
+        assert_eq!(span.loc.end.column, 5);
+    }
+
+    #[test]
+    fn span_maps_back_to_source_text() {
+        let content = String::from("'héllo' + wörld");
+        let tokens: Vec<_> = Tokenizer::new(&content).map(Result::unwrap).collect();
+        let source = SourceFile::new(&content);
+
+        let span = Span::from(&tokens[2]);
+
+        assert_eq!(span.source_text(&source, OffsetEncoding::Char), "wörld");
+    }
+}
@@ -0,0 +1,430 @@
+use crate::ast::{Program, nodes::{AstNode, error::ErrorNode, Literal, Identifier, Comment, block_statement::BlockStatement, function_declaration::FunctionDeclaration, jump_statement::{ReturnStatement, ThrowStatement, BreakStatement, ContinueStatement}, for_statement::{ForStatement, ForInit, ForInStatement, ForOfStatement, ForLeft, EmptyStatement}, do_while_statement::DoWhileStatement, variable_declaration::{VariableDeclaration, VariableDeclarator}, pattern::{Pattern, ArrayPattern, ObjectPattern, ObjectPatternProperty, AssignmentProperty, RestElement}, expression_statement::{Expression, ExpressionStatement, LiteralExpression, IdentifierExpression, BinaryExpression, LogicalExpression, UpdateExpression, ArrowFunctionExpression, ArrowFunctionBody, CallExpression, MemberExpression, ChainExpression, ObjectExpression, ObjectProperty, Property, ArrayExpression, ExpressionOrSpread, SpreadElement, SequenceExpression}}};
+
+/// Takes the tree by value and rebuilds it, a method can return a different kind of node than it was given,
+/// e.g. `fold_expression` can replace a binary expression with a literal
+pub trait Fold {
+    fn fold_program(&mut self, node: Program) -> Program { walk_program(self, node) }
+    fn fold_comment(&mut self, node: Comment) -> Comment { node }
+    fn fold_statement(&mut self, node: AstNode) -> AstNode { walk_statement(self, node) }
+    fn fold_expression_statement(&mut self, node: ExpressionStatement) -> ExpressionStatement { walk_expression_statement(self, node) }
+    fn fold_block_statement(&mut self, node: BlockStatement) -> BlockStatement { walk_block_statement(self, node) }
+    fn fold_function_declaration(&mut self, node: FunctionDeclaration) -> FunctionDeclaration { walk_function_declaration(self, node) }
+    fn fold_return_statement(&mut self, node: ReturnStatement) -> ReturnStatement { walk_return_statement(self, node) }
+    fn fold_throw_statement(&mut self, node: ThrowStatement) -> ThrowStatement { walk_throw_statement(self, node) }
+    fn fold_break_statement(&mut self, node: BreakStatement) -> BreakStatement { walk_break_statement(self, node) }
+    fn fold_continue_statement(&mut self, node: ContinueStatement) -> ContinueStatement { walk_continue_statement(self, node) }
+    fn fold_for_statement(&mut self, node: ForStatement) -> ForStatement { walk_for_statement(self, node) }
+    fn fold_for_init(&mut self, node: ForInit) -> ForInit { walk_for_init(self, node) }
+    fn fold_for_in_statement(&mut self, node: ForInStatement) -> ForInStatement { walk_for_in_statement(self, node) }
+    fn fold_for_of_statement(&mut self, node: ForOfStatement) -> ForOfStatement { walk_for_of_statement(self, node) }
+    fn fold_for_left(&mut self, node: ForLeft) -> ForLeft { walk_for_left(self, node) }
+    fn fold_do_while_statement(&mut self, node: DoWhileStatement) -> DoWhileStatement { walk_do_while_statement(self, node) }
+    fn fold_empty_statement(&mut self, node: EmptyStatement) -> EmptyStatement { node }
+    fn fold_error(&mut self, node: ErrorNode) -> ErrorNode { node }
+    fn fold_variable_declaration(&mut self, node: VariableDeclaration) -> VariableDeclaration { walk_variable_declaration(self, node) }
+    fn fold_variable_declarator(&mut self, node: VariableDeclarator) -> VariableDeclarator { walk_variable_declarator(self, node) }
+    fn fold_expression(&mut self, node: Expression) -> Expression { walk_expression(self, node) }
+    fn fold_identifier_expression(&mut self, node: IdentifierExpression) -> IdentifierExpression { walk_identifier_expression(self, node) }
+    fn fold_literal_expression(&mut self, node: LiteralExpression) -> LiteralExpression { walk_literal_expression(self, node) }
+    fn fold_binary_expression(&mut self, node: BinaryExpression) -> BinaryExpression { walk_binary_expression(self, node) }
+    fn fold_logical_expression(&mut self, node: LogicalExpression) -> LogicalExpression { walk_logical_expression(self, node) }
+    fn fold_update_expression(&mut self, node: UpdateExpression) -> UpdateExpression { walk_update_expression(self, node) }
+    fn fold_arrow_function_expression(&mut self, node: ArrowFunctionExpression) -> ArrowFunctionExpression { walk_arrow_function_expression(self, node) }
+    fn fold_call_expression(&mut self, node: CallExpression) -> CallExpression { walk_call_expression(self, node) }
+    fn fold_member_expression(&mut self, node: MemberExpression) -> MemberExpression { walk_member_expression(self, node) }
+    fn fold_chain_expression(&mut self, node: ChainExpression) -> ChainExpression { walk_chain_expression(self, node) }
+    fn fold_object_expression(&mut self, node: ObjectExpression) -> ObjectExpression { walk_object_expression(self, node) }
+    fn fold_object_property(&mut self, node: ObjectProperty) -> ObjectProperty { walk_object_property(self, node) }
+    fn fold_property(&mut self, node: Property) -> Property { walk_property(self, node) }
+    fn fold_array_expression(&mut self, node: ArrayExpression) -> ArrayExpression { walk_array_expression(self, node) }
+    fn fold_expression_or_spread(&mut self, node: ExpressionOrSpread) -> ExpressionOrSpread { walk_expression_or_spread(self, node) }
+    fn fold_spread_element(&mut self, node: SpreadElement) -> SpreadElement { walk_spread_element(self, node) }
+    fn fold_sequence_expression(&mut self, node: SequenceExpression) -> SequenceExpression { walk_sequence_expression(self, node) }
+    fn fold_pattern(&mut self, node: Pattern) -> Pattern { walk_pattern(self, node) }
+    fn fold_array_pattern(&mut self, node: ArrayPattern) -> ArrayPattern { walk_array_pattern(self, node) }
+    fn fold_object_pattern(&mut self, node: ObjectPattern) -> ObjectPattern { walk_object_pattern(self, node) }
+    fn fold_object_pattern_property(&mut self, node: ObjectPatternProperty) -> ObjectPatternProperty { walk_object_pattern_property(self, node) }
+    fn fold_assignment_property(&mut self, node: AssignmentProperty) -> AssignmentProperty { walk_assignment_property(self, node) }
+    fn fold_rest_element(&mut self, node: RestElement) -> RestElement { walk_rest_element(self, node) }
+    fn fold_identifier(&mut self, node: Identifier) -> Identifier { node }
+    fn fold_literal(&mut self, node: Literal) -> Literal { node }
+}
+
+pub fn walk_program<F: Fold + ?Sized>(folder: &mut F, node: Program) -> Program {
+    Program {
+        body: node.body.into_iter().map(|statement| folder.fold_statement(statement)).collect(),
+        comments: node.comments.into_iter().map(|comment| folder.fold_comment(comment)).collect(),
+        span: node.span,
+        source_type: node.source_type,
+    }
+}
+
+pub fn walk_statement<F: Fold + ?Sized>(folder: &mut F, node: AstNode) -> AstNode {
+    match node {
+        AstNode::ExpressionStatement(statement) => AstNode::ExpressionStatement(folder.fold_expression_statement(statement)),
+        AstNode::BlockStatement(statement) => AstNode::BlockStatement(folder.fold_block_statement(statement)),
+        AstNode::FunctionDeclaration(declaration) => AstNode::FunctionDeclaration(folder.fold_function_declaration(declaration)),
+        AstNode::VariableDeclaration(declaration) => AstNode::VariableDeclaration(folder.fold_variable_declaration(declaration)),
+        AstNode::ReturnStatement(statement) => AstNode::ReturnStatement(folder.fold_return_statement(statement)),
+        AstNode::ThrowStatement(statement) => AstNode::ThrowStatement(folder.fold_throw_statement(statement)),
+        AstNode::BreakStatement(statement) => AstNode::BreakStatement(folder.fold_break_statement(statement)),
+        AstNode::ContinueStatement(statement) => AstNode::ContinueStatement(folder.fold_continue_statement(statement)),
+        AstNode::ForStatement(statement) => AstNode::ForStatement(folder.fold_for_statement(statement)),
+        AstNode::ForInStatement(statement) => AstNode::ForInStatement(folder.fold_for_in_statement(statement)),
+        AstNode::ForOfStatement(statement) => AstNode::ForOfStatement(folder.fold_for_of_statement(statement)),
+        AstNode::DoWhileStatement(statement) => AstNode::DoWhileStatement(folder.fold_do_while_statement(statement)),
+        AstNode::EmptyStatement(statement) => AstNode::EmptyStatement(folder.fold_empty_statement(statement)),
+        AstNode::Error(error) => AstNode::Error(folder.fold_error(error)),
+    }
+}
+
+pub fn walk_expression_statement<F: Fold + ?Sized>(folder: &mut F, node: ExpressionStatement) -> ExpressionStatement {
+    ExpressionStatement {
+        expression: folder.fold_expression(node.expression),
+        span: node.span,
+    }
+}
+
+pub fn walk_block_statement<F: Fold + ?Sized>(folder: &mut F, node: BlockStatement) -> BlockStatement {
+    BlockStatement {
+        body: node.body.into_iter().map(|statement| folder.fold_statement(statement)).collect(),
+        span: node.span,
+    }
+}
+
+pub fn walk_function_declaration<F: Fold + ?Sized>(folder: &mut F, node: FunctionDeclaration) -> FunctionDeclaration {
+    FunctionDeclaration {
+        id: folder.fold_identifier(node.id),
+        params: node.params.into_iter().map(|param| folder.fold_pattern(param)).collect(),
+        body: folder.fold_block_statement(node.body),
+        span: node.span,
+    }
+}
+
+pub fn walk_return_statement<F: Fold + ?Sized>(folder: &mut F, node: ReturnStatement) -> ReturnStatement {
+    ReturnStatement {
+        argument: node.argument.map(|argument| folder.fold_expression(argument)),
+        span: node.span,
+    }
+}
+
+pub fn walk_throw_statement<F: Fold + ?Sized>(folder: &mut F, node: ThrowStatement) -> ThrowStatement {
+    ThrowStatement {
+        argument: folder.fold_expression(node.argument),
+        span: node.span,
+    }
+}
+
+pub fn walk_break_statement<F: Fold + ?Sized>(folder: &mut F, node: BreakStatement) -> BreakStatement {
+    BreakStatement {
+        label: node.label.map(|label| folder.fold_identifier(label)),
+        span: node.span,
+    }
+}
+
+pub fn walk_continue_statement<F: Fold + ?Sized>(folder: &mut F, node: ContinueStatement) -> ContinueStatement {
+    ContinueStatement {
+        label: node.label.map(|label| folder.fold_identifier(label)),
+        span: node.span,
+    }
+}
+
+pub fn walk_for_statement<F: Fold + ?Sized>(folder: &mut F, node: ForStatement) -> ForStatement {
+    ForStatement {
+        init: node.init.map(|init| folder.fold_for_init(init)),
+        test: node.test.map(|test| folder.fold_expression(test)),
+        update: node.update.map(|update| folder.fold_expression(update)),
+        body: Box::new(folder.fold_statement(*node.body)),
+        span: node.span,
+    }
+}
+
+pub fn walk_for_init<F: Fold + ?Sized>(folder: &mut F, node: ForInit) -> ForInit {
+    match node {
+        ForInit::VariableDeclaration(declaration) => ForInit::VariableDeclaration(folder.fold_variable_declaration(declaration)),
+        ForInit::Expression(expression) => ForInit::Expression(folder.fold_expression(expression)),
+    }
+}
+
+pub fn walk_for_in_statement<F: Fold + ?Sized>(folder: &mut F, node: ForInStatement) -> ForInStatement {
+    ForInStatement {
+        left: folder.fold_for_left(node.left),
+        right: folder.fold_expression(node.right),
+        body: Box::new(folder.fold_statement(*node.body)),
+        span: node.span,
+    }
+}
+
+pub fn walk_for_of_statement<F: Fold + ?Sized>(folder: &mut F, node: ForOfStatement) -> ForOfStatement {
+    ForOfStatement {
+        left: folder.fold_for_left(node.left),
+        right: folder.fold_expression(node.right),
+        body: Box::new(folder.fold_statement(*node.body)),
+        span: node.span,
+    }
+}
+
+pub fn walk_for_left<F: Fold + ?Sized>(folder: &mut F, node: ForLeft) -> ForLeft {
+    match node {
+        ForLeft::VariableDeclaration(declaration) => ForLeft::VariableDeclaration(folder.fold_variable_declaration(declaration)),
+        ForLeft::Pattern(pattern) => ForLeft::Pattern(folder.fold_pattern(pattern)),
+    }
+}
+
+pub fn walk_do_while_statement<F: Fold + ?Sized>(folder: &mut F, node: DoWhileStatement) -> DoWhileStatement {
+    DoWhileStatement {
+        body: Box::new(folder.fold_statement(*node.body)),
+        test: folder.fold_expression(node.test),
+        span: node.span,
+    }
+}
+
+pub fn walk_variable_declaration<F: Fold + ?Sized>(folder: &mut F, node: VariableDeclaration) -> VariableDeclaration {
+    VariableDeclaration {
+        kind: node.kind,
+        declarations: node.declarations.into_iter().map(|declarator| folder.fold_variable_declarator(declarator)).collect(),
+        span: node.span,
+    }
+}
+
+pub fn walk_variable_declarator<F: Fold + ?Sized>(folder: &mut F, node: VariableDeclarator) -> VariableDeclarator {
+    VariableDeclarator {
+        id: folder.fold_pattern(node.id),
+        init: node.init.map(|init| folder.fold_expression(init)),
+        span: node.span,
+    }
+}
+
+pub fn walk_expression<F: Fold + ?Sized>(folder: &mut F, node: Expression) -> Expression {
+    match node {
+        Expression::Identifier(expression) => Expression::Identifier(folder.fold_identifier_expression(expression)),
+        Expression::Literal(expression) => Expression::Literal(folder.fold_literal_expression(expression)),
+        Expression::BinaryExpression(expression) => Expression::BinaryExpression(folder.fold_binary_expression(expression)),
+        Expression::LogicalExpression(expression) => Expression::LogicalExpression(folder.fold_logical_expression(expression)),
+        Expression::CallExpression(expression) => Expression::CallExpression(folder.fold_call_expression(expression)),
+        Expression::MemberExpression(expression) => Expression::MemberExpression(folder.fold_member_expression(expression)),
+        Expression::ChainExpression(expression) => Expression::ChainExpression(folder.fold_chain_expression(expression)),
+        Expression::ObjectExpression(expression) => Expression::ObjectExpression(folder.fold_object_expression(expression)),
+        Expression::SequenceExpression(expression) => Expression::SequenceExpression(folder.fold_sequence_expression(expression)),
+        Expression::ArrayExpression(expression) => Expression::ArrayExpression(folder.fold_array_expression(expression)),
+        Expression::UpdateExpression(expression) => Expression::UpdateExpression(folder.fold_update_expression(expression)),
+        Expression::ArrowFunctionExpression(expression) => Expression::ArrowFunctionExpression(folder.fold_arrow_function_expression(expression)),
+    }
+}
+
+pub fn walk_identifier_expression<F: Fold + ?Sized>(folder: &mut F, node: IdentifierExpression) -> IdentifierExpression {
+    IdentifierExpression { identifier: folder.fold_identifier(node.identifier) }
+}
+
+pub fn walk_literal_expression<F: Fold + ?Sized>(folder: &mut F, node: LiteralExpression) -> LiteralExpression {
+    LiteralExpression { value: folder.fold_literal(node.value) }
+}
+
+pub fn walk_binary_expression<F: Fold + ?Sized>(folder: &mut F, node: BinaryExpression) -> BinaryExpression {
+    BinaryExpression {
+        operator: node.operator,
+        left: Box::new(folder.fold_expression(*node.left)),
+        right: Box::new(folder.fold_expression(*node.right)),
+        span: node.span,
+    }
+}
+
+pub fn walk_logical_expression<F: Fold + ?Sized>(folder: &mut F, node: LogicalExpression) -> LogicalExpression {
+    LogicalExpression {
+        operator: node.operator,
+        left: Box::new(folder.fold_expression(*node.left)),
+        right: Box::new(folder.fold_expression(*node.right)),
+        span: node.span,
+    }
+}
+
+pub fn walk_update_expression<F: Fold + ?Sized>(folder: &mut F, node: UpdateExpression) -> UpdateExpression {
+    UpdateExpression {
+        operator: node.operator,
+        prefix: node.prefix,
+        argument: Box::new(folder.fold_expression(*node.argument)),
+        span: node.span,
+    }
+}
+
+pub fn walk_arrow_function_expression<F: Fold + ?Sized>(folder: &mut F, node: ArrowFunctionExpression) -> ArrowFunctionExpression {
+    let body = match node.body {
+        ArrowFunctionBody::BlockStatement(body) => ArrowFunctionBody::BlockStatement(Box::new(folder.fold_block_statement(*body))),
+        ArrowFunctionBody::Expression(body) => ArrowFunctionBody::Expression(Box::new(folder.fold_expression(*body))),
+    };
+
+    ArrowFunctionExpression {
+        params: node.params.into_iter().map(|param| folder.fold_pattern(param)).collect(),
+        body,
+        span: node.span,
+    }
+}
+
+pub fn walk_call_expression<F: Fold + ?Sized>(folder: &mut F, node: CallExpression) -> CallExpression {
+    CallExpression {
+        callee: Box::new(folder.fold_expression(*node.callee)),
+        arguments: node.arguments.into_iter().map(|argument| folder.fold_expression_or_spread(argument)).collect(),
+        optional: node.optional,
+        span: node.span,
+    }
+}
+
+pub fn walk_member_expression<F: Fold + ?Sized>(folder: &mut F, node: MemberExpression) -> MemberExpression {
+    MemberExpression {
+        object: Box::new(folder.fold_expression(*node.object)),
+        property: Box::new(folder.fold_expression(*node.property)),
+        computed: node.computed,
+        optional: node.optional,
+        span: node.span,
+    }
+}
+
+pub fn walk_chain_expression<F: Fold + ?Sized>(folder: &mut F, node: ChainExpression) -> ChainExpression {
+    ChainExpression {
+        expression: Box::new(folder.fold_expression(*node.expression)),
+        span: node.span,
+    }
+}
+
+pub fn walk_object_expression<F: Fold + ?Sized>(folder: &mut F, node: ObjectExpression) -> ObjectExpression {
+    ObjectExpression {
+        properties: node.properties.into_iter().map(|property| folder.fold_object_property(property)).collect(),
+        span: node.span,
+    }
+}
+
+pub fn walk_object_property<F: Fold + ?Sized>(folder: &mut F, node: ObjectProperty) -> ObjectProperty {
+    match node {
+        ObjectProperty::Property(property) => ObjectProperty::Property(folder.fold_property(property)),
+        ObjectProperty::Spread(spread) => ObjectProperty::Spread(folder.fold_spread_element(spread)),
+    }
+}
+
+pub fn walk_property<F: Fold + ?Sized>(folder: &mut F, node: Property) -> Property {
+    Property {
+        key: Box::new(folder.fold_expression(*node.key)),
+        value: Box::new(folder.fold_expression(*node.value)),
+        computed: node.computed,
+        shorthand: node.shorthand,
+        span: node.span,
+    }
+}
+
+pub fn walk_array_expression<F: Fold + ?Sized>(folder: &mut F, node: ArrayExpression) -> ArrayExpression {
+    ArrayExpression {
+        elements: node.elements.into_iter().map(|element| element.map(|element| folder.fold_expression_or_spread(element))).collect(),
+        span: node.span,
+    }
+}
+
+pub fn walk_expression_or_spread<F: Fold + ?Sized>(folder: &mut F, node: ExpressionOrSpread) -> ExpressionOrSpread {
+    match node {
+        ExpressionOrSpread::Expression(expression) => ExpressionOrSpread::Expression(folder.fold_expression(expression)),
+        ExpressionOrSpread::Spread(spread) => ExpressionOrSpread::Spread(folder.fold_spread_element(spread)),
+    }
+}
+
+pub fn walk_spread_element<F: Fold + ?Sized>(folder: &mut F, node: SpreadElement) -> SpreadElement {
+    SpreadElement { argument: Box::new(folder.fold_expression(*node.argument)), span: node.span }
+}
+
+pub fn walk_pattern<F: Fold + ?Sized>(folder: &mut F, node: Pattern) -> Pattern {
+    match node {
+        Pattern::Identifier(identifier) => Pattern::Identifier(folder.fold_identifier(identifier)),
+        Pattern::MemberExpression(expression) => Pattern::MemberExpression(folder.fold_member_expression(expression)),
+        Pattern::ArrayPattern(pattern) => Pattern::ArrayPattern(folder.fold_array_pattern(pattern)),
+        Pattern::ObjectPattern(pattern) => Pattern::ObjectPattern(folder.fold_object_pattern(pattern)),
+        Pattern::RestElement(element) => Pattern::RestElement(folder.fold_rest_element(element)),
+    }
+}
+
+pub fn walk_array_pattern<F: Fold + ?Sized>(folder: &mut F, node: ArrayPattern) -> ArrayPattern {
+    ArrayPattern {
+        elements: node.elements.into_iter().map(|element| element.map(|element| folder.fold_pattern(element))).collect(),
+        span: node.span,
+    }
+}
+
+pub fn walk_object_pattern<F: Fold + ?Sized>(folder: &mut F, node: ObjectPattern) -> ObjectPattern {
+    ObjectPattern {
+        properties: node.properties.into_iter().map(|property| folder.fold_object_pattern_property(property)).collect(),
+        span: node.span,
+    }
+}
+
+pub fn walk_object_pattern_property<F: Fold + ?Sized>(folder: &mut F, node: ObjectPatternProperty) -> ObjectPatternProperty {
+    match node {
+        ObjectPatternProperty::Property(property) => ObjectPatternProperty::Property(folder.fold_assignment_property(property)),
+        ObjectPatternProperty::Rest(rest) => ObjectPatternProperty::Rest(folder.fold_rest_element(rest)),
+    }
+}
+
+pub fn walk_assignment_property<F: Fold + ?Sized>(folder: &mut F, node: AssignmentProperty) -> AssignmentProperty {
+    AssignmentProperty {
+        key: Box::new(folder.fold_expression(*node.key)),
+        value: Box::new(folder.fold_pattern(*node.value)),
+        computed: node.computed,
+        shorthand: node.shorthand,
+        span: node.span,
+    }
+}
+
+pub fn walk_rest_element<F: Fold + ?Sized>(folder: &mut F, node: RestElement) -> RestElement {
+    RestElement { argument: Box::new(folder.fold_pattern(*node.argument)), span: node.span }
+}
+
+pub fn walk_sequence_expression<F: Fold + ?Sized>(folder: &mut F, node: SequenceExpression) -> SequenceExpression {
+    SequenceExpression {
+        expressions: node.expressions.into_iter().map(|expression| folder.fold_expression(expression)).collect(),
+        span: node.span,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tokenizer::{Tokenizer, Operator}, ast::{parse, nodes::{AstNode, Literal, LiteralValue, expression_statement::{Expression, LiteralExpression, BinaryOperator}}}};
+
+    use super::{Fold, walk_expression};
+
+    // Folds additions of two numbers into a single number literal
+    struct FoldAdditions;
+
+    impl Fold for FoldAdditions {
+        fn fold_expression(&mut self, node: Expression) -> Expression {
+            let binary = match walk_expression(self, node) {
+                Expression::BinaryExpression(binary) => binary,
+                expression => return expression,
+            };
+
+            match (binary.operator, binary.left.as_ref(), binary.right.as_ref()) {
+                (BinaryOperator::Operator(Operator::Plus), Expression::Literal(left), Expression::Literal(right)) => {
+                    let (LiteralValue::Number(left), LiteralValue::Number(right)) = (&left.value.value, &right.value.value) else {
+                        return Expression::BinaryExpression(binary);
+                    };
+
+                    let value = left + right;
+
+                    Expression::Literal(LiteralExpression {
+                        value: Literal { value: LiteralValue::Number(value), raw: value.to_string(), span: binary.span },
+                    })
+                },
+                _ => Expression::BinaryExpression(binary),
+            }
+        }
+    }
+
+    #[test]
+    fn fold_replaces_expressions() {
+        let content = String::from("1 + 2 + 3;");
+        let program = parse(Tokenizer::new(&content)).unwrap();
+
+        let program = FoldAdditions.fold_program(program);
+
+        let AstNode::ExpressionStatement(statement) = &program.body[0] else { panic!("Expected an expression statement") };
+        let Expression::Literal(literal) = &statement.expression else { panic!("Expected a literal") };
+
+        assert_eq!(literal.value.value, LiteralValue::Number(6.0));
+        assert_eq!(literal.value.raw, "6");
+        assert_eq!(literal.value.span.range, (0, 9));
+    }
+}
@@ -0,0 +1,347 @@
+//! Traversal of the AST, `Visit` reads the tree, `VisitMut` changes it in place and `Fold` builds a new tree.
+//!
+//! Every method has a default that walks into the children of the node through the matching `walk_*`
+//! function, so a visitor only implements the methods for the nodes it's interested in. An overriding
+//! method calls the `walk_*` function itself to keep walking into the children.
+
+use super::{Program, nodes::{AstNode, error::ErrorNode, Literal, Identifier, Comment, block_statement::BlockStatement, function_declaration::FunctionDeclaration, jump_statement::{ReturnStatement, ThrowStatement, BreakStatement, ContinueStatement}, for_statement::{ForStatement, ForInit, ForInStatement, ForOfStatement, ForLeft, EmptyStatement}, do_while_statement::DoWhileStatement, variable_declaration::{VariableDeclaration, VariableDeclarator}, pattern::{Pattern, ArrayPattern, ObjectPattern, ObjectPatternProperty, AssignmentProperty, RestElement}, expression_statement::{Expression, ExpressionStatement, LiteralExpression, IdentifierExpression, BinaryExpression, LogicalExpression, UpdateExpression, ArrowFunctionExpression, ArrowFunctionBody, CallExpression, MemberExpression, ChainExpression, ObjectExpression, ObjectProperty, Property, ArrayExpression, ExpressionOrSpread, SpreadElement, SequenceExpression}}};
+
+pub mod visit_mut;
+pub mod fold;
+
+pub use self::{visit_mut::VisitMut, fold::Fold};
+
+pub trait Visit<'ast> {
+    fn visit_program(&mut self, node: &'ast Program) { walk_program(self, node) }
+    fn visit_comment(&mut self, _node: &'ast Comment) {}
+    fn visit_statement(&mut self, node: &'ast AstNode) { walk_statement(self, node) }
+    fn visit_expression_statement(&mut self, node: &'ast ExpressionStatement) { walk_expression_statement(self, node) }
+    fn visit_block_statement(&mut self, node: &'ast BlockStatement) { walk_block_statement(self, node) }
+    fn visit_function_declaration(&mut self, node: &'ast FunctionDeclaration) { walk_function_declaration(self, node) }
+    fn visit_return_statement(&mut self, node: &'ast ReturnStatement) { walk_return_statement(self, node) }
+    fn visit_throw_statement(&mut self, node: &'ast ThrowStatement) { walk_throw_statement(self, node) }
+    fn visit_break_statement(&mut self, node: &'ast BreakStatement) { walk_break_statement(self, node) }
+    fn visit_continue_statement(&mut self, node: &'ast ContinueStatement) { walk_continue_statement(self, node) }
+    fn visit_for_statement(&mut self, node: &'ast ForStatement) { walk_for_statement(self, node) }
+    fn visit_for_init(&mut self, node: &'ast ForInit) { walk_for_init(self, node) }
+    fn visit_for_in_statement(&mut self, node: &'ast ForInStatement) { walk_for_in_statement(self, node) }
+    fn visit_for_of_statement(&mut self, node: &'ast ForOfStatement) { walk_for_of_statement(self, node) }
+    fn visit_for_left(&mut self, node: &'ast ForLeft) { walk_for_left(self, node) }
+    fn visit_do_while_statement(&mut self, node: &'ast DoWhileStatement) { walk_do_while_statement(self, node) }
+    fn visit_empty_statement(&mut self, _node: &'ast EmptyStatement) {}
+    fn visit_error(&mut self, _node: &'ast ErrorNode) {}
+    fn visit_variable_declaration(&mut self, node: &'ast VariableDeclaration) { walk_variable_declaration(self, node) }
+    fn visit_variable_declarator(&mut self, node: &'ast VariableDeclarator) { walk_variable_declarator(self, node) }
+    fn visit_expression(&mut self, node: &'ast Expression) { walk_expression(self, node) }
+    fn visit_identifier_expression(&mut self, node: &'ast IdentifierExpression) { walk_identifier_expression(self, node) }
+    fn visit_literal_expression(&mut self, node: &'ast LiteralExpression) { walk_literal_expression(self, node) }
+    fn visit_binary_expression(&mut self, node: &'ast BinaryExpression) { walk_binary_expression(self, node) }
+    fn visit_logical_expression(&mut self, node: &'ast LogicalExpression) { walk_logical_expression(self, node) }
+    fn visit_update_expression(&mut self, node: &'ast UpdateExpression) { walk_update_expression(self, node) }
+    fn visit_arrow_function_expression(&mut self, node: &'ast ArrowFunctionExpression) { walk_arrow_function_expression(self, node) }
+    fn visit_call_expression(&mut self, node: &'ast CallExpression) { walk_call_expression(self, node) }
+    fn visit_member_expression(&mut self, node: &'ast MemberExpression) { walk_member_expression(self, node) }
+    fn visit_chain_expression(&mut self, node: &'ast ChainExpression) { walk_chain_expression(self, node) }
+    fn visit_object_expression(&mut self, node: &'ast ObjectExpression) { walk_object_expression(self, node) }
+    fn visit_object_property(&mut self, node: &'ast ObjectProperty) { walk_object_property(self, node) }
+    fn visit_property(&mut self, node: &'ast Property) { walk_property(self, node) }
+    fn visit_array_expression(&mut self, node: &'ast ArrayExpression) { walk_array_expression(self, node) }
+    fn visit_expression_or_spread(&mut self, node: &'ast ExpressionOrSpread) { walk_expression_or_spread(self, node) }
+    fn visit_spread_element(&mut self, node: &'ast SpreadElement) { walk_spread_element(self, node) }
+    fn visit_sequence_expression(&mut self, node: &'ast SequenceExpression) { walk_sequence_expression(self, node) }
+    fn visit_pattern(&mut self, node: &'ast Pattern) { walk_pattern(self, node) }
+    fn visit_array_pattern(&mut self, node: &'ast ArrayPattern) { walk_array_pattern(self, node) }
+    fn visit_object_pattern(&mut self, node: &'ast ObjectPattern) { walk_object_pattern(self, node) }
+    fn visit_object_pattern_property(&mut self, node: &'ast ObjectPatternProperty) { walk_object_pattern_property(self, node) }
+    fn visit_assignment_property(&mut self, node: &'ast AssignmentProperty) { walk_assignment_property(self, node) }
+    fn visit_rest_element(&mut self, node: &'ast RestElement) { walk_rest_element(self, node) }
+    fn visit_identifier(&mut self, _node: &'ast Identifier) {}
+    fn visit_literal(&mut self, _node: &'ast Literal) {}
+}
+
+pub fn walk_program<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Program) {
+    node.body.iter().for_each(|statement| visitor.visit_statement(statement));
+    node.comments.iter().for_each(|comment| visitor.visit_comment(comment));
+}
+
+pub fn walk_statement<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast AstNode) {
+    match node {
+        AstNode::ExpressionStatement(statement) => visitor.visit_expression_statement(statement),
+        AstNode::BlockStatement(statement) => visitor.visit_block_statement(statement),
+        AstNode::FunctionDeclaration(declaration) => visitor.visit_function_declaration(declaration),
+        AstNode::VariableDeclaration(declaration) => visitor.visit_variable_declaration(declaration),
+        AstNode::ReturnStatement(statement) => visitor.visit_return_statement(statement),
+        AstNode::ThrowStatement(statement) => visitor.visit_throw_statement(statement),
+        AstNode::BreakStatement(statement) => visitor.visit_break_statement(statement),
+        AstNode::ContinueStatement(statement) => visitor.visit_continue_statement(statement),
+        AstNode::ForStatement(statement) => visitor.visit_for_statement(statement),
+        AstNode::ForInStatement(statement) => visitor.visit_for_in_statement(statement),
+        AstNode::ForOfStatement(statement) => visitor.visit_for_of_statement(statement),
+        AstNode::DoWhileStatement(statement) => visitor.visit_do_while_statement(statement),
+        AstNode::EmptyStatement(statement) => visitor.visit_empty_statement(statement),
+        AstNode::Error(error) => visitor.visit_error(error),
+    }
+}
+
+pub fn walk_expression_statement<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ExpressionStatement) {
+    visitor.visit_expression(&node.expression);
+}
+
+pub fn walk_block_statement<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast BlockStatement) {
+    node.body.iter().for_each(|statement| visitor.visit_statement(statement));
+}
+
+pub fn walk_function_declaration<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast FunctionDeclaration) {
+    visitor.visit_identifier(&node.id);
+    node.params.iter().for_each(|param| visitor.visit_pattern(param));
+    visitor.visit_block_statement(&node.body);
+}
+
+pub fn walk_return_statement<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ReturnStatement) {
+    if let Some(argument) = &node.argument { visitor.visit_expression(argument) }
+}
+
+pub fn walk_throw_statement<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ThrowStatement) {
+    visitor.visit_expression(&node.argument);
+}
+
+pub fn walk_break_statement<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast BreakStatement) {
+    if let Some(label) = &node.label { visitor.visit_identifier(label) }
+}
+
+pub fn walk_continue_statement<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ContinueStatement) {
+    if let Some(label) = &node.label { visitor.visit_identifier(label) }
+}
+
+pub fn walk_for_statement<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ForStatement) {
+    if let Some(init) = &node.init { visitor.visit_for_init(init) }
+    if let Some(test) = &node.test { visitor.visit_expression(test) }
+    if let Some(update) = &node.update { visitor.visit_expression(update) }
+    visitor.visit_statement(&node.body);
+}
+
+pub fn walk_for_init<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ForInit) {
+    match node {
+        ForInit::VariableDeclaration(declaration) => visitor.visit_variable_declaration(declaration),
+        ForInit::Expression(expression) => visitor.visit_expression(expression),
+    }
+}
+
+pub fn walk_for_in_statement<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ForInStatement) {
+    visitor.visit_for_left(&node.left);
+    visitor.visit_expression(&node.right);
+    visitor.visit_statement(&node.body);
+}
+
+pub fn walk_for_of_statement<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ForOfStatement) {
+    visitor.visit_for_left(&node.left);
+    visitor.visit_expression(&node.right);
+    visitor.visit_statement(&node.body);
+}
+
+pub fn walk_for_left<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ForLeft) {
+    match node {
+        ForLeft::VariableDeclaration(declaration) => visitor.visit_variable_declaration(declaration),
+        ForLeft::Pattern(pattern) => visitor.visit_pattern(pattern),
+    }
+}
+
+pub fn walk_do_while_statement<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast DoWhileStatement) {
+    visitor.visit_statement(&node.body);
+    visitor.visit_expression(&node.test);
+}
+
+pub fn walk_variable_declaration<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast VariableDeclaration) {
+    node.declarations.iter().for_each(|declarator| visitor.visit_variable_declarator(declarator));
+}
+
+pub fn walk_variable_declarator<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast VariableDeclarator) {
+    visitor.visit_pattern(&node.id);
+    if let Some(init) = &node.init { visitor.visit_expression(init) }
+}
+
+pub fn walk_expression<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Expression) {
+    match node {
+        Expression::Identifier(expression) => visitor.visit_identifier_expression(expression),
+        Expression::Literal(expression) => visitor.visit_literal_expression(expression),
+        Expression::BinaryExpression(expression) => visitor.visit_binary_expression(expression),
+        Expression::LogicalExpression(expression) => visitor.visit_logical_expression(expression),
+        Expression::CallExpression(expression) => visitor.visit_call_expression(expression),
+        Expression::MemberExpression(expression) => visitor.visit_member_expression(expression),
+        Expression::ChainExpression(expression) => visitor.visit_chain_expression(expression),
+        Expression::ObjectExpression(expression) => visitor.visit_object_expression(expression),
+        Expression::SequenceExpression(expression) => visitor.visit_sequence_expression(expression),
+        Expression::ArrayExpression(expression) => visitor.visit_array_expression(expression),
+        Expression::UpdateExpression(expression) => visitor.visit_update_expression(expression),
+        Expression::ArrowFunctionExpression(expression) => visitor.visit_arrow_function_expression(expression),
+    }
+}
+
+pub fn walk_identifier_expression<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast IdentifierExpression) {
+    visitor.visit_identifier(&node.identifier);
+}
+
+pub fn walk_literal_expression<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast LiteralExpression) {
+    visitor.visit_literal(&node.value);
+}
+
+pub fn walk_binary_expression<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast BinaryExpression) {
+    visitor.visit_expression(&node.left);
+    visitor.visit_expression(&node.right);
+}
+
+pub fn walk_logical_expression<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast LogicalExpression) {
+    visitor.visit_expression(&node.left);
+    visitor.visit_expression(&node.right);
+}
+
+pub fn walk_update_expression<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast UpdateExpression) {
+    visitor.visit_expression(&node.argument);
+}
+
+pub fn walk_arrow_function_expression<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ArrowFunctionExpression) {
+    node.params.iter().for_each(|param| visitor.visit_pattern(param));
+
+    match &node.body {
+        ArrowFunctionBody::BlockStatement(body) => visitor.visit_block_statement(body),
+        ArrowFunctionBody::Expression(body) => visitor.visit_expression(body),
+    }
+}
+
+pub fn walk_call_expression<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast CallExpression) {
+    visitor.visit_expression(&node.callee);
+    node.arguments.iter().for_each(|argument| visitor.visit_expression_or_spread(argument));
+}
+
+pub fn walk_member_expression<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast MemberExpression) {
+    visitor.visit_expression(&node.object);
+    visitor.visit_expression(&node.property);
+}
+
+pub fn walk_chain_expression<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ChainExpression) {
+    visitor.visit_expression(&node.expression);
+}
+
+pub fn walk_object_expression<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ObjectExpression) {
+    node.properties.iter().for_each(|property| visitor.visit_object_property(property));
+}
+
+pub fn walk_object_property<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ObjectProperty) {
+    match node {
+        ObjectProperty::Property(property) => visitor.visit_property(property),
+        ObjectProperty::Spread(spread) => visitor.visit_spread_element(spread),
+    }
+}
+
+pub fn walk_property<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Property) {
+    visitor.visit_expression(&node.key);
+    visitor.visit_expression(&node.value);
+}
+
+pub fn walk_array_expression<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ArrayExpression) {
+    node.elements.iter().flatten().for_each(|element| visitor.visit_expression_or_spread(element));
+}
+
+pub fn walk_expression_or_spread<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ExpressionOrSpread) {
+    match node {
+        ExpressionOrSpread::Expression(expression) => visitor.visit_expression(expression),
+        ExpressionOrSpread::Spread(spread) => visitor.visit_spread_element(spread),
+    }
+}
+
+pub fn walk_spread_element<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast SpreadElement) {
+    visitor.visit_expression(&node.argument);
+}
+
+pub fn walk_pattern<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Pattern) {
+    match node {
+        Pattern::Identifier(identifier) => visitor.visit_identifier(identifier),
+        Pattern::MemberExpression(expression) => visitor.visit_member_expression(expression),
+        Pattern::ArrayPattern(pattern) => visitor.visit_array_pattern(pattern),
+        Pattern::ObjectPattern(pattern) => visitor.visit_object_pattern(pattern),
+        Pattern::RestElement(element) => visitor.visit_rest_element(element),
+    }
+}
+
+pub fn walk_array_pattern<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ArrayPattern) {
+    node.elements.iter().flatten().for_each(|element| visitor.visit_pattern(element));
+}
+
+pub fn walk_object_pattern<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ObjectPattern) {
+    node.properties.iter().for_each(|property| visitor.visit_object_pattern_property(property));
+}
+
+pub fn walk_object_pattern_property<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ObjectPatternProperty) {
+    match node {
+        ObjectPatternProperty::Property(property) => visitor.visit_assignment_property(property),
+        ObjectPatternProperty::Rest(rest) => visitor.visit_rest_element(rest),
+    }
+}
+
+pub fn walk_assignment_property<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast AssignmentProperty) {
+    visitor.visit_expression(&node.key);
+    visitor.visit_pattern(&node.value);
+}
+
+pub fn walk_rest_element<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast RestElement) {
+    visitor.visit_pattern(&node.argument);
+}
+
+pub fn walk_sequence_expression<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast SequenceExpression) {
+    node.expressions.iter().for_each(|expression| visitor.visit_expression(expression));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tokenizer::Tokenizer, ast::{parse, nodes::{Identifier, expression_statement::{CallExpression, Expression}}}};
+
+    use super::{Visit, walk_call_expression};
+
+    #[derive(Default)]
+    struct Names<'ast> {
+        names: Vec<&'ast str>,
+    }
+
+    impl<'ast> Visit<'ast> for Names<'ast> {
+        fn visit_identifier(&mut self, node: &'ast Identifier) {
+            self.names.push(&node.name);
+        }
+    }
+
+    #[test]
+    fn visitor_reaches_every_identifier() {
+        let content = String::from("function foo(a, b) {\n    bar(a, 1);\n    { b; }\n}\nbaz;");
+        let program = parse(Tokenizer::new(&content)).unwrap();
+
+        let mut visitor = Names::default();
+        visitor.visit_program(&program);
+
+        assert_eq!(visitor.names, vec!["foo", "a", "b", "bar", "a", "b", "baz"]);
+    }
+
+    #[derive(Default)]
+    struct Calls {
+        callees: Vec<String>,
+    }
+
+    impl<'ast> Visit<'ast> for Calls {
+        fn visit_call_expression(&mut self, node: &'ast CallExpression) {
+            if let Expression::Identifier(callee) = node.callee.as_ref() {
+                self.callees.push(callee.identifier.name.clone());
+            }
+
+            walk_call_expression(self, node);
+        }
+    }
+
+    #[test]
+    fn overridden_method_can_keep_walking() {
+        let content = String::from("foo(1, 2 + 3);\nbar();");
+        let program = parse(Tokenizer::new(&content)).unwrap();
+
+        let mut visitor = Calls::default();
+        visitor.visit_program(&program);
+
+        assert_eq!(visitor.callees, vec!["foo", "bar"]);
+    }
+}
@@ -0,0 +1,321 @@
+use crate::ast::{Program, nodes::{AstNode, error::ErrorNode, Literal, Identifier, Comment, block_statement::BlockStatement, function_declaration::FunctionDeclaration, jump_statement::{ReturnStatement, ThrowStatement, BreakStatement, ContinueStatement}, for_statement::{ForStatement, ForInit, ForInStatement, ForOfStatement, ForLeft, EmptyStatement}, do_while_statement::DoWhileStatement, variable_declaration::{VariableDeclaration, VariableDeclarator}, pattern::{Pattern, ArrayPattern, ObjectPattern, ObjectPatternProperty, AssignmentProperty, RestElement}, expression_statement::{Expression, ExpressionStatement, LiteralExpression, IdentifierExpression, BinaryExpression, LogicalExpression, UpdateExpression, ArrowFunctionExpression, ArrowFunctionBody, CallExpression, MemberExpression, ChainExpression, ObjectExpression, ObjectProperty, Property, ArrayExpression, ExpressionOrSpread, SpreadElement, SequenceExpression}}};
+
+pub trait VisitMut {
+    fn visit_program_mut(&mut self, node: &mut Program) { walk_program_mut(self, node) }
+    fn visit_comment_mut(&mut self, _node: &mut Comment) {}
+    fn visit_statement_mut(&mut self, node: &mut AstNode) { walk_statement_mut(self, node) }
+    fn visit_expression_statement_mut(&mut self, node: &mut ExpressionStatement) { walk_expression_statement_mut(self, node) }
+    fn visit_block_statement_mut(&mut self, node: &mut BlockStatement) { walk_block_statement_mut(self, node) }
+    fn visit_function_declaration_mut(&mut self, node: &mut FunctionDeclaration) { walk_function_declaration_mut(self, node) }
+    fn visit_return_statement_mut(&mut self, node: &mut ReturnStatement) { walk_return_statement_mut(self, node) }
+    fn visit_throw_statement_mut(&mut self, node: &mut ThrowStatement) { walk_throw_statement_mut(self, node) }
+    fn visit_break_statement_mut(&mut self, node: &mut BreakStatement) { walk_break_statement_mut(self, node) }
+    fn visit_continue_statement_mut(&mut self, node: &mut ContinueStatement) { walk_continue_statement_mut(self, node) }
+    fn visit_for_statement_mut(&mut self, node: &mut ForStatement) { walk_for_statement_mut(self, node) }
+    fn visit_for_init_mut(&mut self, node: &mut ForInit) { walk_for_init_mut(self, node) }
+    fn visit_for_in_statement_mut(&mut self, node: &mut ForInStatement) { walk_for_in_statement_mut(self, node) }
+    fn visit_for_of_statement_mut(&mut self, node: &mut ForOfStatement) { walk_for_of_statement_mut(self, node) }
+    fn visit_for_left_mut(&mut self, node: &mut ForLeft) { walk_for_left_mut(self, node) }
+    fn visit_do_while_statement_mut(&mut self, node: &mut DoWhileStatement) { walk_do_while_statement_mut(self, node) }
+    fn visit_empty_statement_mut(&mut self, _node: &mut EmptyStatement) {}
+    fn visit_error_mut(&mut self, _node: &mut ErrorNode) {}
+    fn visit_variable_declaration_mut(&mut self, node: &mut VariableDeclaration) { walk_variable_declaration_mut(self, node) }
+    fn visit_variable_declarator_mut(&mut self, node: &mut VariableDeclarator) { walk_variable_declarator_mut(self, node) }
+    fn visit_expression_mut(&mut self, node: &mut Expression) { walk_expression_mut(self, node) }
+    fn visit_identifier_expression_mut(&mut self, node: &mut IdentifierExpression) { walk_identifier_expression_mut(self, node) }
+    fn visit_literal_expression_mut(&mut self, node: &mut LiteralExpression) { walk_literal_expression_mut(self, node) }
+    fn visit_binary_expression_mut(&mut self, node: &mut BinaryExpression) { walk_binary_expression_mut(self, node) }
+    fn visit_logical_expression_mut(&mut self, node: &mut LogicalExpression) { walk_logical_expression_mut(self, node) }
+    fn visit_update_expression_mut(&mut self, node: &mut UpdateExpression) { walk_update_expression_mut(self, node) }
+    fn visit_arrow_function_expression_mut(&mut self, node: &mut ArrowFunctionExpression) { walk_arrow_function_expression_mut(self, node) }
+    fn visit_call_expression_mut(&mut self, node: &mut CallExpression) { walk_call_expression_mut(self, node) }
+    fn visit_member_expression_mut(&mut self, node: &mut MemberExpression) { walk_member_expression_mut(self, node) }
+    fn visit_chain_expression_mut(&mut self, node: &mut ChainExpression) { walk_chain_expression_mut(self, node) }
+    fn visit_object_expression_mut(&mut self, node: &mut ObjectExpression) { walk_object_expression_mut(self, node) }
+    fn visit_object_property_mut(&mut self, node: &mut ObjectProperty) { walk_object_property_mut(self, node) }
+    fn visit_property_mut(&mut self, node: &mut Property) { walk_property_mut(self, node) }
+    fn visit_array_expression_mut(&mut self, node: &mut ArrayExpression) { walk_array_expression_mut(self, node) }
+    fn visit_expression_or_spread_mut(&mut self, node: &mut ExpressionOrSpread) { walk_expression_or_spread_mut(self, node) }
+    fn visit_spread_element_mut(&mut self, node: &mut SpreadElement) { walk_spread_element_mut(self, node) }
+    fn visit_sequence_expression_mut(&mut self, node: &mut SequenceExpression) { walk_sequence_expression_mut(self, node) }
+    fn visit_pattern_mut(&mut self, node: &mut Pattern) { walk_pattern_mut(self, node) }
+    fn visit_array_pattern_mut(&mut self, node: &mut ArrayPattern) { walk_array_pattern_mut(self, node) }
+    fn visit_object_pattern_mut(&mut self, node: &mut ObjectPattern) { walk_object_pattern_mut(self, node) }
+    fn visit_object_pattern_property_mut(&mut self, node: &mut ObjectPatternProperty) { walk_object_pattern_property_mut(self, node) }
+    fn visit_assignment_property_mut(&mut self, node: &mut AssignmentProperty) { walk_assignment_property_mut(self, node) }
+    fn visit_rest_element_mut(&mut self, node: &mut RestElement) { walk_rest_element_mut(self, node) }
+    fn visit_identifier_mut(&mut self, _node: &mut Identifier) {}
+    fn visit_literal_mut(&mut self, _node: &mut Literal) {}
+}
+
+pub fn walk_program_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut Program) {
+    node.body.iter_mut().for_each(|statement| visitor.visit_statement_mut(statement));
+    node.comments.iter_mut().for_each(|comment| visitor.visit_comment_mut(comment));
+}
+
+pub fn walk_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut AstNode) {
+    match node {
+        AstNode::ExpressionStatement(statement) => visitor.visit_expression_statement_mut(statement),
+        AstNode::BlockStatement(statement) => visitor.visit_block_statement_mut(statement),
+        AstNode::FunctionDeclaration(declaration) => visitor.visit_function_declaration_mut(declaration),
+        AstNode::VariableDeclaration(declaration) => visitor.visit_variable_declaration_mut(declaration),
+        AstNode::ReturnStatement(statement) => visitor.visit_return_statement_mut(statement),
+        AstNode::ThrowStatement(statement) => visitor.visit_throw_statement_mut(statement),
+        AstNode::BreakStatement(statement) => visitor.visit_break_statement_mut(statement),
+        AstNode::ContinueStatement(statement) => visitor.visit_continue_statement_mut(statement),
+        AstNode::ForStatement(statement) => visitor.visit_for_statement_mut(statement),
+        AstNode::ForInStatement(statement) => visitor.visit_for_in_statement_mut(statement),
+        AstNode::ForOfStatement(statement) => visitor.visit_for_of_statement_mut(statement),
+        AstNode::DoWhileStatement(statement) => visitor.visit_do_while_statement_mut(statement),
+        AstNode::EmptyStatement(statement) => visitor.visit_empty_statement_mut(statement),
+        AstNode::Error(error) => visitor.visit_error_mut(error),
+    }
+}
+
+pub fn walk_expression_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ExpressionStatement) {
+    visitor.visit_expression_mut(&mut node.expression);
+}
+
+pub fn walk_block_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut BlockStatement) {
+    node.body.iter_mut().for_each(|statement| visitor.visit_statement_mut(statement));
+}
+
+pub fn walk_function_declaration_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut FunctionDeclaration) {
+    visitor.visit_identifier_mut(&mut node.id);
+    node.params.iter_mut().for_each(|param| visitor.visit_pattern_mut(param));
+    visitor.visit_block_statement_mut(&mut node.body);
+}
+
+pub fn walk_return_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ReturnStatement) {
+    if let Some(argument) = &mut node.argument { visitor.visit_expression_mut(argument) }
+}
+
+pub fn walk_throw_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ThrowStatement) {
+    visitor.visit_expression_mut(&mut node.argument);
+}
+
+pub fn walk_break_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut BreakStatement) {
+    if let Some(label) = &mut node.label { visitor.visit_identifier_mut(label) }
+}
+
+pub fn walk_continue_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ContinueStatement) {
+    if let Some(label) = &mut node.label { visitor.visit_identifier_mut(label) }
+}
+
+pub fn walk_for_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ForStatement) {
+    if let Some(init) = &mut node.init { visitor.visit_for_init_mut(init) }
+    if let Some(test) = &mut node.test { visitor.visit_expression_mut(test) }
+    if let Some(update) = &mut node.update { visitor.visit_expression_mut(update) }
+    visitor.visit_statement_mut(&mut node.body);
+}
+
+pub fn walk_for_init_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ForInit) {
+    match node {
+        ForInit::VariableDeclaration(declaration) => visitor.visit_variable_declaration_mut(declaration),
+        ForInit::Expression(expression) => visitor.visit_expression_mut(expression),
+    }
+}
+
+pub fn walk_for_in_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ForInStatement) {
+    visitor.visit_for_left_mut(&mut node.left);
+    visitor.visit_expression_mut(&mut node.right);
+    visitor.visit_statement_mut(&mut node.body);
+}
+
+pub fn walk_for_of_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ForOfStatement) {
+    visitor.visit_for_left_mut(&mut node.left);
+    visitor.visit_expression_mut(&mut node.right);
+    visitor.visit_statement_mut(&mut node.body);
+}
+
+pub fn walk_for_left_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ForLeft) {
+    match node {
+        ForLeft::VariableDeclaration(declaration) => visitor.visit_variable_declaration_mut(declaration),
+        ForLeft::Pattern(pattern) => visitor.visit_pattern_mut(pattern),
+    }
+}
+
+pub fn walk_do_while_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut DoWhileStatement) {
+    visitor.visit_statement_mut(&mut node.body);
+    visitor.visit_expression_mut(&mut node.test);
+}
+
+pub fn walk_variable_declaration_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut VariableDeclaration) {
+    node.declarations.iter_mut().for_each(|declarator| visitor.visit_variable_declarator_mut(declarator));
+}
+
+pub fn walk_variable_declarator_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut VariableDeclarator) {
+    visitor.visit_pattern_mut(&mut node.id);
+    if let Some(init) = &mut node.init { visitor.visit_expression_mut(init) }
+}
+
+pub fn walk_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut Expression) {
+    match node {
+        Expression::Identifier(expression) => visitor.visit_identifier_expression_mut(expression),
+        Expression::Literal(expression) => visitor.visit_literal_expression_mut(expression),
+        Expression::BinaryExpression(expression) => visitor.visit_binary_expression_mut(expression),
+        Expression::LogicalExpression(expression) => visitor.visit_logical_expression_mut(expression),
+        Expression::CallExpression(expression) => visitor.visit_call_expression_mut(expression),
+        Expression::MemberExpression(expression) => visitor.visit_member_expression_mut(expression),
+        Expression::ChainExpression(expression) => visitor.visit_chain_expression_mut(expression),
+        Expression::ObjectExpression(expression) => visitor.visit_object_expression_mut(expression),
+        Expression::SequenceExpression(expression) => visitor.visit_sequence_expression_mut(expression),
+        Expression::ArrayExpression(expression) => visitor.visit_array_expression_mut(expression),
+        Expression::UpdateExpression(expression) => visitor.visit_update_expression_mut(expression),
+        Expression::ArrowFunctionExpression(expression) => visitor.visit_arrow_function_expression_mut(expression),
+    }
+}
+
+pub fn walk_identifier_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut IdentifierExpression) {
+    visitor.visit_identifier_mut(&mut node.identifier);
+}
+
+pub fn walk_literal_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut LiteralExpression) {
+    visitor.visit_literal_mut(&mut node.value);
+}
+
+pub fn walk_binary_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut BinaryExpression) {
+    visitor.visit_expression_mut(&mut node.left);
+    visitor.visit_expression_mut(&mut node.right);
+}
+
+pub fn walk_logical_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut LogicalExpression) {
+    visitor.visit_expression_mut(&mut node.left);
+    visitor.visit_expression_mut(&mut node.right);
+}
+
+pub fn walk_update_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut UpdateExpression) {
+    visitor.visit_expression_mut(&mut node.argument);
+}
+
+pub fn walk_arrow_function_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ArrowFunctionExpression) {
+    node.params.iter_mut().for_each(|param| visitor.visit_pattern_mut(param));
+
+    match &mut node.body {
+        ArrowFunctionBody::BlockStatement(body) => visitor.visit_block_statement_mut(body),
+        ArrowFunctionBody::Expression(body) => visitor.visit_expression_mut(body),
+    }
+}
+
+pub fn walk_call_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut CallExpression) {
+    visitor.visit_expression_mut(&mut node.callee);
+    node.arguments.iter_mut().for_each(|argument| visitor.visit_expression_or_spread_mut(argument));
+}
+
+pub fn walk_member_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut MemberExpression) {
+    visitor.visit_expression_mut(&mut node.object);
+    visitor.visit_expression_mut(&mut node.property);
+}
+
+pub fn walk_chain_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ChainExpression) {
+    visitor.visit_expression_mut(&mut node.expression);
+}
+
+pub fn walk_object_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ObjectExpression) {
+    node.properties.iter_mut().for_each(|property| visitor.visit_object_property_mut(property));
+}
+
+pub fn walk_object_property_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ObjectProperty) {
+    match node {
+        ObjectProperty::Property(property) => visitor.visit_property_mut(property),
+        ObjectProperty::Spread(spread) => visitor.visit_spread_element_mut(spread),
+    }
+}
+
+pub fn walk_property_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut Property) {
+    visitor.visit_expression_mut(&mut node.key);
+    visitor.visit_expression_mut(&mut node.value);
+}
+
+pub fn walk_array_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ArrayExpression) {
+    node.elements.iter_mut().flatten().for_each(|element| visitor.visit_expression_or_spread_mut(element));
+}
+
+pub fn walk_expression_or_spread_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ExpressionOrSpread) {
+    match node {
+        ExpressionOrSpread::Expression(expression) => visitor.visit_expression_mut(expression),
+        ExpressionOrSpread::Spread(spread) => visitor.visit_spread_element_mut(spread),
+    }
+}
+
+pub fn walk_spread_element_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut SpreadElement) {
+    visitor.visit_expression_mut(&mut node.argument);
+}
+
+pub fn walk_pattern_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut Pattern) {
+    match node {
+        Pattern::Identifier(identifier) => visitor.visit_identifier_mut(identifier),
+        Pattern::MemberExpression(expression) => visitor.visit_member_expression_mut(expression),
+        Pattern::ArrayPattern(pattern) => visitor.visit_array_pattern_mut(pattern),
+        Pattern::ObjectPattern(pattern) => visitor.visit_object_pattern_mut(pattern),
+        Pattern::RestElement(element) => visitor.visit_rest_element_mut(element),
+    }
+}
+
+pub fn walk_array_pattern_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ArrayPattern) {
+    node.elements.iter_mut().flatten().for_each(|element| visitor.visit_pattern_mut(element));
+}
+
+pub fn walk_object_pattern_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ObjectPattern) {
+    node.properties.iter_mut().for_each(|property| visitor.visit_object_pattern_property_mut(property));
+}
+
+pub fn walk_object_pattern_property_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut ObjectPatternProperty) {
+    match node {
+        ObjectPatternProperty::Property(property) => visitor.visit_assignment_property_mut(property),
+        ObjectPatternProperty::Rest(rest) => visitor.visit_rest_element_mut(rest),
+    }
+}
+
+pub fn walk_assignment_property_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut AssignmentProperty) {
+    visitor.visit_expression_mut(&mut node.key);
+    visitor.visit_pattern_mut(&mut node.value);
+}
+
+pub fn walk_rest_element_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut RestElement) {
+    visitor.visit_pattern_mut(&mut node.argument);
+}
+
+pub fn walk_sequence_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, node: &mut SequenceExpression) {
+    node.expressions.iter_mut().for_each(|expression| visitor.visit_expression_mut(expression));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tokenizer::Tokenizer, ast::{parse, nodes::{Identifier, AstNode, pattern::Pattern, expression_statement::{Expression, ExpressionOrSpread}}}};
+
+    use super::VisitMut;
+
+    struct Rename<'a> {
+        from: &'a str,
+        to: &'a str,
+    }
+
+    impl VisitMut for Rename<'_> {
+        fn visit_identifier_mut(&mut self, node: &mut Identifier) {
+            if node.name == self.from {
+                node.name = self.to.to_string();
+            }
+        }
+    }
+
+    #[test]
+    fn mutable_visitor_renames_identifiers() {
+        let content = String::from("function foo(a) { a; }\nfoo(a);");
+        let mut program = parse(Tokenizer::new(&content)).unwrap();
+
+        Rename { from: "a", to: "b" }.visit_program_mut(&mut program);
+
+        let AstNode::FunctionDeclaration(function) = &program.body[0] else { panic!("Expected a function declaration") };
+        let Pattern::Identifier(param) = &function.params[0] else { panic!("Expected an identifier") };
+        assert_eq!(param.name, "b");
+
+        let AstNode::ExpressionStatement(statement) = &program.body[1] else { panic!("Expected an expression statement") };
+        let Expression::CallExpression(call) = &statement.expression else { panic!("Expected a call expression") };
+        let Expression::Identifier(callee) = call.callee.as_ref() else { panic!("Expected an identifier") };
+        assert_eq!(callee.identifier.name, "foo");
+
+        let ExpressionOrSpread::Expression(Expression::Identifier(argument)) = &call.arguments[0] else { panic!("Expected an identifier") };
+        assert_eq!(argument.identifier.name, "b");
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{ast::{AstParseError, AstErrorType}, tokenizer::{TokenizeError, TokenErrorType}};
+
+pub use self::render::{render, ColorMode, RenderOptions};
+
+mod render;
+
+/// A part of the source a diagnostic points at, the range uses the offset encoding given to the renderer
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    pub range: (usize, usize),
+    pub message: Option<String>,
+}
+
+impl Label {
+    pub fn new(range: (usize, usize)) -> Self {
+        Label { range, message: None }
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+/// An error ready to be shown to a developer, independent of the stage that found it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub code: Option<&'static str>,
+    pub message: String,
+    /// Where the error occurred, underlined with `^`
+    pub primary: Label,
+    /// Related parts of the source, underlined with `-`
+    pub secondary: Vec<Label>,
+    /// Notes printed below the source, e.g. "help: ..."
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn new(message: impl Into<String>, primary: Label) -> Self {
+        Diagnostic { code: None, message: message.into(), primary, secondary: Vec::new(), notes: Vec::new() }
+    }
+
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    pub fn with_secondary(mut self, label: Label) -> Self {
+        self.secondary.push(label);
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+}
+
+impl From<&TokenizeError> for Diagnostic {
+    fn from(error: &TokenizeError) -> Self {
+        // The error points at a single character, or at the `/*` of an unterminated comment
+        let length = if error.error_type == TokenErrorType::UnterminatedComment { 2 } else { 1 };
+        let label = Label::new((error.index, error.index + length));
+
+        Diagnostic::new(error.error_type.to_string(), label).with_code(error.error_type.code())
+    }
+}
+
+impl From<&AstParseError> for Diagnostic {
+    fn from(error: &AstParseError) -> Self {
+        if let AstErrorType::InvalidToken(error) = &error.error_type {
+            return Diagnostic::from(error);
+        }
+
+        // Only an empty input has no token to point at
+        let range = error.span.as_ref().map(|span| span.range).unwrap_or((0, 0));
+
+        let mut primary = Label::new(range);
+        primary.message = error.expected_message();
+
+        let diagnostic = Diagnostic::new(error.to_string(), primary).with_code(error.code());
+
+        let Some(help) = error.help.as_deref() else { return diagnostic };
+
+        match &help.span {
+            Some(span) => diagnostic.with_secondary(Label::new(span.range).with_message(&help.message)),
+            None => diagnostic.with_note(format!("help: {}", help.message)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tokenizer::Tokenizer, ast};
+
+    use super::{Diagnostic, Label};
+
+    #[test]
+    fn tokenize_error_points_at_its_character() {
+        let content = String::from("foo(#);");
+
+        let error = ast::parse(Tokenizer::new(&content)).unwrap_err();
+        let diagnostic = Diagnostic::from(&error);
+
+        assert_eq!(diagnostic.code, Some("E0001"));
+        assert_eq!(diagnostic.message, "Unexpected character");
+        assert_eq!(diagnostic.primary, Label::new((4, 5)));
+    }
+
+    #[test]
+    fn unterminated_comment_points_at_its_opening() {
+        let content = String::from("foo;\n/* bar");
+
+        let error = ast::parse(Tokenizer::new(&content)).unwrap_err();
+        let diagnostic = Diagnostic::from(&error);
+
+        assert_eq!(diagnostic.code, Some("E0007"));
+        assert_eq!(diagnostic.message, "Unterminated comment");
+        assert_eq!(diagnostic.primary, Label::new((5, 7)));
+    }
+
+    #[test]
+    fn parse_error_labels_what_was_expected() {
+        let content = String::from("foo(1;");
+
+        let error = ast::parse(Tokenizer::new(&content)).unwrap_err();
+        let diagnostic = Diagnostic::from(&error);
+
+        assert_eq!(diagnostic.code, Some("E1001"));
+        assert_eq!(diagnostic.primary, Label::new((5, 6)).with_message("expected `)` or `,`"));
+        assert_eq!(diagnostic.secondary, vec![Label::new((3, 4)).with_message("missing `)` to close call started here")]);
+    }
+}
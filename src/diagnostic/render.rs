@@ -0,0 +1,255 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use crate::source::{SourceFile, OffsetEncoding, is_line_terminator};
+
+use super::{Diagnostic, Label};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    #[default]
+    Plain,
+    /// Colors the output with ANSI escape codes, for terminals
+    Ansi,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenderOptions {
+    pub color: ColorMode,
+    /// The unit of the label ranges, the same as the tokenizer's `offset_encoding`
+    pub encoding: OffsetEncoding,
+}
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+struct Painter {
+    color: ColorMode,
+}
+
+impl Painter {
+    fn paint(&self, style: &str, text: &str) -> String {
+        match self.color {
+            ColorMode::Plain => text.to_string(),
+            ColorMode::Ansi => format!("{}{}{}", style, text, RESET),
+        }
+    }
+}
+
+// A label resolved to a single line of the source, columns count chars from the start of the line
+struct LineLabel<'a> {
+    start: usize,
+    end: usize,
+    primary: bool,
+    message: Option<&'a str>,
+}
+
+/// Renders a diagnostic as a code frame, the way rustc shows its errors:
+///
+/// ```text
+/// error[E1001]: Unexpected token `;`, expected `)` or `,`
+///  --> input.js:1:6
+///   |
+/// 1 | foo(1;
+///   |      ^ expected `)` or `,`
+///   |    - missing `)` to close call started here
+/// ```
+///
+/// Labels spanning several lines are only underlined up to the end of their first line.
+pub fn render(diagnostic: &Diagnostic, source: &SourceFile, file_name: &str, options: RenderOptions) -> String {
+    let painter = Painter { color: options.color };
+    let mut output = String::new();
+
+    let labels = std::iter::once((&diagnostic.primary, true)).chain(diagnostic.secondary.iter().map(|label| (label, false)));
+    let mut lines: BTreeMap<usize, Vec<LineLabel>> = BTreeMap::new();
+
+    for (label, primary) in labels {
+        let (line, line_label) = resolve(label, primary, source, options.encoding);
+        lines.entry(line).or_default().push(line_label);
+    }
+
+    let (primary_line, primary_column) = {
+        let (line, label) = resolve(&diagnostic.primary, true, source, options.encoding);
+        (line, label.start)
+    };
+
+    let last_line = lines.keys().next_back().copied().unwrap_or(0);
+    let gutter = " ".repeat((last_line + 1).to_string().len());
+    let bar = painter.paint(BLUE, "|");
+
+    let title = match diagnostic.code {
+        Some(code) => format!("error[{}]", code),
+        None => String::from("error"),
+    };
+
+    let _ = writeln!(output, "{}: {}", painter.paint(RED, &title), painter.paint(BOLD, &diagnostic.message));
+    let _ = writeln!(output, "{}{} {}:{}:{}", gutter, painter.paint(BLUE, "-->"), file_name, primary_line + 1, primary_column + 1);
+    let _ = writeln!(output, "{} {}", gutter, bar);
+
+    let mut previous_line = None;
+
+    for (line, mut labels) in lines {
+        // Lines between two labelled lines are left out
+        if previous_line.is_some_and(|previous| line > previous + 1) {
+            let _ = writeln!(output, "{}", painter.paint(BLUE, "..."));
+        }
+
+        let text = line_text(source, line);
+        let number = format!("{:>width$}", line + 1, width = gutter.len());
+
+        let _ = writeln!(output, "{} {} {}", painter.paint(BLUE, &number), bar, text);
+
+        labels.sort_by_key(|label| (!label.primary, label.start));
+
+        for label in labels {
+            // Tabs are kept so the underline lines up with the source
+            let padding: String = text.chars().take(label.start).map(|value| if value == '\t' { '\t' } else { ' ' }).collect();
+            let marker = if label.primary { "^" } else { "-" };
+            let style = if label.primary { RED } else { BLUE };
+
+            let mut underline = marker.repeat((label.end - label.start).max(1));
+
+            if let Some(message) = label.message {
+                underline = format!("{} {}", underline, message);
+            }
+
+            let _ = writeln!(output, "{} {} {}{}", gutter, bar, padding, painter.paint(style, &underline));
+        }
+
+        previous_line = Some(line);
+    }
+
+    for note in &diagnostic.notes {
+        let _ = writeln!(output, "{} {} {}", gutter, painter.paint(BLUE, "="), note);
+    }
+
+    output
+}
+
+fn resolve<'a>(label: &'a Label, primary: bool, source: &SourceFile, encoding: OffsetEncoding) -> (usize, LineLabel<'a>) {
+    let line = source.line_index(label.range.0, encoding);
+    let start = char_column(source, line, label.range.0, encoding);
+
+    let end = if source.line_index(label.range.1, encoding) == line {
+        char_column(source, line, label.range.1, encoding)
+    } else {
+        line_text(source, line).chars().count()
+    };
+
+    let message = label.message.as_deref();
+
+    (line, LineLabel { start, end: end.max(start), primary, message })
+}
+
+fn char_column(source: &SourceFile, line: usize, offset: usize, encoding: OffsetEncoding) -> usize {
+    let line_start = source.line_start(line, OffsetEncoding::Byte);
+    let offset = source.convert(offset, encoding, OffsetEncoding::Byte);
+
+    source.content()[line_start..offset].chars().count()
+}
+
+// The text of a line without its line terminator
+fn line_text<'src>(source: &SourceFile<'src>, line: usize) -> &'src str {
+    let start = source.line_start(line, OffsetEncoding::Byte);
+    let end = source.line_start(line + 1, OffsetEncoding::Byte);
+
+    source.content()[start..end].trim_end_matches(is_line_terminator)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tokenizer::{Tokenizer, TokenizerOptions}, ast, source::{SourceFile, OffsetEncoding}, diagnostic::{Diagnostic, Label}};
+
+    use super::{render, ColorMode, RenderOptions};
+
+    fn render_error(content: &str) -> String {
+        let error = ast::parse(Tokenizer::new(content)).unwrap_err();
+
+        render(&Diagnostic::from(&error), &SourceFile::new(content), "input.js", RenderOptions::default())
+    }
+
+    #[test]
+    fn parse_error_is_rendered_with_its_source_line() {
+        let output = render_error("foo;\nfoo(1;");
+
+        assert_eq!(output, concat!(
+            "error[E1001]: Unexpected token `;`, expected `)` or `,`\n",
+            " --> input.js:2:6\n",
+            "  |\n",
+            "2 | foo(1;\n",
+            "  |      ^ expected `)` or `,`\n",
+            "  |    - missing `)` to close call started here\n",
+        ));
+    }
+
+    #[test]
+    fn labels_on_other_lines_are_rendered_in_order() {
+        let output = render_error("{\n\nfoo;");
+
+        assert_eq!(output, concat!(
+            "error[E1003]: Unexpected end of input, expected `}`\n",
+            " --> input.js:3:5\n",
+            "  |\n",
+            "1 | {\n",
+            "  | - missing `}` to close block started here\n",
+            "...\n",
+            "3 | foo;\n",
+            "  |     ^ expected `}`\n",
+        ));
+    }
+
+    #[test]
+    fn tokenize_error_is_underlined() {
+        let output = render_error("foo(@);");
+
+        assert_eq!(output, concat!(
+            "error[E0001]: Unexpected character\n",
+            " --> input.js:1:5\n",
+            "  |\n",
+            "1 | foo(@);\n",
+            "  |     ^\n",
+        ));
+    }
+
+    #[test]
+    fn columns_count_characters_in_any_encoding() {
+        let content = "'é🦀' + @";
+        let options = TokenizerOptions { offset_encoding: OffsetEncoding::Utf16, ..Default::default() };
+
+        let error = ast::parse(Tokenizer::with_options(content, options)).unwrap_err();
+        let render_options = RenderOptions { encoding: OffsetEncoding::Utf16, ..Default::default() };
+        let output = render(&Diagnostic::from(&error), &SourceFile::new(content), "input.js", render_options);
+
+        assert!(output.contains(" --> input.js:1:8\n"));
+        assert!(output.ends_with("  |        ^\n"));
+    }
+
+    #[test]
+    fn tabs_are_kept_in_the_underline() {
+        let diagnostic = Diagnostic::new("Something went wrong", Label::new((1, 4)).with_message("here"))
+            .with_note("help: this is a note");
+
+        let output = render(&diagnostic, &SourceFile::new("\tfoo"), "main.js", RenderOptions::default());
+
+        assert_eq!(output, concat!(
+            "error: Something went wrong\n",
+            " --> main.js:1:2\n",
+            "  |\n",
+            "1 | \tfoo\n",
+            "  | \t^^^ here\n",
+            "  = help: this is a note\n",
+        ));
+    }
+
+    #[test]
+    fn ansi_mode_colors_the_output() {
+        let diagnostic = Diagnostic::new("Something went wrong", Label::new((0, 3)));
+        let options = RenderOptions { color: ColorMode::Ansi, ..Default::default() };
+
+        let output = render(&diagnostic, &SourceFile::new("foo"), "main.js", options);
+
+        assert!(output.starts_with("\x1b[1;31merror\x1b[0m: \x1b[1mSomething went wrong\x1b[0m\n"));
+        assert!(output.contains("\x1b[1;31m^^^\x1b[0m"));
+    }
+}
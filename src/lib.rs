@@ -0,0 +1,4 @@
+pub mod source;
+pub mod tokenizer;
+pub mod ast;
+pub mod diagnostic;
@@ -1,22 +1,106 @@
-use core::panic;
+use std::{env, fs, process, io::{self, IsTerminal}};
 
-mod tokenizer;
-mod ast;
+use joey_script::{tokenizer::{self, Tokenizer}, ast, source::{SourceFile, OffsetEncoding}, diagnostic::{self, Diagnostic, ColorMode, RenderOptions}};
+
+const USAGE: &str = "Usage: joey-script [tokens | ast [--json]] [file]";
 
 fn main() {
-    let input = String::from(include_str!("input.js"));
-    let tokens = tokenizer::parse(&input);
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let flags: Vec<&str> = args.iter().map(String::as_str).filter(|arg| arg.starts_with("--")).collect();
+    let mut values = args.iter().map(String::as_str).filter(|arg| !arg.starts_with("--"));
+
+    let command = values.next();
+    let (name, input) = match values.next() {
+        Some(path) => (path, fs::read_to_string(path).unwrap_or_else(|error| exit_with_error(&format!("Can't read {}: {}", path, error)))),
+        None => ("input.js", String::from(include_str!("input.js"))),
+    };
+
+    match (command, flags.as_slice()) {
+        (Some("tokens"), []) => print_tokens(name, &input),
+        (Some("ast"), []) => print_ast(name, &input),
+        (Some("ast"), ["--json"]) => print_ast_json(name, &input),
+        (None, []) => {
+            print_tokens(name, &input);
+            print_ast(name, &input);
+        },
+        _ => exit_with_error(USAGE),
+    }
+}
+
+fn print_tokens(name: &str, input: &str) {
+    let tokens = tokenizer::parse(input).unwrap_or_else(|error| exit_with_diagnostic(Diagnostic::from(&error), name, input, OffsetEncoding::Char));
 
     println!("=== Tokens ===");
     println!("{:#?}", tokens);
-    println!("");
-
-    // TODO: Temporary error handling
-    if tokens.is_err() { panic!("Invalid tokens"); }
+    println!();
+}
 
-    let program = ast::parse(&tokens.unwrap());
+fn print_ast(name: &str, input: &str) {
+    // The parser streams its own tokens from the input
+    let program = ast::parse(Tokenizer::new(input)).unwrap_or_else(|error| exit_with_diagnostic(Diagnostic::from(&error), name, input, OffsetEncoding::Char));
 
     println!("=== Syntax ===");
     println!("{:#?}", program);
-    println!("");
+    println!();
+}
+
+// ESTree tools like acorn count offsets in UTF-16 code units, the way JavaScript strings are indexed
+#[cfg(feature = "serde")]
+fn print_ast_json(name: &str, input: &str) {
+    let options = tokenizer::TokenizerOptions { offset_encoding: OffsetEncoding::Utf16, ..Default::default() };
+
+    match ast::parse(Tokenizer::with_options(input, options)) {
+        Ok(program) => println!("{}", ast::estree::to_json_pretty(&program)),
+        Err(error) => exit_with_diagnostic(Diagnostic::from(&error), name, input, OffsetEncoding::Utf16),
+    }
+}
+
+#[cfg(not(feature = "serde"))]
+fn print_ast_json(_name: &str, _input: &str) {
+    exit_with_error("JSON output needs joey-script to be built with the `serde` feature");
+}
+
+// Colors are only used on a terminal, and never when NO_COLOR is set (https://no-color.org)
+fn exit_with_diagnostic(diagnostic: Diagnostic, name: &str, input: &str, encoding: OffsetEncoding) -> ! {
+    let color = if io::stderr().is_terminal() && env::var_os("NO_COLOR").is_none() { ColorMode::Ansi } else { ColorMode::Plain };
+
+    eprint!("{}", render_diagnostic(&diagnostic, name, input, RenderOptions { color, encoding }));
+    process::exit(1);
+}
+
+// The tokenizer strips a leading byte order mark, the offsets of the diagnostic start after it
+fn render_diagnostic(diagnostic: &Diagnostic, name: &str, input: &str, options: RenderOptions) -> String {
+    let source = input.strip_prefix('\u{FEFF}').unwrap_or(input);
+
+    diagnostic::render(diagnostic, &SourceFile::new(source), name, options)
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use joey_script::{tokenizer::Tokenizer, ast, source::OffsetEncoding, diagnostic::{Diagnostic, ColorMode, RenderOptions}};
+
+    use super::render_diagnostic;
+
+    #[test]
+    fn diagnostic_skips_the_byte_order_mark() {
+        let input = "\u{FEFF}foo(@);";
+        let error = ast::parse(Tokenizer::new(input)).unwrap_err();
+        let options = RenderOptions { color: ColorMode::Plain, encoding: OffsetEncoding::Char };
+
+        let output = render_diagnostic(&Diagnostic::from(&error), "input.js", input, options);
+
+        assert_eq!(output, concat!(
+            "error[E0001]: Unexpected character\n",
+            " --> input.js:1:5\n",
+            "  |\n",
+            "1 | foo(@);\n",
+            "  |     ^\n",
+        ));
+    }
 }
@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod tests;
+
+/// The unit used to express offsets into a source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OffsetEncoding {
+    /// Unicode scalar values, the way the tokenizer walks the input.
+    #[default]
+    Char,
+    /// UTF-8 bytes, usable to slice the Rust `&str`.
+    Byte,
+    /// UTF-16 code units, as used by editors and ESTree tooling.
+    Utf16,
+}
+
+/// A single point in a source file expressed in every encoding we report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourcePosition {
+    pub byte_offset: usize,
+    pub utf16_offset: usize,
+    pub line: usize,
+    /// Column in UTF-16 code units, starting at 0.
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct MultiByteChar {
+    char_index: usize,
+    byte_offset: usize,
+    utf16_offset: usize,
+    len_utf8: usize,
+    len_utf16: usize,
+}
+
+impl MultiByteChar {
+    fn offset(&self, encoding: OffsetEncoding) -> usize {
+        match encoding {
+            OffsetEncoding::Char => self.char_index,
+            OffsetEncoding::Byte => self.byte_offset,
+            OffsetEncoding::Utf16 => self.utf16_offset,
+        }
+    }
+
+    fn len(&self, encoding: OffsetEncoding) -> usize {
+        match encoding {
+            OffsetEncoding::Char => 1,
+            OffsetEncoding::Byte => self.len_utf8,
+            OffsetEncoding::Utf16 => self.len_utf16,
+        }
+    }
+}
+
+/// The content of a file together with the tables needed to convert offsets between encodings.
+///
+/// Only characters outside of ASCII are recorded, so for plain ASCII input every encoding
+/// agrees and conversions are free.
+#[derive(Debug)]
+pub struct SourceFile<'src> {
+    content: &'src str,
+    multi_byte_chars: Vec<MultiByteChar>,
+    /// Byte offsets at which every line starts, the first line always starts at 0
+    line_starts: Vec<usize>,
+}
+
+impl<'src> SourceFile<'src> {
+    pub fn new(content: &'src str) -> SourceFile<'src> {
+        let mut multi_byte_chars = Vec::new();
+        let mut line_starts = vec![0];
+        let mut utf16_offset = 0;
+        let mut chars = content.char_indices().enumerate().peekable();
+
+        while let Some((char_index, (byte_offset, value))) = chars.next() {
+            let len_utf8 = value.len_utf8();
+            let len_utf16 = value.len_utf16();
+
+            if len_utf8 > 1 {
+                multi_byte_chars.push(MultiByteChar { char_index, byte_offset, utf16_offset, len_utf8, len_utf16 });
+            }
+
+            // A \r\n pair only ends a single line, which is recorded once we reach the \n
+            let is_crlf = value == '\r' && matches!(chars.peek(), Some((_, (_, '\n'))));
+
+            if is_line_terminator(value) && !is_crlf {
+                line_starts.push(byte_offset + len_utf8);
+            }
+
+            utf16_offset += len_utf16;
+        }
+
+        SourceFile { content, multi_byte_chars, line_starts }
+    }
+
+    pub fn content(&self) -> &'src str {
+        self.content
+    }
+
+    /// Length of the whole file in the given encoding.
+    pub fn len(&self, encoding: OffsetEncoding) -> usize {
+        self.convert(self.content.len(), OffsetEncoding::Byte, encoding)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+
+    /// Converts an offset from one encoding to another.
+    ///
+    /// Offsets that point into the middle of a character are rounded down to the start of
+    /// that character, offsets past the end of the file are clamped to the end.
+    pub fn convert(&self, offset: usize, from: OffsetEncoding, to: OffsetEncoding) -> usize {
+        if from == to { return offset; }
+
+        let count = self.multi_byte_chars.partition_point(|value| value.offset(from) < offset);
+
+        let converted = match count.checked_sub(1).map(|index| &self.multi_byte_chars[index]) {
+            Some(previous) => {
+                let previous_end = previous.offset(from) + previous.len(from);
+
+                if offset < previous_end {
+                    previous.offset(to)
+                } else {
+                    previous.offset(to) + previous.len(to) + (offset - previous_end)
+                }
+            },
+            None => offset,
+        };
+
+        let end = match self.multi_byte_chars.last() {
+            Some(last) => last.offset(to) + last.len(to) + (self.content.len() - last.byte_offset - last.len_utf8),
+            None => self.content.len(),
+        };
+
+        converted.min(end)
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// The index of the line containing the offset, starting at 0.
+    pub fn line_index(&self, offset: usize, encoding: OffsetEncoding) -> usize {
+        let byte_offset = self.convert(offset, encoding, OffsetEncoding::Byte);
+
+        self.line_starts.partition_point(|start| *start <= byte_offset) - 1
+    }
+
+    /// Like `line_index`, but the line at `hint` and the one after it are tried before searching every line, which
+    /// makes walking through a file from front to back cheap.
+    pub fn line_index_near(&self, offset: usize, encoding: OffsetEncoding, hint: usize) -> usize {
+        let byte_offset = self.convert(offset, encoding, OffsetEncoding::Byte);
+        let is_on_line = |index: usize| {
+            self.line_starts.get(index).is_some_and(|start| *start <= byte_offset)
+                && self.line_starts.get(index + 1).is_none_or(|next| byte_offset < *next)
+        };
+
+        if is_on_line(hint) { return hint; }
+        if is_on_line(hint + 1) { return hint + 1; }
+
+        self.line_starts.partition_point(|start| *start <= byte_offset) - 1
+    }
+
+    /// The offset at which the line with the given index starts.
+    pub fn line_start(&self, line_index: usize, encoding: OffsetEncoding) -> usize {
+        let byte_offset = self.line_starts.get(line_index).copied().unwrap_or(self.content.len());
+
+        self.convert(byte_offset, OffsetEncoding::Byte, encoding)
+    }
+
+    /// Resolves an offset to a line starting at 1 and a column starting at 0, both the offset
+    /// and the column are expressed in the given encoding.
+    pub fn line_column(&self, offset: usize, encoding: OffsetEncoding) -> (usize, usize) {
+        self.line_column_on(offset, encoding, self.line_index(offset, encoding))
+    }
+
+    /// Like `line_column`, but the line is found with `line_index_near`.
+    pub fn line_column_near(&self, offset: usize, encoding: OffsetEncoding, hint: usize) -> (usize, usize) {
+        self.line_column_on(offset, encoding, self.line_index_near(offset, encoding, hint))
+    }
+
+    fn line_column_on(&self, offset: usize, encoding: OffsetEncoding, line_index: usize) -> (usize, usize) {
+        let column = offset.saturating_sub(self.line_start(line_index, encoding));
+
+        (line_index + 1, column)
+    }
+
+    /// Resolves an offset in the given encoding to a full source position.
+    pub fn position(&self, offset: usize, encoding: OffsetEncoding) -> SourcePosition {
+        let byte_offset = self.convert(offset, encoding, OffsetEncoding::Byte);
+        let utf16_offset = self.convert(offset, encoding, OffsetEncoding::Utf16);
+        let (line, column) = self.line_column(utf16_offset, OffsetEncoding::Utf16);
+
+        SourcePosition { byte_offset, utf16_offset, line, column }
+    }
+}
+
+// https://262.ecma-international.org/13.0/#sec-line-terminators
+pub fn is_line_terminator(value: char) -> bool {
+    matches!(value, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
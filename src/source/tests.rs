@@ -0,0 +1,90 @@
+use super::{SourceFile, OffsetEncoding, SourcePosition};
+
+#[test]
+fn ascii_offsets_are_the_same_in_every_encoding() {
+    let source = SourceFile::new("let x = 1;");
+
+    assert_eq!(source.convert(4, OffsetEncoding::Char, OffsetEncoding::Byte), 4);
+    assert_eq!(source.convert(4, OffsetEncoding::Char, OffsetEncoding::Utf16), 4);
+    assert_eq!(source.convert(4, OffsetEncoding::Utf16, OffsetEncoding::Char), 4);
+}
+
+#[test]
+fn offsets_after_multi_byte_chars_are_converted() {
+    // é is 2 bytes and 1 UTF-16 unit, 🦀 is 4 bytes and 2 UTF-16 units
+    let source = SourceFile::new("'é🦀' + x");
+
+    // The `x` is the 8th char
+    assert_eq!(source.convert(7, OffsetEncoding::Char, OffsetEncoding::Byte), 11);
+    assert_eq!(source.convert(7, OffsetEncoding::Char, OffsetEncoding::Utf16), 8);
+    assert_eq!(source.convert(11, OffsetEncoding::Byte, OffsetEncoding::Char), 7);
+    assert_eq!(source.convert(8, OffsetEncoding::Utf16, OffsetEncoding::Byte), 11);
+}
+
+#[test]
+fn offsets_inside_a_char_round_down() {
+    let source = SourceFile::new("a🦀b");
+
+    assert_eq!(source.convert(3, OffsetEncoding::Byte, OffsetEncoding::Char), 1);
+    assert_eq!(source.convert(2, OffsetEncoding::Utf16, OffsetEncoding::Byte), 1);
+}
+
+#[test]
+fn offsets_past_the_end_are_clamped() {
+    let source = SourceFile::new("é");
+
+    assert_eq!(source.convert(10, OffsetEncoding::Char, OffsetEncoding::Byte), 2);
+    assert_eq!(source.len(OffsetEncoding::Char), 1);
+    assert_eq!(source.len(OffsetEncoding::Byte), 2);
+    assert_eq!(source.len(OffsetEncoding::Utf16), 1);
+}
+
+#[test]
+fn position_gives_back_every_encoding() {
+    let source = SourceFile::new("x;\n🦀 = y");
+
+    let position = source.position(5, OffsetEncoding::Char);
+
+    assert_eq!(position, SourcePosition { byte_offset: 8, utf16_offset: 6, line: 2, column: 3 });
+}
+
+#[test]
+fn every_line_terminator_starts_a_new_line() {
+    let source = SourceFile::new("a\nb\r\nc\rd\u{2028}e\u{2029}f");
+
+    assert_eq!(source.line_count(), 6);
+    assert_eq!(source.line_column(2, OffsetEncoding::Char), (2, 0));
+    assert_eq!(source.line_column(5, OffsetEncoding::Char), (3, 0));
+    assert_eq!(source.line_column(7, OffsetEncoding::Char), (4, 0));
+    assert_eq!(source.line_column(9, OffsetEncoding::Char), (5, 0));
+    assert_eq!(source.line_column(11, OffsetEncoding::Char), (6, 0));
+}
+
+#[test]
+fn carriage_return_of_crlf_stays_on_its_line() {
+    let source = SourceFile::new("ab\r\ncd");
+
+    assert_eq!(source.line_column(2, OffsetEncoding::Char), (1, 2));
+    assert_eq!(source.line_column(3, OffsetEncoding::Char), (1, 3));
+    assert_eq!(source.line_column(4, OffsetEncoding::Char), (2, 0));
+}
+
+#[test]
+fn line_index_near_looks_past_its_hint() {
+    let source = SourceFile::new("a\nb\nc\nd");
+
+    assert_eq!(source.line_index_near(2, OffsetEncoding::Char, 1), 1);
+    assert_eq!(source.line_index_near(4, OffsetEncoding::Char, 1), 2);
+    assert_eq!(source.line_index_near(6, OffsetEncoding::Char, 1), 3);
+    assert_eq!(source.line_index_near(0, OffsetEncoding::Char, 2), 0);
+    assert_eq!(source.line_index_near(6, OffsetEncoding::Char, 10), 3);
+}
+
+#[test]
+fn line_start_is_given_in_the_requested_encoding() {
+    let source = SourceFile::new("🦀\nx");
+
+    assert_eq!(source.line_start(1, OffsetEncoding::Char), 2);
+    assert_eq!(source.line_start(1, OffsetEncoding::Byte), 5);
+    assert_eq!(source.line_start(1, OffsetEncoding::Utf16), 3);
+}
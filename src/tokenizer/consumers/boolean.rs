@@ -1,55 +1,32 @@
-use crate::{tokenizer::{tokenizer::Tokenizer, TokenizeError, Token, TokenErrorType, Literal, TokenType, FileLocation}, tokenize_error};
+use crate::{tokenizer::{tokenizer::Tokenizer, TokenizeError, Token, TokenErrorType, Literal, TokenType}, tokenize_error};
 
-use super::is_word;
+use super::{is_reserved_word, consume_fixed};
 
 fn is_true(tokenizer: &Tokenizer) -> bool {
-    is_word(tokenizer, "true")
+    is_reserved_word(tokenizer, "true")
 } 
 
 fn is_false(tokenizer: &Tokenizer) -> bool {
-    is_word(tokenizer, "false")
+    is_reserved_word(tokenizer, "false")
 } 
 
 pub fn is_boolean(tokenizer: &Tokenizer) -> bool {
     is_true(tokenizer) || is_false(tokenizer)
 }
 
-fn consume_value(tokenizer: &mut Tokenizer, length: usize) -> Result<Token, TokenizeError> {
-    let start = tokenizer.get_current_index();
-    let start_pos = tokenizer.get_current_file_loc();
-
-    let mut raw_value = String::new();
-
-    for _ in 0..length {
-        raw_value.push(tokenizer
-            .consume()
-            .unwrap()
-            .clone()
-        );
-    }
-
-    let end  = tokenizer.get_current_index();
-    let end_pos = tokenizer.get_current_file_loc();
-    let value = raw_value.clone();
-
-    Ok(Token {
-        token_type: TokenType::Literal(Literal::Boolean),
-        value,
-        raw_value,
-        range: (start, end),
-        loc: FileLocation { start: start_pos, end: end_pos }
-    })
+fn consume_value<'src>(tokenizer: &mut Tokenizer<'src>, length: usize) -> Result<Token<'src>, TokenizeError> {
+    Ok(consume_fixed(tokenizer, length, TokenType::Literal(Literal::Boolean)))
 }
 
-fn consume_true(tokenizer: &mut Tokenizer) -> Result<Token, TokenizeError> {
+fn consume_true<'src>(tokenizer: &mut Tokenizer<'src>) -> Result<Token<'src>, TokenizeError> {
     consume_value(tokenizer, 4)
 }
 
-fn consume_false(tokenizer: &mut Tokenizer) -> Result<Token, TokenizeError> {
+fn consume_false<'src>(tokenizer: &mut Tokenizer<'src>) -> Result<Token<'src>, TokenizeError> {
     consume_value(tokenizer, 5)
 }
 
-pub fn consume_boolean(tokenizer: &mut Tokenizer) -> Result<Token, TokenizeError> {
+pub fn consume_boolean<'src>(tokenizer: &mut Tokenizer<'src>) -> Result<Token<'src>, TokenizeError> {
 
     if is_true(tokenizer) {
         return consume_true(tokenizer);
@@ -59,7 +36,7 @@ pub fn consume_boolean(tokenizer: &mut Tokenizer) -> Result<Token, TokenizeError
         return consume_false(tokenizer);
     }
 
-    return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer);
+    tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer)
 }
 
 #[cfg(test)]
@@ -75,7 +52,7 @@ mod tests {
 
         let result = super::is_true(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -85,7 +62,7 @@ mod tests {
 
         let result = super::is_true(&tokenizer);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
     
     #[test]
@@ -95,7 +72,7 @@ mod tests {
 
         let result = super::is_false(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -105,7 +82,7 @@ mod tests {
 
         let result = super::is_false(&tokenizer);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
@@ -115,7 +92,7 @@ mod tests {
 
         let result = super::is_boolean(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -125,7 +102,7 @@ mod tests {
 
         let result = super::is_boolean(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -0,0 +1,98 @@
+use crate::tokenize_error;
+use crate::tokenizer::tokenizer::Tokenizer;
+use crate::tokenizer::{util as util, Token, TokenizeError, TokenType, Separator, TokenErrorType};
+
+use super::consume_fixed;
+
+pub fn is_bracket(tokenizer: &Tokenizer) -> bool {
+    let token = tokenizer.token().unwrap();
+
+    util::is_bracket(token)
+}
+
+pub fn consume_bracket<'src>(tokenizer: &mut Tokenizer<'src>) -> Result<Token<'src>, TokenizeError>  {
+    if !is_bracket(tokenizer) {
+        return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer);
+    }
+
+    let separator = match tokenizer.token() {
+        Some('[') => Separator::OpenBracket,
+        _ => Separator::CloseBracket,
+    };
+
+    Ok(consume_fixed(tokenizer, 1, TokenType::Separator(separator)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::tokenizer::{tokenizer::Tokenizer, TokenType, Separator, TokenErrorType};
+
+    #[test]
+    fn is_open_bracket_input_a_bracket() {
+        let input = String::from_str("[").unwrap();
+        let tokenizer = Tokenizer::new(&input);
+
+        let result = super::is_bracket(&tokenizer);
+
+        assert!(result);
+    }
+
+    #[test]
+    fn is_closed_bracket_input_a_bracket() {
+        let input = String::from_str("]").unwrap();
+        let tokenizer = Tokenizer::new(&input);
+
+        let result = super::is_bracket(&tokenizer);
+
+        assert!(result);
+    }
+
+    #[test]
+    fn is_whitespace_input_not_a_bracket() {
+        let input = String::from_str(" ").unwrap();
+        let tokenizer = Tokenizer::new(&input);
+
+        let result = super::is_bracket(&tokenizer);
+
+        assert!(!result);
+    }
+
+    #[test]
+    fn consume_open_bracket_input() {
+        let input = String::from_str("[").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_bracket(&mut tokenizer).unwrap();
+
+        assert_eq!(tokenizer.get_current_index(), input.len());
+        assert_eq!(token.token_type, TokenType::Separator(Separator::OpenBracket));
+        assert_eq!(token.value, "[");
+        assert_eq!(token.raw_value, "[");
+    }
+
+    #[test]
+    fn consume_closed_bracket_input() {
+        let input = String::from_str("]").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_bracket(&mut tokenizer).unwrap();
+
+        assert_eq!(tokenizer.get_current_index(), input.len());
+        assert_eq!(token.token_type, TokenType::Separator(Separator::CloseBracket));
+        assert_eq!(token.value, "]");
+        assert_eq!(token.raw_value, "]");
+    }
+
+    #[test]
+    fn consume_invalid_input() {
+        let input = String::from_str("🦀").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_bracket(&mut tokenizer).unwrap_err();
+
+        assert_eq!(token.error_type, TokenErrorType::UnexpectedToken);
+    }
+
+}
@@ -1,14 +1,14 @@
-use crate::tokenizer::{tokenizer::Tokenizer, util::is_eol};
+use crate::tokenizer::{tokenizer::Tokenizer, util::is_eol, TokenErrorType, TokenizeError};
 
 fn is_start_line_comment(tokenizer: &Tokenizer) -> bool {
-    let first   = tokenizer.token().unwrap();
-    let second  = tokenizer.peek().unwrap_or(&' ');
-
-    *first == '/' && *second == '/'
+    tokenizer.starts_with("//")
 }
 
-fn is_end_line_comment(tokenizer: &Tokenizer) -> bool {
-    is_eol(tokenizer.token().unwrap_or(&' '))
+// The line terminator isn't part of the comment, it's left for the tokenizer
+fn consume_until_end_of_line(tokenizer: &mut Tokenizer) {
+    let remaining = tokenizer.remaining();
+
+    tokenizer.advance(remaining.find(is_eol).unwrap_or(remaining.len()));
 }
 
 pub fn is_line_comment(tokenizer: &Tokenizer) -> bool {
@@ -18,52 +18,63 @@ pub fn is_line_comment(tokenizer: &Tokenizer) -> bool {
 pub fn consume_line_comment(tokenizer: &mut Tokenizer) {
     if !is_start_line_comment(tokenizer) { return; }
 
-    let mut consumed = tokenizer.token();
+    consume_until_end_of_line(tokenizer);
+}
 
-    while consumed.is_some() && !is_end_line_comment(tokenizer) {
+// A hashbang is only allowed as the very first thing in the file
+pub fn is_hashbang_comment(tokenizer: &Tokenizer) -> bool {
+    tokenizer.get_current_index() == 0 && tokenizer.starts_with("#!")
+}
 
-        tokenizer.next();
-        consumed = tokenizer.token();
-    }
+pub fn consume_hashbang_comment(tokenizer: &mut Tokenizer) {
+    if !is_hashbang_comment(tokenizer) { return; }
 
-    if is_end_line_comment(tokenizer) {
-        tokenizer.next(); // Consume new line
-    }
+    consume_until_end_of_line(tokenizer);
 }
 
-fn is_start_block_comment(tokenizer: &Tokenizer) -> bool {
-    let first   = tokenizer.token().unwrap();
-    let second  = tokenizer.peek().unwrap_or(&' ');
+// https://262.ecma-international.org/13.0/#sec-html-like-comments
+pub fn is_html_open_comment(tokenizer: &Tokenizer) -> bool {
+    tokenizer.starts_with("<!--")
+}
 
-    *first == '/' && *second == '*'
+// `-->` is only a comment when nothing but whitespace and comments came before it on its line
+pub fn is_html_close_comment(tokenizer: &Tokenizer) -> bool {
+    tokenizer.is_at_line_start() && tokenizer.starts_with("-->")
 }
 
-fn is_end_block_comment(tokenizer: &mut Tokenizer) -> bool {
-    
-    let first   = tokenizer.token().unwrap_or(&' ');
-    let second  = tokenizer.peek().unwrap_or(&' ');
+pub fn consume_html_comment(tokenizer: &mut Tokenizer) {
+    if !is_html_open_comment(tokenizer) && !is_html_close_comment(tokenizer) { return; }
 
-    *first == '*' && *second == '/'
+    consume_until_end_of_line(tokenizer);
+}
+
+fn is_start_block_comment(tokenizer: &Tokenizer) -> bool {
+    tokenizer.starts_with("/*")
 }
 
 pub fn is_block_comment(tokenizer: &Tokenizer) -> bool {
     is_start_block_comment(tokenizer)
 }
 
-pub fn consume_block_comment(tokenizer: &mut Tokenizer) {
-    if !is_start_block_comment(tokenizer) { return; }
+// An unterminated comment runs to the end of the file, the error points at the `/*` that opened it
+pub fn consume_block_comment(tokenizer: &mut Tokenizer) -> Result<(), TokenizeError> {
+    if !is_start_block_comment(tokenizer) { return Ok(()); }
 
-    let mut consumed = tokenizer.token();
-    println!("{:?}", consumed);
+    let start = tokenizer.get_current_offset();
 
-    while consumed.is_some() && !is_end_block_comment(tokenizer) {
-        tokenizer.next();
-        consumed = tokenizer.token();
-    }    
+    tokenizer.advance(2);
 
-    if is_end_block_comment(tokenizer) {
-        tokenizer.next(); // Consume last *
-        tokenizer.next(); // Consume last /         
+    let remaining = tokenizer.remaining();
+
+    match remaining.find("*/") {
+        Some(length) => {
+            tokenizer.advance(length + 2);
+            Ok(())
+        },
+        None => {
+            tokenizer.advance(remaining.len());
+            Err(TokenizeError { error_type: TokenErrorType::UnterminatedComment, index: start })
+        },
     }
 }
 
@@ -71,7 +82,7 @@ pub fn consume_block_comment(tokenizer: &mut Tokenizer) {
 mod tests {
     use std::str::FromStr;
 
-    use crate::tokenizer::tokenizer::Tokenizer;
+    use crate::tokenizer::{tokenizer::Tokenizer, TokenErrorType};
 
     #[test]
     fn is_start_line_comment() {
@@ -80,7 +91,7 @@ mod tests {
 
         let result = super::is_line_comment(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -90,7 +101,20 @@ mod tests {
 
         let result = super::is_block_comment(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
+    }
+
+    #[test]
+    fn block_comment_opening_is_not_its_end() {
+        let input = String::from_str("a /*/ Foo *").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+        tokenizer.advance(2);
+
+        let error = super::consume_block_comment(&mut tokenizer).unwrap_err();
+
+        assert_eq!(error.error_type, TokenErrorType::UnterminatedComment);
+        assert_eq!(error.index, 2);
+        assert_eq!(tokenizer.get_current_index(), input.len());
     }
 
     #[test]
@@ -110,7 +134,44 @@ mod tests {
 
         super::consume_line_comment(&mut tokenizer);
 
-        assert_eq!(tokenizer.get_current_index(), 7);
+        assert_eq!(tokenizer.get_current_index(), 6);
+    }
+
+    #[test]
+    fn hashbang_only_at_the_start_of_the_file() {
+        let input = String::from_str("#!/usr/bin/env joey\nfoo").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        assert!(super::is_hashbang_comment(&tokenizer));
+
+        super::consume_hashbang_comment(&mut tokenizer);
+
+        assert_eq!(tokenizer.get_current_index(), 19);
+        assert!(!super::is_hashbang_comment(&tokenizer));
+    }
+
+    #[test]
+    fn process_html_open_comment() {
+        let input = String::from_str("<!-- Foo\nbar").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        assert!(super::is_html_open_comment(&tokenizer));
+
+        super::consume_html_comment(&mut tokenizer);
+
+        assert_eq!(tokenizer.get_current_index(), 8);
+    }
+
+    #[test]
+    fn process_html_close_comment() {
+        let input = String::from_str("--> Foo").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        assert!(super::is_html_close_comment(&tokenizer));
+
+        super::consume_html_comment(&mut tokenizer);
+
+        assert_eq!(tokenizer.get_current_index(), input.len());
     }
 
     #[test]
@@ -118,7 +179,7 @@ mod tests {
         let input = String::from_str("/* Foobar */").unwrap();
         let mut tokenizer = Tokenizer::new(&input);
 
-        super::consume_block_comment(&mut tokenizer);
+        super::consume_block_comment(&mut tokenizer).unwrap();
 
         assert_eq!(tokenizer.get_current_index(), input.len());
     }
@@ -128,7 +189,10 @@ mod tests {
         let input = String::from_str("/* Foobar").unwrap();
         let mut tokenizer = Tokenizer::new(&input);
 
-        super::consume_block_comment(&mut tokenizer);
+        let error = super::consume_block_comment(&mut tokenizer).unwrap_err();
+
+        assert_eq!(error.error_type, TokenErrorType::UnterminatedComment);
+        assert_eq!(error.index, 0);
 
         assert_eq!(tokenizer.get_current_index(), input.len());
     }
@@ -138,7 +202,10 @@ mod tests {
         let input = String::from_str("/* Foobar *").unwrap();
         let mut tokenizer = Tokenizer::new(&input);
 
-        super::consume_block_comment(&mut tokenizer);
+        let error = super::consume_block_comment(&mut tokenizer).unwrap_err();
+
+        assert_eq!(error.error_type, TokenErrorType::UnterminatedComment);
+        assert_eq!(error.index, 0);
 
         assert_eq!(tokenizer.get_current_index(), input.len());
     }
@@ -1,6 +1,8 @@
 use crate::tokenize_error;
 use crate::tokenizer::tokenizer::Tokenizer;
-use crate::tokenizer::{util as util, Token, TokenizeError, TokenType, Separator, TokenErrorType, FileLocation};
+use crate::tokenizer::{util as util, Token, TokenizeError, TokenType, Separator, TokenErrorType};
+
+use super::consume_fixed;
 
 pub fn is_curly_brace(tokenizer: &Tokenizer) -> bool {
     let token = tokenizer.token().unwrap();
@@ -8,25 +10,17 @@ pub fn is_curly_brace(tokenizer: &Tokenizer) -> bool {
     util::is_curly_brace(token)
 }
 
-pub fn consume_curly_brace(tokenizer: &mut Tokenizer) -> Result<Token, TokenizeError>  {
+pub fn consume_curly_brace<'src>(tokenizer: &mut Tokenizer<'src>) -> Result<Token<'src>, TokenizeError>  {
     if !is_curly_brace(tokenizer) {
         return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer);
     }
 
-    let start = tokenizer.get_current_index();
-    let start_pos = tokenizer.get_current_file_loc();
-
-    let token = tokenizer.consume().unwrap().to_string();
-    let end = tokenizer.get_current_index();
-    let end_pos = tokenizer.get_current_file_loc();
+    let separator = match tokenizer.token() {
+        Some('{') => Separator::OpenCurlyBrace,
+        _ => Separator::CloseCurlyBrace,
+    };
 
-    Ok(Token {
-        token_type: TokenType::Separator(Separator::CurlyBrace),
-        value: token.clone(),
-        raw_value: token,
-        range: (start, end),
-        loc: FileLocation { start: start_pos, end: end_pos }
-    })
+    Ok(consume_fixed(tokenizer, 1, TokenType::Separator(separator)))
 }
 
 #[cfg(test)]
@@ -42,7 +36,7 @@ mod tests {
 
         let result = super::is_curly_brace(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -52,7 +46,7 @@ mod tests {
 
         let result = super::is_curly_brace(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -62,7 +56,7 @@ mod tests {
 
         let result = super::is_curly_brace(&tokenizer);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
@@ -73,7 +67,7 @@ mod tests {
         let token = super::consume_curly_brace(&mut tokenizer).unwrap();
 
         assert_eq!(tokenizer.get_current_index(), input.len());
-        assert_eq!(token.token_type, TokenType::Separator(Separator::CurlyBrace));
+        assert_eq!(token.token_type, TokenType::Separator(Separator::OpenCurlyBrace));
         assert_eq!(token.value, "{");
         assert_eq!(token.raw_value, "{");
     }
@@ -96,7 +90,7 @@ mod tests {
         let token = super::consume_curly_brace(&mut tokenizer).unwrap();
 
         assert_eq!(tokenizer.get_current_index(), input.len());
-        assert_eq!(token.token_type, TokenType::Separator(Separator::CurlyBrace));
+        assert_eq!(token.token_type, TokenType::Separator(Separator::CloseCurlyBrace));
         assert_eq!(token.value, "}");
         assert_eq!(token.raw_value, "}");
     }
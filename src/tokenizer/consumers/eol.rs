@@ -13,10 +13,14 @@ pub fn consume_eol(tokenizer: &mut Tokenizer) -> Result<(), TokenizeError>  {
         return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer);
     }
 
-    tokenizer.next();
-    tokenizer.found_new_line();
+    // A \r\n pair is a single line terminator
+    let is_crlf = tokenizer.starts_with("\r\n");
 
-    return Ok(())
+    tokenizer.next_char();
+
+    if is_crlf { tokenizer.next_char(); }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -32,7 +36,7 @@ mod tests {
 
         let result = super::is_eol(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -42,7 +46,7 @@ mod tests {
 
         let result = super::is_eol(&tokenizer);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
@@ -50,11 +54,30 @@ mod tests {
         let input = String::from_str("\n").unwrap();
         let mut tokenizer = Tokenizer::new(&input);
 
-        _ = super::consume_eol(&mut tokenizer).unwrap();
+        super::consume_eol(&mut tokenizer).unwrap();
 
         assert_eq!(tokenizer.get_current_index(), input.len());
     }
 
+    #[test]
+    fn consume_crlf_as_single_eol() {
+        let input = String::from_str("\r\nx").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        super::consume_eol(&mut tokenizer).unwrap();
+
+        assert_eq!(tokenizer.get_current_index(), 2);
+    }
+
+    #[test]
+    fn unicode_line_separators_are_eol() {
+        for input in ["\r", "\u{2028}", "\u{2029}"] {
+            let tokenizer = Tokenizer::new(input);
+
+            assert!(super::is_eol(&tokenizer));
+        }
+    }
+
     #[test]
     fn consume_invalid_input() {
         let input = String::from_str("🦀").unwrap();
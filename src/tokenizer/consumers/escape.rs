@@ -0,0 +1,155 @@
+use crate::tokenize_error;
+use crate::tokenizer::tokenizer::Tokenizer;
+use crate::tokenizer::{util as util, TokenizeError, TokenErrorType};
+
+pub fn is_unicode_escape(tokenizer: &Tokenizer) -> bool {
+    tokenizer.starts_with("\\u")
+}
+
+fn consume_hex_digits(tokenizer: &mut Tokenizer, max_length: usize) -> String {
+    let mut digits = String::new();
+
+    while let Some(token) = tokenizer.token() {
+        if !token.is_ascii_hexdigit() || digits.len() == max_length { break; }
+
+        digits.push(token);
+        tokenizer.next_char();
+    }
+
+    digits
+}
+
+fn to_char(digits: &str) -> Option<char> {
+    u32::from_str_radix(digits, 16).ok().and_then(char::from_u32)
+}
+
+// https://262.ecma-international.org/13.0/#prod-UnicodeEscapeSequence
+pub fn consume_unicode_escape(tokenizer: &mut Tokenizer) -> Result<char, TokenizeError> {
+    if !is_unicode_escape(tokenizer) {
+        return tokenize_error!(TokenErrorType::InvalidUnicodeEscapeSequence, tokenizer);
+    }
+
+    // Consume the \u
+    tokenizer.next_char();
+    tokenizer.next_char();
+
+    let is_braced = tokenizer.token() == Some('{');
+
+    if is_braced { tokenizer.next_char(); }
+
+    let digits = consume_hex_digits(tokenizer, if is_braced { usize::MAX } else { 4 });
+
+    if is_braced {
+        if tokenizer.token() != Some('}') {
+            return tokenize_error!(TokenErrorType::InvalidUnicodeEscapeSequence, tokenizer);
+        }
+
+        tokenizer.next_char();
+    }
+
+    let has_valid_length = if is_braced { !digits.is_empty() } else { digits.len() == 4 };
+
+    match to_char(&digits) {
+        Some(value) if has_valid_length => Ok(value),
+        _ => tokenize_error!(TokenErrorType::InvalidUnicodeEscapeSequence, tokenizer),
+    }
+}
+
+// https://262.ecma-international.org/13.0/#prod-HexEscapeSequence
+fn consume_hex_escape(tokenizer: &mut Tokenizer) -> Result<char, TokenizeError> {
+    // Consume the \x
+    tokenizer.next_char();
+    tokenizer.next_char();
+
+    let digits = consume_hex_digits(tokenizer, 2);
+
+    match to_char(&digits) {
+        Some(value) if digits.len() == 2 => Ok(value),
+        _ => tokenize_error!(TokenErrorType::InvalidHexEscapeSequence, tokenizer),
+    }
+}
+
+/// Consumes an escape sequence within a string literal, starting at the backslash.
+///
+/// Gives back `None` for a line continuation, which doesn't add anything to the string value.
+// https://262.ecma-international.org/13.0/#prod-EscapeSequence
+pub fn consume_escape_sequence(tokenizer: &mut Tokenizer) -> Result<Option<char>, TokenizeError> {
+    let escaped = match tokenizer.peek() {
+        Some(token) => token,
+        None => return tokenize_error!(TokenErrorType::UnterminatedStringLiteral, tokenizer),
+    };
+
+    match escaped {
+        'u' => return consume_unicode_escape(tokenizer).map(Some),
+        'x' => return consume_hex_escape(tokenizer).map(Some),
+        _ => {},
+    }
+
+    // Consume the backslash and the escaped character
+    tokenizer.next_char();
+    let is_crlf = tokenizer.starts_with("\r\n");
+    tokenizer.next_char();
+
+    if util::is_eol(escaped) {
+        if is_crlf { tokenizer.next_char(); }
+
+        return Ok(None);
+    }
+
+    let value = match escaped {
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        'b' => '\u{0008}',
+        'f' => '\u{000C}',
+        'v' => '\u{000B}',
+        '0' => '\0',
+        _ => escaped,
+    };
+
+    Ok(Some(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tokenizer::{tokenizer::Tokenizer, TokenErrorType};
+
+    macro_rules! consume_escape_sequence_tests {
+        ($($name:ident: $input:expr => $expected:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let mut tokenizer = Tokenizer::new($input);
+
+                let result = super::consume_escape_sequence(&mut tokenizer).unwrap();
+
+                assert_eq!(result, $expected);
+                assert_eq!(tokenizer.get_current_index(), $input.len());
+            }
+        )*
+        };
+    }
+
+    consume_escape_sequence_tests! {
+        consume_newline_escape: "\\n" => Some('\n'),
+        consume_tab_escape: "\\t" => Some('\t'),
+        consume_null_escape: "\\0" => Some('\0'),
+        consume_identity_escape: "\\q" => Some('q'),
+        consume_quote_escape: "\\'" => Some('\''),
+        consume_hex_escape: "\\x41" => Some('A'),
+        consume_unicode_escape: "\\u0041" => Some('A'),
+        consume_braced_unicode_escape: "\\u{1F980}" => Some('🦀'),
+        consume_line_continuation: "\\\n" => None,
+        consume_crlf_line_continuation: "\\\r\n" => None,
+    }
+
+    #[test]
+    fn short_hex_escape_gives_an_error() {
+        let mut tokenizer = Tokenizer::new("\\x4");
+
+        let result = super::consume_escape_sequence(&mut tokenizer).unwrap_err();
+
+        assert_eq!(result.error_type, TokenErrorType::InvalidHexEscapeSequence);
+    }
+
+}
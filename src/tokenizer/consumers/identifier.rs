@@ -1,37 +1,98 @@
+use std::borrow::Cow;
+
+use crate::tokenize_error;
 use crate::tokenizer::tokenizer::Tokenizer;
-use crate::tokenizer::{util as util, Token, TokenType, TokenizeError, FileLocation };
+use crate::tokenizer::{util as util, Token, TokenType, TokenizeError, TokenErrorType, FileLocation, Keyword};
+
+use super::escape::{is_unicode_escape, consume_unicode_escape};
+use super::keywords::keyword_from_word;
+
+// https://262.ecma-international.org/13.0/#prod-ReservedWord
+// `await` and `yield` are identifiers to the parser outside of async functions and generators
+fn is_reserved_name(name: &str) -> bool {
+    match keyword_from_word(name) {
+        Some(Keyword::Await | Keyword::Yield) => false,
+        Some(_) => true,
+        None => matches!(name, "true" | "false" | "null"),
+    }
+}
 
 pub fn is_identifier(tokenizer: &Tokenizer) -> bool {
     let token = tokenizer.token().unwrap();
 
-    util::is_identifier(token)
+    util::is_identifier_start(token) || is_unicode_escape(tokenizer)
 }
 
-pub fn consume_identifier(tokenizer: &mut Tokenizer) -> Result<Token, TokenizeError> {
-    let mut value = String::new();
+pub fn consume_identifier<'src>(tokenizer: &mut Tokenizer<'src>) -> Result<Token<'src>, TokenizeError> {
+    if !is_identifier(tokenizer) {
+        return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer);
+    }
+
     let start = tokenizer.get_current_index();
     let start_pos = tokenizer.get_current_file_loc();
 
-    let mut token = tokenizer.consume();
+    // Only identifiers with escape sequences need their own copy of the name
+    let mut cooked: Option<String> = None;
+    let mut first_escape: Option<usize> = None;
+
+    while let Some(token) = tokenizer.token() {
+        let is_first = tokenizer.get_current_index() == start;
+        let is_valid = |value: char| {
+            if is_first { util::is_identifier_start(value) } else { util::is_identifier_part(value) }
+        };
+
+        if is_unicode_escape(tokenizer) {
+            let escape_start = tokenizer.get_current_offset();
+            first_escape.get_or_insert(escape_start);
+            let name = cooked.get_or_insert_with(|| {
+                tokenizer.slice(start, tokenizer.get_current_index()).to_string()
+            });
+
+            let escaped = consume_unicode_escape(tokenizer)?;
+
+            // An escape sequence has to resolve to a character that is valid at this position
+            if !is_valid(escaped) {
+                return Err(TokenizeError { error_type: TokenErrorType::InvalidUnicodeEscapeSequence, index: escape_start });
+            }
+
+            name.push(escaped);
+            continue;
+        }
+
+        if !is_valid(token) { break; }
 
-    while token.is_some() && util::is_identifier(&token.unwrap()) {
-        value.push(token.unwrap().clone());
+        if let Some(name) = cooked.as_mut() {
+            name.push(token);
+        }
 
-        token = tokenizer.consume();
+        tokenizer.next_char();
+    }
+
+    // https://262.ecma-international.org/13.0/#sec-identifier-names-static-semantics-early-errors
+    // Escapes can't spell a reserved word, only a property name after a period can be one
+    if let (Some(name), Some(index)) = (&cooked, first_escape) {
+        if !tokenizer.is_after_period() && is_reserved_name(name) {
+            return Err(TokenizeError { error_type: TokenErrorType::EscapedReservedWord, index });
+        }
     }
-    
-    tokenizer.walk_back();
 
     let end = tokenizer.get_current_index();
     let end_pos = tokenizer.get_current_file_loc();
-    let raw_value = value.clone();
+    let raw_value = tokenizer.slice(start, end);
+
+    let value = match cooked {
+        Some(name) => Cow::Owned(name),
+        None => Cow::Borrowed(raw_value),
+    };
 
     Ok(Token {
         token_type: TokenType::Identifier,
-        raw_value,
         value,
-        range: (start, end),
+        raw_value,
+        range: tokenizer.get_range(start, end),
         loc: FileLocation { start: start_pos, end: end_pos },
+        leading_trivia: Vec::new(),
+        trailing_trivia: Vec::new(),
     })
 }
 
@@ -39,7 +100,7 @@ pub fn consume_identifier(tokenizer: &mut Tokenizer) -> Result<Token, TokenizeEr
 mod tests {
     use std::str::FromStr;
 
-    use crate::tokenizer::{tokenizer::Tokenizer, TokenType};
+    use crate::tokenizer::{tokenizer::Tokenizer, TokenType, TokenErrorType};
 
     #[test]
     fn is_identifier_identifier() {
@@ -48,7 +109,7 @@ mod tests {
 
         let result = super::is_identifier(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -58,7 +119,7 @@ mod tests {
 
         let result = super::is_identifier(&tokenizer);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
@@ -85,4 +146,163 @@ mod tests {
         assert_eq!(tokenizer.get_current_index(), input.len());
     }
     
+    macro_rules! consume_identifier_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = String::from_str($value).unwrap();
+                let mut tokenizer = Tokenizer::new(&input);
+
+                let token = super::consume_identifier(&mut tokenizer).unwrap();
+
+                assert_eq!(token.value, $value);
+                assert_eq!(token.raw_value, $value);
+                assert_eq!(token.token_type, TokenType::Identifier);
+                assert_eq!(tokenizer.get_current_index(), input.len());
+            }
+        )*
+        };
+    }
+
+    consume_identifier_tests! {
+        consume_underscore_identifier: "_private",
+        consume_dollar_identifier: "$el",
+        consume_unicode_identifier: "café",
+        consume_identifier_with_digits: "x1",
+        consume_identifier_with_zwnj: "a\u{200C}b",
+        consume_identifier_with_zwj: "a\u{200D}b",
+    }
+
+    #[test]
+    fn digit_is_not_an_identifier_start() {
+        let input = String::from_str("1x").unwrap();
+        let tokenizer = Tokenizer::new(&input);
+
+        let result = super::is_identifier(&tokenizer);
+
+        assert!(!result);
+    }
+
+    #[test]
+    fn consume_identifier_stops_at_punctuation() {
+        let input = String::from_str("foo.bar").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_identifier(&mut tokenizer).unwrap();
+
+        assert_eq!(token.value, "foo");
+        assert_eq!(tokenizer.get_current_index(), 3);
+    }
+
+    #[test]
+    fn consume_identifier_with_unicode_escape() {
+        let input = String::from_str("\\u0061bc").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_identifier(&mut tokenizer).unwrap();
+
+        assert_eq!(token.value, "abc");
+        assert_eq!(token.raw_value, "\\u0061bc");
+        assert_eq!(tokenizer.get_current_index(), input.len());
+    }
+
+    #[test]
+    fn consume_identifier_with_braced_unicode_escape() {
+        let input = String::from_str("a\\u{62}c").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_identifier(&mut tokenizer).unwrap();
+
+        assert_eq!(token.value, "abc");
+        assert_eq!(token.raw_value, "a\\u{62}c");
+        assert_eq!(tokenizer.get_current_index(), input.len());
+    }
+
+    #[test]
+    fn escaped_digit_is_valid_identifier_part() {
+        let input = String::from_str("a\\u0031").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_identifier(&mut tokenizer).unwrap();
+
+        assert_eq!(token.value, "a1");
+    }
+
+    #[test]
+    fn escaped_digit_is_not_a_valid_identifier_start() {
+        let input = String::from_str("\\u0031a").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_identifier(&mut tokenizer).unwrap_err();
+
+        assert_eq!(token.error_type, TokenErrorType::InvalidUnicodeEscapeSequence);
+        assert_eq!(token.index, 0);
+    }
+
+    #[test]
+    fn short_unicode_escape_gives_an_error() {
+        let input = String::from_str("\\u61").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_identifier(&mut tokenizer).unwrap_err();
+
+        assert_eq!(token.error_type, TokenErrorType::InvalidUnicodeEscapeSequence);
+    }
+
+    #[test]
+    fn unclosed_braced_unicode_escape_gives_an_error() {
+        let input = String::from_str("\\u{61").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_identifier(&mut tokenizer).unwrap_err();
+
+        assert_eq!(token.error_type, TokenErrorType::InvalidUnicodeEscapeSequence);
+    }
+
+    #[test]
+    fn out_of_range_unicode_escape_gives_an_error() {
+        let input = String::from_str("\\u{110000}").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_identifier(&mut tokenizer).unwrap_err();
+
+        assert_eq!(token.error_type, TokenErrorType::InvalidUnicodeEscapeSequence);
+    }
+
+    macro_rules! escaped_reserved_word_tests {
+        ($($name:ident: $value:expr => $index:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = String::from_str($value).unwrap();
+                let mut tokenizer = Tokenizer::new(&input);
+
+                let error = super::consume_identifier(&mut tokenizer).unwrap_err();
+
+                assert_eq!(error.error_type, TokenErrorType::EscapedReservedWord);
+                assert_eq!(error.index, $index);
+            }
+        )*
+        };
+    }
+
+    escaped_reserved_word_tests! {
+        escaped_keyword_is_an_error: "\\u0069f" => 0,
+        keyword_with_braced_escape_is_an_error: "i\\u{66}" => 1,
+        escaped_null_is_an_error: "n\\u0075ll" => 1,
+        escaped_boolean_is_an_error: "\\u0074rue" => 0,
+    }
+
+    #[test]
+    fn escaped_await_is_an_identifier() {
+        let input = String::from_str("\\u0061wait").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_identifier(&mut tokenizer).unwrap();
+
+        assert_eq!(token.token_type, TokenType::Identifier);
+        assert_eq!(token.value, "await");
+    }
+
 }
\ No newline at end of file
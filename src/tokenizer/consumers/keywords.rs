@@ -1,64 +1,67 @@
-use once_cell::sync::Lazy;
+use crate::{tokenizer::{tokenizer::Tokenizer, TokenErrorType, Token, TokenizeError, TokenType, Keyword}, tokenize_error};
+
+use super::{is_reserved_word, consume_fixed};
+
+const KEYWORDS: [(&str, Keyword); 35] = [
+    ("await", Keyword::Await), ("break", Keyword::Break), ("case", Keyword::Case),
+    ("catch", Keyword::Catch), ("class", Keyword::Class), ("const", Keyword::Const),
+    ("continue", Keyword::Continue), ("debugger", Keyword::Debugger), ("default", Keyword::Default),
+    ("delete", Keyword::Delete), ("do", Keyword::Do), ("else", Keyword::Else),
+    ("enum", Keyword::Enum), ("export", Keyword::Export), ("extends", Keyword::Extends),
+    ("finally", Keyword::Finally), ("for", Keyword::For), ("function", Keyword::Function),
+    ("if", Keyword::If), ("import", Keyword::Import), ("in", Keyword::In),
+    ("instanceof", Keyword::Instanceof), ("new", Keyword::New), ("return", Keyword::Return),
+    ("super", Keyword::Super), ("switch", Keyword::Switch), ("this", Keyword::This),
+    ("throw", Keyword::Throw), ("try", Keyword::Try), ("typeof", Keyword::Typeof),
+    ("var", Keyword::Var), ("void", Keyword::Void), ("while", Keyword::While),
+    ("with", Keyword::With), ("yield", Keyword::Yield),
+];
+
+impl Keyword {
+    pub fn as_str(&self) -> &'static str {
+        KEYWORDS.iter().find(|(_, keyword)| keyword == self).map(|(word, _)| *word).unwrap()
+    }
+}
 
-use crate::{tokenizer::{tokenizer::Tokenizer, TokenErrorType, Token, TokenizeError, TokenType, FileLocation}, tokenize_error};
+pub fn is_keyword(tokenizer: &Tokenizer, keyword: &str) -> bool {
+    is_reserved_word(tokenizer, keyword)
+}
 
-use super::is_word;
+/// The keyword spelled by `word`, e.g. an identifier's name once its escape sequences are decoded
+pub fn keyword_from_word(word: &str) -> Option<Keyword> {
+    KEYWORDS.iter().find(|(keyword, _)| *keyword == word).map(|(_, value)| *value)
+}
 
-static KEYWORDS: Lazy<Vec<&str>> = Lazy::new(|| {
-    vec![
-        "break", "do", "instanceof", "typeof",
-        "case", "else", "new", "var", "catch",
-        "finally", "return", "void", "continue",
-        "for", "switch", "while", "debugger",
-        "function", "this", "with", "default",
-        "if", "throw", "delete", "in", "try"
-    ]
-});
+pub fn find_keyword(tokenizer: &Tokenizer) -> Option<Keyword> {
+    // Read the whole word once instead of trying every keyword against the input
+    let remaining = tokenizer.remaining();
+    let length = remaining.find(|value: char| !value.is_ascii_lowercase()).unwrap_or(remaining.len());
+    let word = &remaining[..length];
 
-pub fn is_keyword(tokenizer: &Tokenizer, keyword: &str) -> bool {
-    is_word(tokenizer, keyword)
-}
+    // Most tokens don't start with a lowercase letter at all
+    if word.is_empty() { return None }
 
-pub fn find_keyword(tokenizer: &Tokenizer) -> Result<&'static str, ()> {
-    for word in KEYWORDS.iter() {
-        if is_word(tokenizer, *word) { return Ok(*word) }
+    match KEYWORDS.iter().find(|(keyword, _)| *keyword == word) {
+        Some((keyword, value)) if is_reserved_word(tokenizer, keyword) => Some(*value),
+        _ => None,
     }
-
-    return Err(())
 }
 
-pub fn consume_keyword(tokenizer: &mut Tokenizer, keyword: &str) -> Result<Token, TokenizeError> {
-    if !is_keyword(tokenizer, keyword) { return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer); }
-    let start = tokenizer.get_current_index();
-    let start_pos = tokenizer.get_current_file_loc();
-    let mut raw_value = String::new();
-
-    for _ in 0..keyword.len() {
-        raw_value.push(tokenizer
-            .consume()
-            .unwrap()
-            .clone()
-        );
+pub fn consume_keyword<'src>(tokenizer: &mut Tokenizer<'src>, keyword: Keyword) -> Result<Token<'src>, TokenizeError> {
+    let word = keyword.as_str();
+
+    if !is_keyword(tokenizer, word) {
+        return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer);
     }
 
-    let end  = tokenizer.get_current_index();
-    let end_pos = tokenizer.get_current_file_loc();
-    let value = raw_value.clone();
-
-    Ok(Token {
-        token_type: TokenType::Keyword,
-        value,
-        raw_value,
-        range: (start, end),
-        loc: FileLocation { start: start_pos, end: end_pos }
-    })
+    Ok(consume_fixed(tokenizer, word.len(), TokenType::Keyword(keyword)))
 }
 
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::tokenizer::{tokenizer::Tokenizer, TokenType};
+    use crate::tokenizer::{tokenizer::Tokenizer, TokenType, TokenErrorType, Keyword};
 
     #[test]
     fn if_is_if_is_true() {
@@ -67,7 +70,7 @@ mod tests {
 
         let result = super::is_keyword(&tokenizer, "if");
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -77,7 +80,7 @@ mod tests {
 
         let result = super::is_keyword(&tokenizer, "if");
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
@@ -87,7 +90,27 @@ mod tests {
 
         let result = super::find_keyword(&tokenizer).unwrap();
 
-        assert_eq!(result, "if");
+        assert_eq!(result, Keyword::If);
+    }
+
+    #[test]
+    fn identifier_starting_with_keyword_is_not_a_keyword() {
+        let input = String::from_str("iffy").unwrap();
+        let tokenizer = Tokenizer::new(&input);
+
+        let result = super::find_keyword(&tokenizer);
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn in_is_not_found_in_instanceof() {
+        let input = String::from_str("instanceof").unwrap();
+        let tokenizer = Tokenizer::new(&input);
+
+        let result = super::find_keyword(&tokenizer).unwrap();
+
+        assert_eq!(result, Keyword::Instanceof);
     }
 
     #[test]
@@ -95,12 +118,37 @@ mod tests {
         let input = String::from_str("if").unwrap();
         let mut tokenizer = Tokenizer::new(&input);
 
-        let result = super::consume_keyword(&mut tokenizer, "if").unwrap();
+        let result = super::consume_keyword(&mut tokenizer, Keyword::If).unwrap();
 
-        assert_eq!(result.token_type, TokenType::Keyword);
+        assert_eq!(result.token_type, TokenType::Keyword(Keyword::If));
         assert_eq!(result.value, "if");
         assert_eq!(result.raw_value, "if");
         assert_eq!(tokenizer.get_current_index(), 2);
     }
 
+    #[test]
+    fn keyword_as_str_gives_back_the_word() {
+        assert_eq!(Keyword::Function.as_str(), "function");
+        assert_eq!(Keyword::Instanceof.as_str(), "instanceof");
+    }
+
+    #[test]
+    fn find_await_and_yield_keywords() {
+        let input = String::from_str("await").unwrap();
+        let tokenizer = Tokenizer::new(&input);
+
+        assert_eq!(super::find_keyword(&tokenizer), Some(Keyword::Await));
+        assert_eq!(Keyword::Yield.as_str(), "yield");
+    }
+
+    #[test]
+    fn consume_other_keyword_gives_an_error() {
+        let input = String::from_str("let").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let result = super::consume_keyword(&mut tokenizer, Keyword::If).unwrap_err();
+
+        assert_eq!(result.error_type, TokenErrorType::UnexpectedToken);
+    }
+
 }
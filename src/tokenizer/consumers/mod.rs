@@ -1,14 +1,20 @@
+use std::borrow::Cow;
+
 use super::tokenizer::Tokenizer;
+use super::{util, Token, TokenType, FileLocation};
 
 pub mod whitespace;
 pub mod number;
+pub mod regexp;
 pub mod identifier;
 pub mod string;
+pub mod escape;
 pub mod comments;
 pub mod operator;
 pub mod terminator;
 pub mod separator;
 pub mod parenthesis;
+pub mod bracket;
 pub mod curly_brace;
 pub mod boolean;
 pub mod null;
@@ -18,22 +24,44 @@ pub mod eol;
 #[macro_export]
 macro_rules! tokenize_error {
     ($a: expr, $b: expr) => {
-        Err(TokenizeError { error_type: $a, index: $b.get_current_index() })
+        Err(TokenizeError { error_type: $a, index: $b.get_current_offset() })
     };
 }
 
 fn is_word(tokenizer: &Tokenizer, word: &str) -> bool {
-    let mut chars = word.chars();
+    tokenizer.starts_with(word)
+}
 
-    for index in 0..word.len() {
-        let token_char = tokenizer.peek_forward(index);
-        let word_char   = chars.next();
+// Reserved words only match when they aren't the start of a longer identifier, e.g. `iffy` or `null_value`
+fn is_reserved_word(tokenizer: &Tokenizer, word: &str) -> bool {
+    if !is_word(tokenizer, word) { return false }
 
-        if token_char.is_none() || word_char.is_none() { return false }
-        if token_char.unwrap().to_owned() != word_char.unwrap() { return false }
+    match tokenizer.remaining()[word.len()..].chars().next() {
+        Some(token) => !util::is_identifier_part(token) && !util::is_escape_char(token),
+        None => true,
     }
+}
+
+// Consumes a token with a fixed length in bytes, its value is the source text itself
+fn consume_fixed<'src>(tokenizer: &mut Tokenizer<'src>, length: usize, token_type: TokenType) -> Token<'src> {
+    let start = tokenizer.get_current_index();
+    let start_pos = tokenizer.get_current_file_loc();
 
-    true
+    tokenizer.advance(length);
+
+    let end = tokenizer.get_current_index();
+    let end_pos = tokenizer.get_current_file_loc();
+    let raw_value = tokenizer.slice(start, end);
+
+    Token {
+        token_type,
+        value: Cow::Borrowed(raw_value),
+        raw_value,
+        range: tokenizer.get_range(start, end),
+        loc: FileLocation { start: start_pos, end: end_pos },
+        leading_trivia: Vec::new(),
+        trailing_trivia: Vec::new(),
+    }
 }
 
 #[cfg(test)]
@@ -50,7 +78,7 @@ mod tests {
         
         let result = super::is_word(&tokenizer, word);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -61,7 +89,7 @@ mod tests {
         
         let result = super::is_word(&tokenizer, word);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
 }
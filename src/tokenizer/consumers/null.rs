@@ -1,37 +1,19 @@
-use crate::{tokenize_error, tokenizer::{Token, tokenizer::Tokenizer, TokenizeError, TokenErrorType, Literal, TokenType, FileLocation}};
+use std::borrow::Cow;
 
-use super::is_word;
+use crate::{tokenize_error, tokenizer::{Token, tokenizer::Tokenizer, TokenizeError, TokenErrorType, Literal, TokenType}};
+
+use super::{is_reserved_word, consume_fixed};
 
 pub fn is_null(tokenizer: &Tokenizer) -> bool {
-    is_word(tokenizer, "null")
+    is_reserved_word(tokenizer, "null")
 }
 
-pub fn consume_null(tokenizer: &mut Tokenizer) -> Result<Token, TokenizeError> {
+pub fn consume_null<'src>(tokenizer: &mut Tokenizer<'src>) -> Result<Token<'src>, TokenizeError> {
     if !is_null(tokenizer) { return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer); }
 
-    let start = tokenizer.get_current_index();
-    let start_pos = tokenizer.get_current_file_loc();
-
-    let mut raw_value = String::new();
-
-    for _ in 0..4 {
-        raw_value.push(tokenizer
-            .consume()
-            .unwrap()
-            .clone()
-        );
-    }
-
-    let end  = tokenizer.get_current_index();
-    let end_pos = tokenizer.get_current_file_loc();
+    let token = consume_fixed(tokenizer, 4, TokenType::Literal(Literal::Null));
 
-    Ok(Token {
-        token_type: TokenType::Literal(Literal::Null),
-        value: String::new(),
-        raw_value,
-        range: (start, end),
-        loc: FileLocation { start: start_pos, end: end_pos }
-    })
+    Ok(Token { value: Cow::Borrowed(""), ..token })
 }
 
 #[cfg(test)]
@@ -47,7 +29,7 @@ mod tests {
 
         let result = super::is_null(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -57,7 +39,7 @@ mod tests {
 
         let result = super::is_null(&tokenizer);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
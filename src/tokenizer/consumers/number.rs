@@ -1,43 +1,159 @@
+use std::borrow::Cow;
+
 use crate::tokenize_error;
 use crate::tokenizer::tokenizer::Tokenizer;
-use crate::tokenizer::{util as util, Token, TokenType, Literal, TokenizeError, FileLocation};
+use crate::tokenizer::{util as util, Token, TokenType, Literal, TokenizeError, TokenErrorType, FileLocation};
 
 pub fn is_number(tokenizer: &Tokenizer) -> bool {
     let token = tokenizer.token().unwrap();
 
-    util::is_number(token)
+    // Decimals can leave out the leading zero, e.g. `.5`
+    util::is_number(token) || (util::is_period(token) && tokenizer.peek().is_some_and(util::is_number))
+}
+
+fn is_digit(token: char, radix: u32) -> bool {
+    token.is_digit(radix)
+}
+
+// Consumes digits with numeric separators between them, e.g. `1_000`, gives back if there were any digits
+fn consume_digits(tokenizer: &mut Tokenizer, radix: u32) -> Result<bool, TokenizeError> {
+    let mut has_digits = false;
+
+    while let Some(token) = tokenizer.token() {
+        if is_digit(token, radix) {
+            has_digits = true;
+            tokenizer.next_char();
+            continue;
+        }
+
+        if token == '_' {
+            let is_between_digits = has_digits && tokenizer.peek().is_some_and(|value| is_digit(value, radix));
+            if !is_between_digits { return tokenize_error!(TokenErrorType::InvalidNumericLiteral, tokenizer) }
+
+            tokenizer.next_char();
+            continue;
+        }
+
+        break;
+    }
+
+    Ok(has_digits)
+}
+
+fn radix_prefix(tokenizer: &Tokenizer) -> Option<u32> {
+    if tokenizer.token() != Some('0') { return None }
+
+    match tokenizer.peek() {
+        Some('x' | 'X') => Some(16),
+        Some('o' | 'O') => Some(8),
+        Some('b' | 'B') => Some(2),
+        _ => None,
+    }
+}
+
+// https://262.ecma-international.org/13.0/#prod-LegacyOctalIntegerLiteral, a `0` followed by more digits
+fn is_leading_zero(tokenizer: &Tokenizer) -> bool {
+    tokenizer.token() == Some('0') && tokenizer.peek().is_some_and(|value| util::is_number(value) || value == '_')
+}
+
+// Consumes the digits of a number with a leading zero, these can't have numeric separators, e.g. `0_1`. Gives back if
+// all of them are octal digits
+fn consume_leading_zero_digits(tokenizer: &mut Tokenizer) -> Result<bool, TokenizeError> {
+    let mut is_octal = true;
+
+    while let Some(token) = tokenizer.token().filter(|value| is_digit(*value, 10)) {
+        is_octal &= is_digit(token, 8);
+        tokenizer.next_char();
+    }
+
+    if tokenizer.token() == Some('_') { return tokenize_error!(TokenErrorType::InvalidNumericLiteral, tokenizer) }
+
+    Ok(is_octal)
+}
+
+// Gives back if the number can still be a BigInt, which isn't the case for decimals and exponents
+fn consume_decimal(tokenizer: &mut Tokenizer) -> Result<bool, TokenizeError> {
+    let start = tokenizer.get_current_index();
+    let mut is_integer = true;
+
+    if is_leading_zero(tokenizer) {
+        // Only octal digits make a legacy octal literal, which ends at its last digit, `08` and `09` are decimals
+        if consume_leading_zero_digits(tokenizer)? { return Ok(false) }
+    } else {
+        consume_digits(tokenizer, 10)?;
+    }
+
+    if tokenizer.token().is_some_and(util::is_period) {
+        is_integer = false;
+        tokenizer.next_char();
+
+        if tokenizer.token() == Some('_') { return tokenize_error!(TokenErrorType::InvalidNumericLiteral, tokenizer) }
+        consume_digits(tokenizer, 10)?;
+    }
+
+    if matches!(tokenizer.token(), Some('e' | 'E')) {
+        is_integer = false;
+        tokenizer.next_char();
+
+        if matches!(tokenizer.token(), Some('+' | '-')) { tokenizer.next_char(); }
+
+        if !consume_digits(tokenizer, 10)? {
+            return tokenize_error!(TokenErrorType::InvalidNumericLiteral, tokenizer);
+        }
+    }
+
+    // BigInts can't have a leading zero, e.g. `01n`
+    let digits = tokenizer.slice(start, tokenizer.get_current_index());
+    let has_leading_zero = digits.len() > 1 && digits.starts_with('0');
+
+    Ok(is_integer && !has_leading_zero)
 }
 
-pub fn consume_number(tokenizer: &mut Tokenizer) -> Result<Token, TokenizeError> {
+pub fn consume_number<'src>(tokenizer: &mut Tokenizer<'src>) -> Result<Token<'src>, TokenizeError> {
     if !is_number(tokenizer) {
-        return tokenize_error!(crate::tokenizer::TokenErrorType::UnexpectedToken, tokenizer);
+        return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer);
     }
 
-    let mut value = String::new();
     let start = tokenizer.get_current_index();
     let start_pos = tokenizer.get_current_file_loc();
 
-    let mut token = tokenizer.consume();
+    let can_be_big_int = match radix_prefix(tokenizer) {
+        Some(radix) => {
+            tokenizer.advance(2);
+
+            if !consume_digits(tokenizer, radix)? {
+                return tokenize_error!(TokenErrorType::InvalidNumericLiteral, tokenizer);
+            }
+
+            true
+        },
+        None => consume_decimal(tokenizer)?,
+    };
 
-    while token.is_some() && util::is_number(&token.unwrap()) {
-        value.push(token.unwrap().clone());
+    let mut literal = Literal::Number;
 
+    if can_be_big_int && tokenizer.token() == Some('n') {
+        literal = Literal::BigInt;
+        tokenizer.next_char();
+    }
 
-        token = tokenizer.consume();
+    // A number can't be directly followed by an identifier or another digit, e.g. `3in` or `0b12`
+    if tokenizer.token().is_some_and(|value| util::is_identifier_start(value) || util::is_number(value) || util::is_escape_char(value)) {
+        return tokenize_error!(TokenErrorType::InvalidNumericLiteral, tokenizer);
     }
-    
-    tokenizer.walk_back();
 
     let end = tokenizer.get_current_index();
     let end_pos = tokenizer.get_current_file_loc();
-    let raw_value = value.clone();
+    let raw_value = tokenizer.slice(start, end);
 
     Ok(Token {
-        token_type: TokenType::Literal(Literal::Number),
+        token_type: TokenType::Literal(literal),
+        value: Cow::Borrowed(raw_value),
         raw_value,
-        value,
-        range: (start, end),
-        loc: FileLocation { start: start_pos, end: end_pos }
+        range: tokenizer.get_range(start, end),
+        loc: FileLocation { start: start_pos, end: end_pos },
+        leading_trivia: Vec::new(),
+        trailing_trivia: Vec::new(),
     })
 }
 
@@ -54,7 +170,7 @@ mod tests {
 
         let result = super::is_number(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -64,7 +180,7 @@ mod tests {
 
         let result = super::is_number(&tokenizer);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
@@ -101,4 +217,72 @@ mod tests {
         assert_eq!(token.error_type, TokenErrorType::UnexpectedToken);
     }
 
+    macro_rules! consume_number_tests {
+        ($($name:ident: $value:expr => $literal:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = String::from_str($value).unwrap();
+                let mut tokenizer = Tokenizer::new(&input);
+
+                let token = super::consume_number(&mut tokenizer).unwrap();
+
+                assert_eq!(token.raw_value, $value);
+                assert_eq!(token.token_type, TokenType::Literal($literal));
+                assert_eq!(tokenizer.get_current_index(), input.len());
+            }
+        )*
+        };
+    }
+
+    consume_number_tests! {
+        consume_decimal_number: "1.5" => Literal::Number,
+        consume_decimal_without_integer: ".5" => Literal::Number,
+        consume_decimal_without_fraction: "5." => Literal::Number,
+        consume_exponent: "1e10" => Literal::Number,
+        consume_signed_exponent: "1.5E-3" => Literal::Number,
+        consume_hex_number: "0xFF" => Literal::Number,
+        consume_octal_number: "0o17" => Literal::Number,
+        consume_binary_number: "0b1010" => Literal::Number,
+        consume_number_with_separators: "1_000_000" => Literal::Number,
+        consume_legacy_octal_number: "010" => Literal::Number,
+        consume_non_octal_decimal_number: "089.5" => Literal::Number,
+        consume_big_int: "123n" => Literal::BigInt,
+        consume_zero_big_int: "0n" => Literal::BigInt,
+        consume_hex_big_int: "0xFFn" => Literal::BigInt,
+    }
+
+    macro_rules! invalid_number_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = String::from_str($value).unwrap();
+                let mut tokenizer = Tokenizer::new(&input);
+
+                let error = super::consume_number(&mut tokenizer).unwrap_err();
+
+                assert_eq!(error.error_type, TokenErrorType::InvalidNumericLiteral);
+            }
+        )*
+        };
+    }
+
+    invalid_number_tests! {
+        identifier_after_number: "3in",
+        binary_with_invalid_digit: "0b12",
+        hex_without_digits: "0x",
+        exponent_without_digits: "1e",
+        trailing_separator: "1_",
+        double_separator: "1__0",
+        decimal_big_int: "1.5n",
+        exponent_big_int: "1e3n",
+        leading_zero_big_int: "01n",
+        legacy_octal_big_int: "07n",
+        separator_after_leading_zero: "0_1",
+        separator_in_legacy_octal: "01_0",
+        separator_in_non_octal_decimal: "08_1",
+        legacy_octal_with_exponent: "01e1",
+    }
+
 }
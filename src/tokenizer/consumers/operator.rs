@@ -1,80 +1,131 @@
-use once_cell::sync::Lazy;
+use std::str::FromStr;
 
 use crate::tokenize_error;
 use crate::tokenizer::tokenizer::Tokenizer;
-use crate::tokenizer::{Token, TokenizeError, TokenType, FileLocation};
+use crate::tokenizer::{Token, TokenizeError, TokenType, Operator, Separator};
 
-use super::is_word;
+use super::{is_word, consume_fixed};
 
-static OPERATORS: Lazy<Vec<&str>> = Lazy::new(|| {
-    let mut operators = vec![
-        // Assignment operators
-        "=", "+=", "-=", "*=", "/=","%=", "**=",
-        "<<=", ">>=",">>>=",
-        "&=", "^=", "|=", "&&=", "||=", "??=",
-        
-        // Comparison operators
-        "==", "!=", "===", "!==", ">", ">=", "<", "<=",
+// Separators that share their first characters with operators are included, so they take part in the longest match
+const OPERATORS: [(&str, TokenType); 46] = [
+    // Assignment operators
+    ("=", TokenType::Operator(Operator::Assign)),
+    ("+=", TokenType::Operator(Operator::AddAssign)),
+    ("-=", TokenType::Operator(Operator::SubtractAssign)),
+    ("*=", TokenType::Operator(Operator::MultiplyAssign)),
+    ("/=", TokenType::Operator(Operator::DivideAssign)),
+    ("%=", TokenType::Operator(Operator::RemainderAssign)),
+    ("**=", TokenType::Operator(Operator::ExponentAssign)),
+    ("<<=", TokenType::Operator(Operator::LeftShiftAssign)),
+    (">>=", TokenType::Operator(Operator::RightShiftAssign)),
+    (">>>=", TokenType::Operator(Operator::UnsignedRightShiftAssign)),
+    ("&=", TokenType::Operator(Operator::BitwiseAndAssign)),
+    ("^=", TokenType::Operator(Operator::BitwiseXorAssign)),
+    ("|=", TokenType::Operator(Operator::BitwiseOrAssign)),
+    ("&&=", TokenType::Operator(Operator::LogicalAndAssign)),
+    ("||=", TokenType::Operator(Operator::LogicalOrAssign)),
+    ("??=", TokenType::Operator(Operator::NullishAssign)),
 
-        // Arithmetic operators
-        "%", "++", "--", "-", "+", "**",
+    // Comparison operators
+    ("==", TokenType::Operator(Operator::Equal)),
+    ("!=", TokenType::Operator(Operator::NotEqual)),
+    ("===", TokenType::Operator(Operator::StrictEqual)),
+    ("!==", TokenType::Operator(Operator::StrictNotEqual)),
+    (">", TokenType::Operator(Operator::GreaterThan)),
+    (">=", TokenType::Operator(Operator::GreaterThanOrEqual)),
+    ("<", TokenType::Operator(Operator::LessThan)),
+    ("<=", TokenType::Operator(Operator::LessThanOrEqual)),
 
-        // Bitwise operators
-        "&", "|", "^", "~", ">>", ">>>",
+    // Arithmetic operators
+    ("%", TokenType::Operator(Operator::Remainder)),
+    ("++", TokenType::Operator(Operator::Increment)),
+    ("--", TokenType::Operator(Operator::Decrement)),
+    ("-", TokenType::Operator(Operator::Minus)),
+    ("+", TokenType::Operator(Operator::Plus)),
+    ("*", TokenType::Operator(Operator::Multiply)),
+    ("/", TokenType::Operator(Operator::Divide)),
+    ("**", TokenType::Operator(Operator::Exponent)),
 
-        // Logical operators
-        "&&", "||", "!"
-    ];
-    
-    operators.sort_by(|a, b| b.len().cmp(&a.len()));
-    operators
-});
+    // Bitwise operators
+    ("&", TokenType::Operator(Operator::BitwiseAnd)),
+    ("|", TokenType::Operator(Operator::BitwiseOr)),
+    ("^", TokenType::Operator(Operator::BitwiseXor)),
+    ("~", TokenType::Operator(Operator::BitwiseNot)),
+    ("<<", TokenType::Operator(Operator::LeftShift)),
+    (">>", TokenType::Operator(Operator::RightShift)),
+    (">>>", TokenType::Operator(Operator::UnsignedRightShift)),
+
+    // Logical operators
+    ("&&", TokenType::Operator(Operator::LogicalAnd)),
+    ("||", TokenType::Operator(Operator::LogicalOr)),
+    ("??", TokenType::Operator(Operator::NullishCoalescing)),
+    ("!", TokenType::Operator(Operator::LogicalNot)),
+
+    // Separators
+    ("?", TokenType::Separator(Separator::QuestionMark)),
+    ("?.", TokenType::Separator(Separator::OptionalChaining)),
+    ("=>", TokenType::Separator(Separator::Arrow)),
+];
+
+impl Operator {
+    pub fn as_str(&self) -> &'static str {
+        OPERATORS.iter()
+            .find(|(_, token_type)| *token_type == TokenType::Operator(*self))
+            .map(|(operator, _)| *operator)
+            .unwrap()
+    }
+}
+
+impl FromStr for Operator {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        OPERATORS.iter()
+            .find_map(|(operator, token_type)| match token_type {
+                TokenType::Operator(result) if *operator == value => Some(*result),
+                _ => None,
+            })
+            .ok_or(())
+    }
+}
 
 pub fn is_operator(tokenizer: &Tokenizer, operator: &str) -> bool {
+    // `?.` followed by a digit is a conditional with a decimal number, e.g. `a?.5:b`
+    if operator == "?." {
+        return is_word(tokenizer, operator) && !tokenizer.peek_forward(2).is_some_and(|value| value.is_ascii_digit());
+    }
+
     is_word(tokenizer, operator)
 }
 
 pub fn find_operator(tokenizer: &Tokenizer) -> Result<&'static str, ()> {
-    for operator in OPERATORS.iter() {
-        if is_word(tokenizer, *operator) { return Ok(*&operator) }
-    }
+    let first = tokenizer.remaining().bytes().next();
 
-    return Err(())
+    // Operators share prefixes, so the longest match wins e.g. `>>>=` over `>>`. Comparing the first byte before the
+    // whole operator skips most of the table
+    OPERATORS.iter()
+        .map(|(operator, _)| *operator)
+        .filter(|operator| operator.bytes().next() == first && is_operator(tokenizer, operator))
+        .max_by_key(|operator| operator.len())
+        .ok_or(())
 }
 
-pub fn consume_operator(tokenizer: &mut Tokenizer, operator: &str) -> Result<Token, TokenizeError> {
-    if !is_operator(tokenizer, operator) { return tokenize_error!(crate::tokenizer::TokenErrorType::UnexpectedToken, tokenizer); }
+pub fn consume_operator<'src>(tokenizer: &mut Tokenizer<'src>, operator: &str) -> Result<Token<'src>, TokenizeError> {
+    let found = OPERATORS.iter().find(|(value, _)| *value == operator);
 
-    let start = tokenizer.get_current_index();
-    let start_pos = tokenizer.get_current_file_loc();
-    let mut raw_value = String::new();
-
-    for _ in 0..operator.len() {
-        raw_value.push(tokenizer
-            .consume()
-            .unwrap()
-            .clone()
-        );
+    match found {
+        Some((_, token_type)) if is_operator(tokenizer, operator) => {
+            Ok(consume_fixed(tokenizer, operator.len(), *token_type))
+        },
+        _ => tokenize_error!(crate::tokenizer::TokenErrorType::UnexpectedToken, tokenizer),
     }
-
-    let end  = tokenizer.get_current_index();
-    let end_pos = tokenizer.get_current_file_loc();
-    let value = raw_value.clone();
-
-    Ok(Token {
-        token_type: TokenType::Operator,
-        raw_value: value.clone(),
-        value,
-        range: (start, end),
-        loc: FileLocation { start: start_pos, end: end_pos },
-    })
 }
 
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::tokenizer::{tokenizer::Tokenizer, TokenType, TokenErrorType};
+    use crate::tokenizer::{tokenizer::Tokenizer, TokenType, Operator, Separator, TokenErrorType};
     
     macro_rules! is_operator_tests {
         ($($name:ident: $value:expr,)*) => {
@@ -109,7 +160,7 @@ mod tests {
     }
 
     macro_rules! consume_operator_tests {
-        ($($name:ident: $value:expr,)*) => {
+        ($($name:ident: $value:expr => $operator:expr,)*) => {
         $(
             #[test]
             fn $name() {
@@ -120,26 +171,31 @@ mod tests {
         
                 assert_eq!(operator.value, $value);
                 assert_eq!(operator.raw_value, $value);
-                assert_eq!(operator.token_type, TokenType::Operator);
+                assert_eq!(operator.token_type, TokenType::Operator($operator));
+                assert_eq!($operator.as_str(), $value);
+                assert_eq!(Operator::from_str($value), Ok($operator));
             }
         )*
         };
     }
 
     consume_operator_tests! {
-        consume_plus_an_operator: "+",
-        consume_minus_an_operator: "-",
-        consume_is_an_operator: "=",
-        consume_gt_an_operator: ">",
-        consume_lt_an_operator: "<",
-        consume_exclamation_mark_an_operator: "!",
-        consume_divide_an_operator: "/",
-        consume_times_an_operator: "*",
-        consume_modulo_an_operator: "%",
-        consume_and_an_operator: "&",
-        consume_or_an_operator: "|",
-        consume_xor_an_operator: "^",
-        consume_not_an_operator: "~",
+        consume_plus_an_operator: "+" => Operator::Plus,
+        consume_minus_an_operator: "-" => Operator::Minus,
+        consume_is_an_operator: "=" => Operator::Assign,
+        consume_gt_an_operator: ">" => Operator::GreaterThan,
+        consume_lt_an_operator: "<" => Operator::LessThan,
+        consume_exclamation_mark_an_operator: "!" => Operator::LogicalNot,
+        consume_divide_an_operator: "/" => Operator::Divide,
+        consume_times_an_operator: "*" => Operator::Multiply,
+        consume_modulo_an_operator: "%" => Operator::Remainder,
+        consume_and_an_operator: "&" => Operator::BitwiseAnd,
+        consume_or_an_operator: "|" => Operator::BitwiseOr,
+        consume_xor_an_operator: "^" => Operator::BitwiseXor,
+        consume_not_an_operator: "~" => Operator::BitwiseNot,
+        consume_unsigned_right_shift_assign: ">>>=" => Operator::UnsignedRightShiftAssign,
+        consume_nullish_assign: "??=" => Operator::NullishAssign,
+        consume_strict_not_equal: "!==" => Operator::StrictNotEqual,
     }
 
     #[test]
@@ -151,10 +207,60 @@ mod tests {
 
         assert_eq!(operator.value, "-=");
         assert_eq!(operator.raw_value, "-=");
-        assert_eq!(operator.token_type, TokenType::Operator);
+        assert_eq!(operator.token_type, TokenType::Operator(Operator::SubtractAssign));
         assert_eq!(tokenizer.get_current_index(), 2);
     }
 
+    macro_rules! find_operator_tests {
+        ($($name:ident: $input:expr => $expected:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = String::from_str($input).unwrap();
+                let tokenizer = Tokenizer::new(&input);
+
+                let operator = super::find_operator(&tokenizer).unwrap();
+
+                assert_eq!(operator, $expected);
+            }
+        )*
+        };
+    }
+
+    find_operator_tests! {
+        find_longest_shift_assignment: ">>>=1" => ">>>=",
+        find_left_shift: "<<1" => "<<",
+        find_exponent_over_times: "**2" => "**",
+        find_nullish_coalescing: "??b" => "??",
+        find_nullish_assignment: "??=b" => "??=",
+        find_arrow_over_assignment: "=>{" => "=>",
+        find_optional_chaining: "?.b" => "?.",
+        find_question_mark_before_decimal: "?.5:b" => "?",
+    }
+
+    macro_rules! consume_separator_tests {
+        ($($name:ident: $value:expr => $separator:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = String::from_str($value).unwrap();
+                let mut tokenizer = Tokenizer::new(&input);
+
+                let token = super::consume_operator(&mut tokenizer, $value).unwrap();
+
+                assert_eq!(token.raw_value, $value);
+                assert_eq!(token.token_type, TokenType::Separator($separator));
+            }
+        )*
+        };
+    }
+
+    consume_separator_tests! {
+        consume_question_mark: "?" => Separator::QuestionMark,
+        consume_optional_chaining: "?." => Separator::OptionalChaining,
+        consume_arrow: "=>" => Separator::Arrow,
+    }
+
     #[test]
     fn consume_invalid_input() {
         let input = String::from_str("🦀").unwrap();
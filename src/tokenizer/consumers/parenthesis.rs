@@ -1,6 +1,8 @@
 use crate::tokenize_error;
 use crate::tokenizer::tokenizer::Tokenizer;
-use crate::tokenizer::{util as util, Token, TokenizeError, TokenType, Separator, TokenErrorType, FileLocation};
+use crate::tokenizer::{util as util, Token, TokenizeError, TokenType, Separator, TokenErrorType};
+
+use super::consume_fixed;
 
 pub fn is_parenthesis(tokenizer: &Tokenizer) -> bool {
     let token = tokenizer.token().unwrap();
@@ -8,26 +10,17 @@ pub fn is_parenthesis(tokenizer: &Tokenizer) -> bool {
     util::is_parenthesis(token)
 }
 
-pub fn consume_parenthesis(tokenizer: &mut Tokenizer) -> Result<Token, TokenizeError>  {
+pub fn consume_parenthesis<'src>(tokenizer: &mut Tokenizer<'src>) -> Result<Token<'src>, TokenizeError>  {
     if !is_parenthesis(tokenizer) {
         return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer);
     }
 
-    let start = tokenizer.get_current_index();
-    let start_pos = tokenizer.get_current_file_loc();
-
-    let token = tokenizer.consume().unwrap().to_string();
-
-    let end = tokenizer.get_current_index();
-    let end_pos = tokenizer.get_current_file_loc();
+    let separator = match tokenizer.token() {
+        Some('(') => Separator::OpenParenthesis,
+        _ => Separator::CloseParenthesis,
+    };
 
-    Ok(Token {
-        token_type: TokenType::Separator(Separator::Parenthesis),
-        value: token.clone(),
-        raw_value: token,
-        range: (start, end),
-        loc: FileLocation { start: start_pos, end: end_pos }
-    })
+    Ok(consume_fixed(tokenizer, 1, TokenType::Separator(separator)))
 }
 
 #[cfg(test)]
@@ -43,7 +36,7 @@ mod tests {
 
         let result = super::is_parenthesis(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -53,7 +46,7 @@ mod tests {
 
         let result = super::is_parenthesis(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -63,7 +56,7 @@ mod tests {
 
         let result = super::is_parenthesis(&tokenizer);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
@@ -74,7 +67,7 @@ mod tests {
         let token = super::consume_parenthesis(&mut tokenizer).unwrap();
 
         assert_eq!(tokenizer.get_current_index(), input.len());
-        assert_eq!(token.token_type, TokenType::Separator(Separator::Parenthesis));
+        assert_eq!(token.token_type, TokenType::Separator(Separator::OpenParenthesis));
         assert_eq!(token.value, "(");
         assert_eq!(token.raw_value, "(");
     }
@@ -87,7 +80,7 @@ mod tests {
         let token = super::consume_parenthesis(&mut tokenizer).unwrap();
 
         assert_eq!(tokenizer.get_current_index(), input.len());
-        assert_eq!(token.token_type, TokenType::Separator(Separator::Parenthesis));
+        assert_eq!(token.token_type, TokenType::Separator(Separator::CloseParenthesis));
         assert_eq!(token.value, ")");
         assert_eq!(token.raw_value, ")");
     }
@@ -0,0 +1,141 @@
+use std::borrow::Cow;
+
+use crate::tokenize_error;
+use crate::tokenizer::tokenizer::Tokenizer;
+use crate::tokenizer::{util as util, Token, TokenType, Literal, TokenizeError, TokenErrorType, FileLocation};
+
+// Whether a `/` starts a regular expression depends on the previous token, so the tokenizer decides when to check this
+pub fn is_regexp(tokenizer: &Tokenizer) -> bool {
+    tokenizer.starts_with("/") && !tokenizer.starts_with("//") && !tokenizer.starts_with("/*")
+}
+
+fn is_end_of_line(tokenizer: &Tokenizer) -> bool {
+    tokenizer.token().is_none_or(util::is_eol)
+}
+
+// https://262.ecma-international.org/13.0/#sec-literals-regular-expression-literals
+pub fn consume_regexp<'src>(tokenizer: &mut Tokenizer<'src>) -> Result<Token<'src>, TokenizeError> {
+    if !is_regexp(tokenizer) {
+        return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer);
+    }
+
+    let start = tokenizer.get_current_index();
+    let start_pos = tokenizer.get_current_file_loc();
+
+    tokenizer.next_char();
+
+    // A `/` within a class like `[/]` doesn't end the pattern
+    let mut is_in_class = false;
+
+    loop {
+        if is_end_of_line(tokenizer) {
+            return tokenize_error!(TokenErrorType::UnterminatedRegExpLiteral, tokenizer);
+        }
+
+        match tokenizer.consume() {
+            Some('\\') => {
+                if is_end_of_line(tokenizer) {
+                    return tokenize_error!(TokenErrorType::UnterminatedRegExpLiteral, tokenizer);
+                }
+
+                tokenizer.next_char();
+            },
+            Some('[') => is_in_class = true,
+            Some(']') => is_in_class = false,
+            Some('/') if !is_in_class => break,
+            _ => {},
+        }
+    }
+
+    while tokenizer.token().is_some_and(util::is_identifier_part) {
+        tokenizer.next_char();
+    }
+
+    let end = tokenizer.get_current_index();
+    let end_pos = tokenizer.get_current_file_loc();
+    let raw_value = tokenizer.slice(start, end);
+
+    Ok(Token {
+        token_type: TokenType::Literal(Literal::RegExp),
+        value: Cow::Borrowed(raw_value),
+        raw_value,
+        range: tokenizer.get_range(start, end),
+        loc: FileLocation { start: start_pos, end: end_pos },
+        leading_trivia: Vec::new(),
+        trailing_trivia: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::tokenizer::{tokenizer::Tokenizer, TokenType, Literal, TokenErrorType};
+
+    #[test]
+    fn is_slash_a_regexp() {
+        let input = String::from_str("/a/").unwrap();
+        let tokenizer = Tokenizer::new(&input);
+
+        let result = super::is_regexp(&tokenizer);
+
+        assert!(result);
+    }
+
+    #[test]
+    fn is_comment_not_a_regexp() {
+        let input = String::from_str("// a").unwrap();
+        let tokenizer = Tokenizer::new(&input);
+
+        let result = super::is_regexp(&tokenizer);
+
+        assert!(!result);
+    }
+
+    macro_rules! consume_regexp_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = String::from_str($value).unwrap();
+                let mut tokenizer = Tokenizer::new(&input);
+
+                let token = super::consume_regexp(&mut tokenizer).unwrap();
+
+                assert_eq!(token.raw_value, $value);
+                assert_eq!(token.token_type, TokenType::Literal(Literal::RegExp));
+                assert_eq!(tokenizer.get_current_index(), input.len());
+            }
+        )*
+        };
+    }
+
+    consume_regexp_tests! {
+        consume_simple_regexp: "/abc/",
+        consume_regexp_with_flags: "/abc/gimsuy",
+        consume_regexp_with_escaped_slash: "/a\\/b/",
+        consume_regexp_with_slash_in_class: "/[/]+/",
+    }
+
+    #[test]
+    fn regexp_stops_after_flags() {
+        let input = String::from_str("/a/g.test").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_regexp(&mut tokenizer).unwrap();
+
+        assert_eq!(token.raw_value, "/a/g");
+    }
+
+    #[test]
+    fn unterminated_regexp_gives_an_error() {
+        let input = String::from_str("/abc\n/").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let error = super::consume_regexp(&mut tokenizer).unwrap_err();
+
+        assert_eq!(error.error_type, TokenErrorType::UnterminatedRegExpLiteral);
+        assert_eq!(error.index, 4);
+    }
+
+}
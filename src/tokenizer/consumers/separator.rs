@@ -1,6 +1,8 @@
 use crate::tokenize_error;
 use crate::tokenizer::tokenizer::Tokenizer;
-use crate::tokenizer::{util as util, Token, TokenizeError, TokenType, Separator, FileLocation};
+use crate::tokenizer::{util as util, Token, TokenizeError, TokenType, Separator, TokenErrorType};
+
+use super::consume_fixed;
 
 pub fn is_period(tokenizer: &Tokenizer) -> bool {
     let token = tokenizer.token().unwrap();
@@ -14,48 +16,38 @@ pub fn is_comma(tokenizer: &Tokenizer) -> bool {
     util::is_comma(token)
 }
 
-pub fn consume_period(tokenizer: &mut Tokenizer) -> Result<Token, TokenizeError>  {
+pub fn is_colon(tokenizer: &Tokenizer) -> bool {
+    let token = tokenizer.token().unwrap();
+
+    util::is_colon(token)
+}
+
+pub fn consume_period<'src>(tokenizer: &mut Tokenizer<'src>) -> Result<Token<'src>, TokenizeError>  {
     if !is_period(tokenizer) {
-        return tokenize_error!(crate::tokenizer::TokenErrorType::UnexpectedToken, tokenizer);
+        return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer);
     }
 
-    let start = tokenizer.get_current_index();
-    let start_pos = tokenizer.get_current_file_loc();
-
-    let token = tokenizer.consume().unwrap().to_string();
-    
-    let end = tokenizer.get_current_index();
-    let end_pos = tokenizer.get_current_file_loc();
-
-    Ok(Token {
-        token_type: TokenType::Separator(Separator::Period),
-        value: token.clone(),
-        raw_value: token,
-        range: (start, end),
-        loc: FileLocation { start: start_pos, end: end_pos },
-    })
+    if tokenizer.starts_with("...") {
+        return Ok(consume_fixed(tokenizer, 3, TokenType::Separator(Separator::Ellipsis)));
+    }
+
+    Ok(consume_fixed(tokenizer, 1, TokenType::Separator(Separator::Period)))
 }
 
-pub fn consume_comma(tokenizer: &mut Tokenizer) -> Result<Token, TokenizeError>  {
+pub fn consume_comma<'src>(tokenizer: &mut Tokenizer<'src>) -> Result<Token<'src>, TokenizeError>  {
     if !is_comma(tokenizer) {
-        return tokenize_error!(crate::tokenizer::TokenErrorType::UnexpectedToken, tokenizer);
+        return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer);
     }
 
-    let start = tokenizer.get_current_index();
-    let start_pos = tokenizer.get_current_file_loc();
-    
-    let token = tokenizer.consume().unwrap().to_string();
-
-    let end = tokenizer.get_current_index();
-    let end_pos = tokenizer.get_current_file_loc();
-
-    Ok(Token {
-        token_type: TokenType::Separator(Separator::Comma),
-        value: token.clone(),
-        raw_value: token,
-        range: (start, end),
-        loc: FileLocation { start: start_pos, end: end_pos },
-    })
+    Ok(consume_fixed(tokenizer, 1, TokenType::Separator(Separator::Comma)))
+}
+
+pub fn consume_colon<'src>(tokenizer: &mut Tokenizer<'src>) -> Result<Token<'src>, TokenizeError>  {
+    if !is_colon(tokenizer) {
+        return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer);
+    }
+
+    Ok(consume_fixed(tokenizer, 1, TokenType::Separator(Separator::Colon)))
 }
 
 #[cfg(test)]
@@ -71,7 +63,7 @@ mod tests {
 
         let result = super::is_comma(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -81,7 +73,7 @@ mod tests {
 
         let result = super::is_period(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -91,7 +83,7 @@ mod tests {
 
         let result = super::is_comma(&tokenizer);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
@@ -120,6 +112,41 @@ mod tests {
         assert_eq!(token.raw_value, ".");
     }
 
+    #[test]
+    fn consume_ellipsis_input() {
+        let input = String::from_str("...").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_period(&mut tokenizer).unwrap();
+
+        assert_eq!(tokenizer.get_current_index(), input.len());
+        assert_eq!(token.token_type, TokenType::Separator(Separator::Ellipsis));
+        assert_eq!(token.raw_value, "...");
+    }
+
+    #[test]
+    fn consume_two_periods_input() {
+        let input = String::from_str("..").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_period(&mut tokenizer).unwrap();
+
+        assert_eq!(tokenizer.get_current_index(), 1);
+        assert_eq!(token.token_type, TokenType::Separator(Separator::Period));
+    }
+
+    #[test]
+    fn consume_colon_input() {
+        let input = String::from_str(":").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_colon(&mut tokenizer).unwrap();
+
+        assert_eq!(tokenizer.get_current_index(), input.len());
+        assert_eq!(token.token_type, TokenType::Separator(Separator::Colon));
+        assert_eq!(token.raw_value, ":");
+    }
+
     #[test]
     fn consume_invalid_input_period() {
         let input = String::from_str("🦀").unwrap();
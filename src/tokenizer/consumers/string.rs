@@ -1,99 +1,93 @@
-use crate::tokenizer::util::{is_eol, is_escape_char};
-use crate::{tokenize_error};
+use std::borrow::Cow;
+
+use crate::tokenize_error;
 use crate::tokenizer::tokenizer::Tokenizer;
 use crate::tokenizer::{util as util, Token, TokenizeError, TokenErrorType, TokenType, Literal, FileLocation};
 
+use super::escape::consume_escape_sequence;
+
 pub fn is_string(tokenizer: &Tokenizer) -> bool {
     let token = tokenizer.token().unwrap();
 
     util::is_string_delimiter(token)
 }
 
-fn is_end_string(tokenizer: &Tokenizer, delimiter: char) -> bool {
-    if is_escaped(tokenizer) { return false; }    
-
-    let token = tokenizer.token().unwrap();
-
-    token.clone() == delimiter
-}
-
-fn is_escaped(tokenizer: &Tokenizer) -> bool {
-    tokenizer.peek_back()
-        .unwrap_or(&' ')
-        .clone() == '\\'
-}
-
-macro_rules! unwrap_token {
-    ($a:expr) => {
-        match $a.token() {
-            Some(val) => Some(val.to_owned()),
-            None => None,
-        }
-    };
+// <LS> and <PS> are allowed within string literals, other line terminators are not
+fn is_invalid_string_char(token: char) -> bool {
+    token == '\n' || token == '\r'
 }
 
-pub fn consume_string(tokenizer: &mut Tokenizer) -> Result<Token, TokenizeError> {
-    // We clone the delimiter here, otherwise the tokenizer will have a mutable reference
-    let delimiter = tokenizer.token().unwrap().clone();
+pub fn consume_string<'src>(tokenizer: &mut Tokenizer<'src>) -> Result<Token<'src>, TokenizeError> {
+    let delimiter = tokenizer.token().unwrap();
 
-    if !util::is_string_delimiter(&delimiter) {
+    if !util::is_string_delimiter(delimiter) {
         return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer);
     }
-    
-    let mut value = String::new();
-    let mut raw_value = String::new();
-    raw_value.push(delimiter);
 
     let start = tokenizer.get_current_index();
     let start_pos = tokenizer.get_current_file_loc();
 
-    tokenizer.next();
+    tokenizer.next_char();
 
-    let mut token = unwrap_token!(tokenizer);
-    
-    while token.is_some() && !is_end_string(tokenizer, delimiter) {
-        let val = token.unwrap().clone();
+    let content_start = tokenizer.get_current_index();
 
-        if is_eol(&val) {
-            return tokenize_error!(TokenErrorType::UnterminatedStringLiteral, tokenizer);
-        }
+    // Only strings with escape sequences need their own copy of the value
+    let mut cooked: Option<String> = None;
+
+    loop {
+        let token = match tokenizer.token() {
+            Some(token) if !is_invalid_string_char(token) => token,
+            _ => return tokenize_error!(TokenErrorType::UnterminatedStringLiteral, tokenizer),
+        };
+
+        if token == delimiter { break; }
 
-        raw_value.push(val);
+        if util::is_escape_char(token) {
+            let value = cooked.get_or_insert_with(|| {
+                tokenizer.slice(content_start, tokenizer.get_current_index()).to_string()
+            });
 
-        if !is_escape_char(&val) || is_escaped(tokenizer) {
-            value.push(val);
+            if let Some(escaped) = consume_escape_sequence(tokenizer)? {
+                value.push(escaped);
+            }
+
+            continue;
+        }
+
+        if let Some(value) = cooked.as_mut() {
+            value.push(token);
         }
 
-        tokenizer.next();
-        token = unwrap_token!(tokenizer);
+        tokenizer.next_char();
     }
 
-    // the last token should be the same asgit  the first delimiter
-    if token.is_some() && (token.unwrap() == delimiter) {
-        let val = token.unwrap().clone();
-        raw_value.push(val);
+    let content_end = tokenizer.get_current_index();
 
-        // Since we got a fine delimiter, we need to go to the next character in the input 
-        tokenizer.next();        
-    } else {
-        return tokenize_error!(TokenErrorType::UnterminatedStringLiteral, tokenizer);
-    }
+    // Consume the closing delimiter
+    tokenizer.next_char();
 
     let end = tokenizer.get_current_index();
     let end_pos = tokenizer.get_current_file_loc();
 
+    let value = match cooked {
+        Some(value) => Cow::Owned(value),
+        None => Cow::Borrowed(tokenizer.slice(content_start, content_end)),
+    };
+
     Ok(Token {
         token_type: TokenType::Literal(Literal::String),
         value,
-        raw_value,
-        range: (start, end),
+        raw_value: tokenizer.slice(start, end),
+        range: tokenizer.get_range(start, end),
         loc: FileLocation { start: start_pos, end: end_pos },
+        leading_trivia: Vec::new(),
+        trailing_trivia: Vec::new(),
     })
 }
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use std::{borrow::Cow, str::FromStr};
 
     use crate::tokenizer::{tokenizer::Tokenizer, TokenType, Literal, TokenErrorType};
 
@@ -104,7 +98,7 @@ mod tests {
 
         let result = super::is_string(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -114,7 +108,7 @@ mod tests {
 
         let result = super::is_string(&tokenizer);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
@@ -170,6 +164,38 @@ mod tests {
     }
 
 
+    #[test]
+    fn string_without_escapes_borrows_the_input() {
+        let input = String::from_str("'Foobar'").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_string(&mut tokenizer).unwrap();
+
+        assert!(matches!(token.value, Cow::Borrowed("Foobar")));
+    }
+
+    #[test]
+    fn string_with_escapes_is_cooked() {
+        let input = String::from_str("'Foo\\n\\tbar\\u{1F980}'").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_string(&mut tokenizer).unwrap();
+
+        assert!(matches!(token.value, Cow::Owned(_)));
+        assert_eq!(token.value, "Foo\n\tbar🦀");
+        assert_eq!(token.raw_value, input);
+    }
+
+    #[test]
+    fn string_with_line_continuation_is_cooked() {
+        let input = String::from_str("'Foo\\\nbar'").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        let token = super::consume_string(&mut tokenizer).unwrap();
+
+        assert_eq!(token.value, "Foobar");
+    }
+
     #[test]
     fn give_error_on_line_separated_string() {
         let input = String::from_str("\"Foo\nbar\"").unwrap();
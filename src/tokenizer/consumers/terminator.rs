@@ -1,6 +1,8 @@
 use crate::tokenize_error;
 use crate::tokenizer::tokenizer::Tokenizer;
-use crate::tokenizer::{util as util, Token, TokenizeError, TokenType, Separator, TokenErrorType, FileLocation};
+use crate::tokenizer::{util as util, Token, TokenizeError, TokenType, Separator, TokenErrorType};
+
+use super::consume_fixed;
 
 pub fn is_terminator(tokenizer: &Tokenizer) -> bool {
     let token = tokenizer.token().unwrap();
@@ -8,25 +10,12 @@ pub fn is_terminator(tokenizer: &Tokenizer) -> bool {
     util::is_terminator(token)
 }
 
-pub fn consume_terminator(tokenizer: &mut Tokenizer) -> Result<Token, TokenizeError>  {
+pub fn consume_terminator<'src>(tokenizer: &mut Tokenizer<'src>) -> Result<Token<'src>, TokenizeError>  {
     if !is_terminator(tokenizer) {
         return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer);
     }
 
-    let start = tokenizer.get_current_index();
-    let start_pos = tokenizer.get_current_file_loc();
-
-    let token = tokenizer.consume().unwrap().to_string();
-    let end = tokenizer.get_current_index();
-    let end_pos = tokenizer.get_current_file_loc();
-
-    Ok(Token {
-        token_type: TokenType::Separator(Separator::Terminator),
-        value: token.clone(),
-        raw_value: token,
-        range: (start, end),
-        loc: FileLocation { start: start_pos, end: end_pos },
-    })
+    Ok(consume_fixed(tokenizer, 1, TokenType::Separator(Separator::Terminator)))
 }
 
 #[cfg(test)]
@@ -42,7 +31,7 @@ mod tests {
 
         let result = super::is_terminator(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -52,7 +41,7 @@ mod tests {
 
         let result = super::is_terminator(&tokenizer);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
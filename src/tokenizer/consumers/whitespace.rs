@@ -13,9 +13,11 @@ pub fn consume_whitespace(tokenizer: &mut Tokenizer) -> Result<(), TokenizeError
         return tokenize_error!(TokenErrorType::UnexpectedToken, tokenizer);
     }
 
-    tokenizer.consume().unwrap();
+    while tokenizer.token().is_some_and(util::is_whitespace) {
+        tokenizer.next_char();
+    }
 
-    return Ok(())
+    Ok(())
 }
 
 #[cfg(test)]
@@ -31,7 +33,7 @@ mod tests {
 
         let result = super::is_whitespace(&tokenizer);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -41,7 +43,7 @@ mod tests {
 
         let result = super::is_whitespace(&tokenizer);
 
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
@@ -49,11 +51,21 @@ mod tests {
         let input = String::from_str(" ").unwrap();
         let mut tokenizer = Tokenizer::new(&input);
 
-        _ = super::consume_whitespace(&mut tokenizer).unwrap();
+        super::consume_whitespace(&mut tokenizer).unwrap();
 
         assert_eq!(tokenizer.get_current_index(), input.len());
     }
 
+    #[test]
+    fn consume_whitespace_run() {
+        let input = String::from_str(" \t \u{00A0}x").unwrap();
+        let mut tokenizer = Tokenizer::new(&input);
+
+        super::consume_whitespace(&mut tokenizer).unwrap();
+
+        assert_eq!(tokenizer.token(), Some('x'));
+    }
+
     #[test]
     fn consume_invalid_input() {
         let input = String::from_str("🦀").unwrap();
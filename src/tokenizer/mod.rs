@@ -1,68 +1,329 @@
+#[allow(clippy::module_inception)]
 mod tokenizer;
 mod util;
 mod consumers;
 #[cfg(test)]
 mod tests;
 
-#[derive(Debug, PartialEq)]
+use std::{borrow::Cow, fmt};
+
+use crate::source::OffsetEncoding;
+
+pub use self::tokenizer::Tokenizer;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TokenType {
-    Keyword,
+    Keyword(Keyword),
     Identifier,
     Literal(Literal),
     Separator(Separator),
-    Operator,
+    Operator(Operator),
+    /// Source text that couldn't be tokenized, only given back when recovering from errors
+    Invalid,
+    /// An empty token at the end of the file, it holds trivia no other token can, e.g. a file with only a comment.
+    /// `parse` only keeps it when it holds trivia
+    EndOfInput,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Literal {
     Number,
+    BigInt,
     String,
     Boolean,
-    Null
+    Null,
+    RegExp,
 }
 
-#[derive(Debug, PartialEq)]
+// https://262.ecma-international.org/13.0/#sec-keywords-and-reserved-words
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Keyword {
+    /// Only reserved within async functions and modules, the parser reads it as an identifier
+    Await,
+    Break,
+    Case,
+    Catch,
+    Class,
+    Const,
+    Continue,
+    Debugger,
+    Default,
+    Delete,
+    Do,
+    Else,
+    Enum,
+    Export,
+    Extends,
+    Finally,
+    For,
+    Function,
+    If,
+    Import,
+    In,
+    Instanceof,
+    New,
+    Return,
+    Super,
+    Switch,
+    This,
+    Throw,
+    Try,
+    Typeof,
+    Var,
+    Void,
+    While,
+    With,
+    /// Only reserved within generators and strict mode code, the parser reads it as an identifier
+    Yield,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+    // Assignment operators
+    Assign,
+    AddAssign,
+    SubtractAssign,
+    MultiplyAssign,
+    DivideAssign,
+    RemainderAssign,
+    ExponentAssign,
+    LeftShiftAssign,
+    RightShiftAssign,
+    UnsignedRightShiftAssign,
+    BitwiseAndAssign,
+    BitwiseXorAssign,
+    BitwiseOrAssign,
+    LogicalAndAssign,
+    LogicalOrAssign,
+    NullishAssign,
+
+    // Comparison operators
+    Equal,
+    NotEqual,
+    StrictEqual,
+    StrictNotEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+
+    // Arithmetic operators
+    Remainder,
+    Increment,
+    Decrement,
+    Minus,
+    Plus,
+    Multiply,
+    Divide,
+    Exponent,
+
+    // Bitwise operators
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    BitwiseNot,
+    LeftShift,
+    RightShift,
+    UnsignedRightShift,
+
+    // Logical operators
+    LogicalAnd,
+    LogicalOr,
+    NullishCoalescing,
+    LogicalNot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Separator {
     Period,
+    /// `...`
+    Ellipsis,
     Comma,
-    Parenthesis,
-    CurlyBrace,
+    Colon,
+    OpenParenthesis,
+    CloseParenthesis,
+    OpenCurlyBrace,
+    CloseCurlyBrace,
+    OpenBracket,
+    CloseBracket,
     Terminator,
+    QuestionMark,
+    /// `?.`
+    OptionalChaining,
+    /// `=>`
+    Arrow,
 }
 
 #[derive(Debug)]
-pub struct Token {
+pub struct Token<'src> {
     pub token_type: TokenType,
-    pub value: String,
-    pub raw_value: String,
+    /// The cooked value, this only owns its content when it differs from the source text
+    pub value: Cow<'src, str>,
+    pub raw_value: &'src str,
     pub range: (usize, usize),
     pub loc: FileLocation,
+    /// Trivia between the previous line and this token, empty unless `TokenizerOptions::preserve_trivia` is set
+    pub leading_trivia: Vec<Trivia<'src>>,
+    /// Trivia after this token up to and including the end of its line, or the end of the file
+    pub trailing_trivia: Vec<Trivia<'src>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TriviaKind {
+    Whitespace,
+    LineTerminator,
+    LineComment,
+    BlockComment,
+    /// `#!` at the very start of the file
+    HashbangComment,
+    /// Annex B `<!--` comments, only in scripts
+    HtmlOpenComment,
+    /// Annex B `-->` comments at the start of a line, only in scripts
+    HtmlCloseComment,
 }
 
 #[derive(Debug, Clone)]
+pub struct Trivia<'src> {
+    pub kind: TriviaKind,
+    pub raw_value: &'src str,
+    pub range: (usize, usize),
+    pub loc: FileLocation,
+}
+
+impl<'src> Trivia<'src> {
+    pub fn is_comment(&self) -> bool {
+        !matches!(self.kind, TriviaKind::Whitespace | TriviaKind::LineTerminator)
+    }
+
+    /// The text of a comment without its delimiters, for other trivia this is the source text
+    pub fn value(&self) -> &'src str {
+        match self.kind {
+            TriviaKind::LineComment | TriviaKind::HashbangComment => &self.raw_value[2..],
+            TriviaKind::HtmlOpenComment => &self.raw_value[4..],
+            TriviaKind::HtmlCloseComment => &self.raw_value[3..],
+            TriviaKind::BlockComment => {
+                let value = &self.raw_value[2..];
+                value.strip_suffix("*/").unwrap_or(value)
+            },
+            _ => self.raw_value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FileLocationPos {
     pub line: usize,
     pub column: usize,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FileLocation {
     pub start: FileLocationPos, 
     pub end: FileLocationPos
 }
 
-#[derive(Debug, PartialEq)]
+/// The goal symbol the source is parsed with
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum SourceType {
+    /// Classic scripts allow the Annex B HTML-like comments
+    Script,
+    #[default]
+    Module,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokenizerOptions {
+    /// The unit used by `Token::range`, `FileLocation` columns and error indexes
+    pub offset_encoding: OffsetEncoding,
+    /// Keep whitespace, line terminators and comments as trivia on the surrounding tokens
+    pub preserve_trivia: bool,
+    /// Go on after an error with an `Invalid` token that covers it instead of stopping, see `parse_recovering`
+    pub recover_errors: bool,
+    pub source_type: SourceType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TokenErrorType {
     UnexpectedToken,
-    UnterminatedStringLiteral
+    UnterminatedStringLiteral,
+    InvalidUnicodeEscapeSequence,
+    InvalidHexEscapeSequence,
+    InvalidNumericLiteral,
+    UnterminatedRegExpLiteral,
+    UnterminatedComment,
+    /// A reserved word spelled with escape sequences, e.g. `\u0069f`
+    EscapedReservedWord,
 }
 
-#[derive(Debug)]
+impl TokenErrorType {
+    /// A stable code to look up or filter the error by, it never changes once released
+    pub fn code(&self) -> &'static str {
+        match self {
+            TokenErrorType::UnexpectedToken => "E0001",
+            TokenErrorType::UnterminatedStringLiteral => "E0002",
+            TokenErrorType::InvalidUnicodeEscapeSequence => "E0003",
+            TokenErrorType::InvalidHexEscapeSequence => "E0004",
+            TokenErrorType::InvalidNumericLiteral => "E0005",
+            TokenErrorType::UnterminatedRegExpLiteral => "E0006",
+            TokenErrorType::UnterminatedComment => "E0007",
+            TokenErrorType::EscapedReservedWord => "E0008",
+        }
+    }
+}
+
+impl fmt::Display for TokenErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            TokenErrorType::UnexpectedToken => "Unexpected character",
+            TokenErrorType::UnterminatedStringLiteral => "Unterminated string literal",
+            TokenErrorType::InvalidUnicodeEscapeSequence => "Invalid Unicode escape sequence",
+            TokenErrorType::InvalidHexEscapeSequence => "Invalid hexadecimal escape sequence",
+            TokenErrorType::InvalidNumericLiteral => "Invalid numeric literal",
+            TokenErrorType::UnterminatedRegExpLiteral => "Unterminated regular expression literal",
+            TokenErrorType::UnterminatedComment => "Unterminated comment",
+            TokenErrorType::EscapedReservedWord => "Reserved words can't contain escape sequences",
+        };
+
+        f.write_str(message)
+    }
+}
+
+#[derive(Debug, PartialEq)]
 pub struct TokenizeError {
     pub error_type: TokenErrorType,
     pub index: usize
 }
 
-pub fn parse(file_content: &String) -> Result<Vec<Token>, TokenizeError> {
-    tokenizer::parse(file_content)
+/// A lexical error found while recovering, it covers the text of the `Invalid` token it produced
+#[derive(Debug, Clone)]
+pub struct TokenDiagnostic<'src> {
+    pub error_type: TokenErrorType,
+    pub message: String,
+    /// The offending source text
+    pub text: &'src str,
+    /// Where the error was found, this can be within the offending text
+    pub index: usize,
+    pub range: (usize, usize),
+    pub loc: FileLocation,
+}
+
+#[derive(Debug)]
+pub struct RecoveredTokens<'src> {
+    pub tokens: Vec<Token<'src>>,
+    pub diagnostics: Vec<TokenDiagnostic<'src>>,
+}
+
+pub fn parse(file_content: &str) -> Result<Vec<Token<'_>>, TokenizeError> {
+    tokenizer::parse(file_content, TokenizerOptions::default())
+}
+
+pub fn parse_with_options(file_content: &str, options: TokenizerOptions) -> Result<Vec<Token<'_>>, TokenizeError> {
+    tokenizer::parse(file_content, options)
+}
+
+/// Tokenizes the whole file, every lexical error becomes an `Invalid` token and a diagnostic
+pub fn parse_recovering(file_content: &str, options: TokenizerOptions) -> RecoveredTokens<'_> {
+    tokenizer::parse_recovering(file_content, options)
 }
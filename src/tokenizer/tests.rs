@@ -1,4 +1,6 @@
-use super::tokenizer::parse;
+use crate::source::OffsetEncoding;
+
+use super::{parse, parse_with_options, parse_recovering, Token, TokenType, Keyword, Separator, Operator, Literal, TokenizerOptions, Tokenizer, TokenErrorType, TriviaKind, SourceType};
 
 #[test]
 fn parse_gives_back_single_tokens() {
@@ -7,7 +9,7 @@ fn parse_gives_back_single_tokens() {
     let tokens = parse(&content).unwrap();
 
     assert_eq!(tokens.len(), 1);
-    assert_eq!(tokens.get(0).unwrap().value, "Hello world");
+    assert_eq!(tokens.first().unwrap().value, "Hello world");
 }
 
 #[test]
@@ -17,7 +19,7 @@ fn parse_gives_back_two_tokens() {
     let tokens = parse(&content).unwrap();
 
     assert_eq!(tokens.len(), 2);
-    assert_eq!(tokens.get(0).unwrap().value, "Hello world");
+    assert_eq!(tokens.first().unwrap().value, "Hello world");
     assert_eq!(tokens.get(1).unwrap().value, "Hello world");
 }
 
@@ -28,7 +30,7 @@ fn equation_num_plus_num_gives_back_correct_tokens() {
     let tokens = parse(&content).unwrap();
 
     assert_eq!(tokens.len(), 3);
-    assert_eq!(tokens.get(0).unwrap().value, "123");
+    assert_eq!(tokens.first().unwrap().value, "123");
     assert_eq!(tokens.get(1).unwrap().value, "+");
     assert_eq!(tokens.get(2).unwrap().value, "321");    
 }
@@ -40,7 +42,498 @@ fn equation_num_min_num_gives_back_correct_tokens() {
     let tokens = parse(&content).unwrap();
 
     assert_eq!(tokens.len(), 3);
-    assert_eq!(tokens.get(0).unwrap().value, "123");
+    assert_eq!(tokens.first().unwrap().value, "123");
     assert_eq!(tokens.get(1).unwrap().value, "-");
     assert_eq!(tokens.get(2).unwrap().value, "321");
 }
+
+#[test]
+fn identifiers_with_reserved_word_prefixes_give_back_identifiers() {
+    let content = String::from("nullable truthy _private $el");
+
+    let tokens = parse(&content).unwrap();
+
+    assert_eq!(tokens.len(), 4);
+    assert!(tokens.iter().all(|token| token.token_type == TokenType::Identifier));
+    assert_eq!(tokens[0].value, "nullable");
+    assert_eq!(tokens[1].value, "truthy");
+    assert_eq!(tokens[2].value, "_private");
+    assert_eq!(tokens[3].value, "$el");
+}
+
+#[test]
+fn escaped_reserved_word_is_an_error() {
+    let content = String::from("var \\u0069f = 1;");
+
+    let error = parse(&content).unwrap_err();
+
+    assert_eq!(error.error_type, TokenErrorType::EscapedReservedWord);
+    assert_eq!(error.index, 4);
+}
+
+#[test]
+fn escaped_reserved_word_is_a_property_name_after_a_period() {
+    let content = String::from("a.\\u0069f; a?.n\\u0075ll");
+
+    let tokens = parse(&content).unwrap();
+
+    assert_eq!(tokens[2].token_type, TokenType::Identifier);
+    assert_eq!(tokens[2].value, "if");
+    assert_eq!(tokens[6].token_type, TokenType::Identifier);
+    assert_eq!(tokens[6].value, "null");
+}
+
+#[test]
+fn ranges_are_reported_in_chars_by_default() {
+    let content = String::from("'🦀' + café");
+
+    let tokens = parse(&content).unwrap();
+
+    assert_eq!(tokens[0].range, (0, 3));
+    assert_eq!(tokens[2].range, (6, 10));
+    assert_eq!(tokens[2].loc.end.column, 10);
+}
+
+#[test]
+fn ranges_can_be_reported_in_bytes() {
+    let content = String::from("'🦀' + café");
+    let options = TokenizerOptions { offset_encoding: OffsetEncoding::Byte, ..Default::default() };
+
+    let tokens = parse_with_options(&content, options).unwrap();
+
+    assert_eq!(tokens[0].range, (0, 6));
+    assert_eq!(tokens[2].range, (9, 14));
+    assert_eq!(&content[tokens[2].range.0..tokens[2].range.1], "café");
+    assert_eq!(tokens[2].loc.end.column, 14);
+}
+
+#[test]
+fn ranges_can_be_reported_in_utf16() {
+    let content = String::from("'🦀' + café");
+    let options = TokenizerOptions { offset_encoding: OffsetEncoding::Utf16, ..Default::default() };
+
+    let tokens = parse_with_options(&content, options).unwrap();
+
+    assert_eq!(tokens[0].range, (0, 4));
+    assert_eq!(tokens[2].range, (7, 11));
+    assert_eq!(tokens[2].loc.end.column, 11);
+}
+
+fn loc(token: &Token) -> ((usize, usize), (usize, usize)) {
+    loc_of(&token.loc)
+}
+
+fn loc_of(loc: &super::FileLocation) -> ((usize, usize), (usize, usize)) {
+    ((loc.start.line, loc.start.column), (loc.end.line, loc.end.column))
+}
+
+#[test]
+fn loc_is_correct_on_following_lines() {
+    let content = String::from("foo\n  bar\n\nbaz");
+
+    let tokens = parse(&content).unwrap();
+
+    assert_eq!(loc(&tokens[0]), ((1, 0), (1, 3)));
+    assert_eq!(loc(&tokens[1]), ((2, 2), (2, 5)));
+    assert_eq!(loc(&tokens[2]), ((4, 0), (4, 3)));
+}
+
+#[test]
+fn loc_is_correct_for_every_line_terminator() {
+    let content = String::from("a\r\n b\r c\u{2028} d\u{2029} e");
+
+    let tokens = parse(&content).unwrap();
+
+    assert_eq!(tokens.len(), 5);
+
+    for (line, token) in tokens.iter().enumerate().skip(1) {
+        assert_eq!(loc(token), ((line + 1, 1), (line + 1, 2)));
+    }
+}
+
+#[test]
+fn loc_counts_lines_within_comments() {
+    let content = String::from("// first\n/* second\nthird */ x");
+
+    let tokens = parse(&content).unwrap();
+
+    assert_eq!(loc(&tokens[0]), ((3, 9), (3, 10)));
+}
+
+#[test]
+fn loc_columns_follow_the_offset_encoding() {
+    let content = String::from("x\n'🦀' y");
+    let options = TokenizerOptions { offset_encoding: OffsetEncoding::Utf16, ..Default::default() };
+
+    let tokens = parse_with_options(&content, options).unwrap();
+
+    assert_eq!(loc(&tokens[2]), ((2, 5), (2, 6)));
+}
+
+#[test]
+fn tokenizer_reads_tokens_on_demand() {
+    let content = String::from("foo bar #");
+    let mut tokenizer = Tokenizer::new(&content);
+
+    assert_eq!(tokenizer.next().unwrap().unwrap().value, "foo");
+    assert_eq!(tokenizer.get_current_index(), 3);
+
+    assert_eq!(tokenizer.next().unwrap().unwrap().value, "bar");
+    assert_eq!(tokenizer.get_current_index(), 7);
+}
+
+#[test]
+fn tokenizer_stops_after_the_first_error() {
+    let content = String::from("foo # bar");
+
+    let results: Vec<_> = Tokenizer::new(&content).collect();
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+
+    let error = results[1].as_ref().unwrap_err();
+    assert_eq!(error.error_type, TokenErrorType::UnexpectedToken);
+    assert_eq!(error.index, 4);
+}
+
+fn trivia_kinds(trivia: &[super::Trivia]) -> Vec<TriviaKind> {
+    trivia.iter().map(|trivia| trivia.kind).collect()
+}
+
+#[test]
+fn trivia_is_not_kept_by_default() {
+    let content = String::from("// Comment\nfoo /* bar */ ;");
+
+    let tokens = parse(&content).unwrap();
+
+    assert!(tokens.iter().all(|token| token.leading_trivia.is_empty() && token.trailing_trivia.is_empty()));
+}
+
+#[test]
+fn trivia_is_attached_to_tokens() {
+    let content = String::from("// Comment\nfoo /* bar */;\n  baz");
+    let options = TokenizerOptions { preserve_trivia: true, ..Default::default() };
+
+    let tokens = parse_with_options(&content, options).unwrap();
+
+    assert_eq!(tokens.len(), 3);
+
+    assert_eq!(trivia_kinds(&tokens[0].leading_trivia), vec![TriviaKind::LineComment, TriviaKind::LineTerminator]);
+    assert_eq!(tokens[0].leading_trivia[0].raw_value, "// Comment");
+    assert_eq!(tokens[0].leading_trivia[0].value(), " Comment");
+    assert_eq!(trivia_kinds(&tokens[0].trailing_trivia), vec![TriviaKind::Whitespace, TriviaKind::BlockComment]);
+    assert_eq!(tokens[0].trailing_trivia[1].value(), " bar ");
+    assert_eq!(tokens[0].trailing_trivia[1].range, (15, 24));
+
+    assert!(tokens[1].leading_trivia.is_empty());
+    assert_eq!(trivia_kinds(&tokens[1].trailing_trivia), vec![TriviaKind::LineTerminator]);
+
+    assert_eq!(trivia_kinds(&tokens[2].leading_trivia), vec![TriviaKind::Whitespace]);
+    assert_eq!(tokens[2].leading_trivia[0].loc.start.line, 3);
+}
+
+#[test]
+fn trivia_at_the_end_of_the_file_trails_the_last_token() {
+    let content = String::from("foo\n\n/* end */");
+    let options = TokenizerOptions { preserve_trivia: true, ..Default::default() };
+
+    let tokens = parse_with_options(&content, options).unwrap();
+
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(
+        trivia_kinds(&tokens[0].trailing_trivia),
+        vec![TriviaKind::LineTerminator, TriviaKind::LineTerminator, TriviaKind::BlockComment]
+    );
+}
+
+#[test]
+fn trivia_without_a_token_ends_the_stream() {
+    let content = String::from("// only\n");
+    let options = TokenizerOptions { preserve_trivia: true, ..Default::default() };
+
+    let tokens = parse_with_options(&content, options).unwrap();
+
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].token_type, TokenType::EndOfInput);
+    assert_eq!(tokens[0].range, (8, 8));
+    assert_eq!(trivia_kinds(&tokens[0].leading_trivia), vec![TriviaKind::LineComment, TriviaKind::LineTerminator]);
+}
+
+#[test]
+fn only_trivia_is_no_tokens_by_default() {
+    let content = String::from("// only\n");
+
+    assert!(parse(&content).unwrap().is_empty());
+}
+
+#[test]
+fn trivia_round_trips_the_source() {
+    let content = String::from("\t/* a */ foo(1, 2); // b\r\n\nbar\n");
+    let options = TokenizerOptions { preserve_trivia: true, ..Default::default() };
+
+    let tokens = parse_with_options(&content, options).unwrap();
+
+    let mut output = String::new();
+
+    for token in tokens.iter() {
+        token.leading_trivia.iter().for_each(|trivia| output.push_str(trivia.raw_value));
+        output.push_str(token.raw_value);
+        token.trailing_trivia.iter().for_each(|trivia| output.push_str(trivia.raw_value));
+    }
+
+    assert_eq!(output, content);
+}
+
+#[test]
+fn recovering_gives_back_every_error() {
+    let content = String::from("foo # bar\n'open\nbaz @");
+
+    let result = parse_recovering(&content, TokenizerOptions::default());
+
+    let values: Vec<_> = result.tokens.iter().map(|token| token.raw_value).collect();
+    assert_eq!(values, vec!["foo", "#", "bar", "'open", "baz", "@"]);
+    assert_eq!(result.tokens[1].token_type, TokenType::Invalid);
+
+    assert_eq!(result.diagnostics.len(), 3);
+
+    assert_eq!(result.diagnostics[0].error_type, TokenErrorType::UnexpectedToken);
+    assert_eq!(result.diagnostics[0].message, "Unexpected character");
+    assert_eq!(result.diagnostics[0].text, "#");
+    assert_eq!(result.diagnostics[0].range, (4, 5));
+
+    assert_eq!(result.diagnostics[1].error_type, TokenErrorType::UnterminatedStringLiteral);
+    assert_eq!(result.diagnostics[1].text, "'open");
+    assert_eq!(result.diagnostics[1].index, 15);
+    assert_eq!(loc_of(&result.diagnostics[1].loc), ((2, 0), (2, 5)));
+
+    assert_eq!(result.diagnostics[2].text, "@");
+    assert_eq!(loc_of(&result.diagnostics[2].loc), ((3, 4), (3, 5)));
+}
+
+#[test]
+fn recovering_tokenizer_gives_back_errors_before_their_token() {
+    let content = String::from("foo # bar @");
+    let options = TokenizerOptions { recover_errors: true, ..Default::default() };
+
+    let items: Vec<_> = Tokenizer::with_options(&content, options)
+        .map(|item| item.map(|token| token.raw_value).map_err(|error| error.index))
+        .collect();
+
+    assert_eq!(items, vec![Ok("foo"), Err(4), Ok("#"), Ok("bar"), Err(10), Ok("@"), Ok("")]);
+}
+
+#[test]
+fn tokenizer_ends_at_the_end_of_the_source() {
+    let content = String::from("foo; // bar\n");
+
+    let tokens: Vec<_> = Tokenizer::new(&content).map(Result::unwrap).collect();
+
+    assert_eq!(tokens.len(), 3);
+    assert_eq!(tokens[2].token_type, TokenType::EndOfInput);
+    assert_eq!(tokens[2].range, (12, 12));
+    assert_eq!((tokens[2].loc.start.line, tokens[2].loc.start.column), (2, 0));
+}
+
+#[test]
+fn recovering_reads_an_unterminated_comment_to_the_end() {
+    let content = String::from("foo /* bar\nbaz");
+    let options = TokenizerOptions { preserve_trivia: true, ..Default::default() };
+
+    let result = parse_recovering(&content, options);
+
+    assert_eq!(result.tokens.len(), 1);
+    assert_eq!(trivia_kinds(&result.tokens[0].trailing_trivia), vec![TriviaKind::Whitespace, TriviaKind::BlockComment]);
+
+    assert_eq!(result.diagnostics.len(), 1);
+    assert_eq!(result.diagnostics[0].error_type, TokenErrorType::UnterminatedComment);
+    assert_eq!(result.diagnostics[0].index, 4);
+    assert_eq!(result.diagnostics[0].text, "/* bar\nbaz");
+}
+
+#[test]
+fn recovering_skips_strings_with_invalid_escapes() {
+    let content = String::from("'a\\xZZ \\' b' c");
+
+    let result = parse_recovering(&content, TokenizerOptions::default());
+
+    assert_eq!(result.tokens.len(), 2);
+    assert_eq!(result.tokens[0].token_type, TokenType::Invalid);
+    assert_eq!(result.tokens[0].raw_value, "'a\\xZZ \\' b'");
+    assert_eq!(result.tokens[1].raw_value, "c");
+
+    assert_eq!(result.diagnostics.len(), 1);
+    assert_eq!(result.diagnostics[0].error_type, TokenErrorType::InvalidHexEscapeSequence);
+    assert_eq!(result.diagnostics[0].message, "Invalid hexadecimal escape sequence");
+}
+
+#[test]
+fn recovering_without_errors_has_no_diagnostics() {
+    let content = String::from("foo(1, 'two');");
+
+    let result = parse_recovering(&content, TokenizerOptions::default());
+
+    assert_eq!(result.tokens.len(), 7);
+    assert!(result.diagnostics.is_empty());
+}
+
+#[test]
+fn hashbang_is_skipped() {
+    let content = String::from("#!/usr/bin/env joey\nfoo");
+
+    let tokens = parse(&content).unwrap();
+
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(loc(&tokens[0]), ((2, 0), (2, 3)));
+}
+
+#[test]
+fn hashbang_is_kept_as_trivia() {
+    let content = String::from("#!/usr/bin/env joey\nfoo");
+    let options = TokenizerOptions { preserve_trivia: true, ..Default::default() };
+
+    let tokens = parse_with_options(&content, options).unwrap();
+
+    assert_eq!(tokens[0].leading_trivia[0].kind, TriviaKind::HashbangComment);
+    assert_eq!(tokens[0].leading_trivia[0].value(), "/usr/bin/env joey");
+}
+
+#[test]
+fn hashbang_after_the_start_is_an_error() {
+    let content = String::from(" #!/usr/bin/env joey");
+
+    let error = parse(&content).unwrap_err();
+
+    assert_eq!(error.error_type, TokenErrorType::UnexpectedToken);
+    assert_eq!(error.index, 1);
+}
+
+#[test]
+fn byte_order_mark_is_stripped() {
+    let content = String::from("\u{FEFF}#!joey\nfoo bar");
+
+    let tokens = parse(&content).unwrap();
+
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(tokens[0].range, (7, 10));
+    assert_eq!(loc(&tokens[1]), ((2, 4), (2, 7)));
+}
+
+#[test]
+fn html_comments_are_skipped_in_scripts() {
+    let content = String::from("foo <!-- bar\n  --> baz\n/*\n*/ --> qux\nx");
+    let options = TokenizerOptions { source_type: SourceType::Script, ..Default::default() };
+
+    let tokens = parse_with_options(&content, options).unwrap();
+
+    let values: Vec<_> = tokens.iter().map(|token| token.raw_value).collect();
+    assert_eq!(values, vec!["foo", "x"]);
+}
+
+#[test]
+fn html_close_comment_after_a_token_is_not_a_comment() {
+    let content = String::from("x --> y");
+    let options = TokenizerOptions { source_type: SourceType::Script, ..Default::default() };
+
+    let tokens = parse_with_options(&content, options).unwrap();
+
+    let values: Vec<_> = tokens.iter().map(|token| token.raw_value).collect();
+    assert_eq!(values, vec!["x", "--", ">", "y"]);
+}
+
+#[test]
+fn html_comments_are_not_comments_in_modules() {
+    let content = String::from("x <!--y");
+
+    let tokens = parse(&content).unwrap();
+
+    let values: Vec<_> = tokens.iter().map(|token| token.raw_value).collect();
+    assert_eq!(values, vec!["x", "<", "!", "--", "y"]);
+}
+
+#[test]
+fn every_punctuator_is_tokenized() {
+    let content = String::from("{ ( ) [ ] . ... ; , < > <= >= == != === !== + - * % ** ++ -- << >> >>> & | ^ ! ~ && || ?? ? : = += -= *= %= **= <<= >>= >>>= &= |= ^= &&= ||= ??= => ?. x / x /= }");
+
+    let tokens = parse(&content).unwrap();
+
+    let values: Vec<_> = tokens.iter().map(|token| token.raw_value).collect();
+    let expected: Vec<_> = content.split(' ').collect();
+
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn punctuators_use_the_longest_match() {
+    let content = String::from("[...a]=>b?.c??d:e");
+
+    let tokens = parse(&content).unwrap();
+
+    let types: Vec<_> = tokens.iter().map(|token| &token.token_type).collect();
+
+    assert_eq!(types, vec![
+        &TokenType::Separator(Separator::OpenBracket),
+        &TokenType::Separator(Separator::Ellipsis),
+        &TokenType::Identifier,
+        &TokenType::Separator(Separator::CloseBracket),
+        &TokenType::Separator(Separator::Arrow),
+        &TokenType::Identifier,
+        &TokenType::Separator(Separator::OptionalChaining),
+        &TokenType::Identifier,
+        &TokenType::Operator(Operator::NullishCoalescing),
+        &TokenType::Identifier,
+        &TokenType::Separator(Separator::Colon),
+        &TokenType::Identifier,
+    ]);
+}
+
+#[test]
+fn optional_chaining_is_not_matched_before_a_digit() {
+    let content = String::from("a?.5:b");
+
+    let tokens = parse(&content).unwrap();
+
+    let values: Vec<_> = tokens.iter().map(|token| token.raw_value).collect();
+    assert_eq!(values, vec!["a", "?", ".5", ":", "b"]);
+    assert_eq!(tokens[1].token_type, TokenType::Separator(Separator::QuestionMark));
+}
+
+#[test]
+fn slash_is_a_regexp_or_division_depending_on_the_previous_token() {
+    let content = String::from("a = /b/g.test(c) / 2; x = (y) / z /i; typeof /d/");
+
+    let tokens = parse(&content).unwrap();
+
+    let regexps: Vec<_> = tokens.iter()
+        .filter(|token| token.token_type == TokenType::Literal(Literal::RegExp))
+        .map(|token| token.raw_value)
+        .collect();
+
+    assert_eq!(regexps, vec!["/b/g", "/d/"]);
+}
+
+#[test]
+fn slash_after_await_or_yield_is_a_division() {
+    let content = String::from("await / 2 / yield / 3 /g");
+
+    let tokens = parse(&content).unwrap();
+
+    assert_eq!(tokens[0].token_type, TokenType::Keyword(Keyword::Await));
+    assert!(tokens.iter().all(|token| token.token_type != TokenType::Literal(Literal::RegExp)));
+}
+
+#[test]
+fn number_literals_are_tokenized() {
+    let content = String::from("0xFF 1_000 1.5e-3 .5 10n");
+
+    let tokens = parse(&content).unwrap();
+
+    let types: Vec<_> = tokens.iter().map(|token| token.token_type).collect();
+
+    assert_eq!(types, vec![
+        TokenType::Literal(Literal::Number),
+        TokenType::Literal(Literal::Number),
+        TokenType::Literal(Literal::Number),
+        TokenType::Literal(Literal::Number),
+        TokenType::Literal(Literal::BigInt),
+    ]);
+}
@@ -1,195 +1,522 @@
+use std::borrow::Cow;
+use std::cell::Cell;
+
+use crate::source::{SourceFile, OffsetEncoding, is_line_terminator};
 use crate::tokenizer::consumers::curly_brace::consume_curly_brace;
 use crate::tokenizer::consumers::keywords::consume_keyword;
 use crate::tokenizer::consumers::parenthesis::consume_parenthesis;
-use crate::tokenizer::consumers::separator::{consume_period, consume_comma};
+use crate::tokenizer::consumers::separator::{consume_period, consume_comma, consume_colon};
+use crate::tokenizer::consumers::bracket::{is_bracket, consume_bracket};
 
 use super::consumers::boolean::{is_boolean, consume_boolean};
-use super::consumers::comments::{is_line_comment, is_block_comment, consume_line_comment, consume_block_comment};
+use super::consumers::comments::{is_line_comment, is_block_comment, consume_line_comment, consume_block_comment, is_hashbang_comment, consume_hashbang_comment, is_html_open_comment, is_html_close_comment, consume_html_comment};
 use super::consumers::curly_brace::is_curly_brace;
 use super::consumers::eol::{is_eol, consume_eol};
 use super::consumers::keywords::find_keyword;
 use super::consumers::null::{is_null, consume_null};
 use super::consumers::operator::{consume_operator, find_operator};
 use super::consumers::parenthesis::is_parenthesis;
-use super::consumers::separator::{is_period, is_comma};
+use super::consumers::separator::{is_period, is_comma, is_colon};
 use super::consumers::string::{is_string, consume_string};
 use super::consumers::terminator::{is_terminator, consume_terminator};
-use super::{Token, TokenizeError, FileLocationPos};
+use super::util::{is_escape_char, is_string_delimiter};
+use super::{Token, TokenType, Keyword, Operator, Separator, TokenizeError, TokenDiagnostic, RecoveredTokens, FileLocation, FileLocationPos, TokenizerOptions, SourceType, Trivia, TriviaKind};
 use super::consumers::identifier::{is_identifier, consume_identifier};
 use super::consumers::number::{is_number, consume_number};
+use super::consumers::regexp::{is_regexp, consume_regexp};
 use super::consumers::whitespace::{is_whitespace, consume_whitespace};
 
 #[derive(Debug)]
-pub struct Tokenizer {
+pub struct Tokenizer<'src> {
+    /// Byte offset into the file content
     index: usize,
-    eol_count: usize,
-    eol_index: usize,
-    file_content: Vec<char>,
+    file_content: &'src str,
+    source: SourceFile<'src>,
+    options: TokenizerOptions,
+    /// The line index of the last location that was looked up, the next one is usually on the same line
+    line_hint: Cell<usize>,
+    has_failed: bool,
+    /// The `EndOfInput` token has been given back
+    has_ended: bool,
+    /// Trivia that has been read ahead for the next token
+    leading_trivia: Vec<Trivia<'src>>,
+    /// No token has been read on the current line yet
+    is_at_line_start: bool,
+    /// A `/` starts a regular expression instead of a division after the previous token
+    is_regexp_allowed: bool,
+    /// The previous token is a `.` or `?.`, so a word is a property name even when it's a reserved word
+    is_after_period: bool,
+    diagnostics: Vec<TokenDiagnostic<'src>>,
+    /// How many diagnostics have been given back as errors by the iterator
+    reported: usize,
+    /// The token read after a recovered error, it's given back once the error has been
+    recovered_token: Option<Token<'src>>,
 }
 
-impl Tokenizer {
-    pub fn new(file_content: &String) -> Tokenizer {
+impl<'src> Tokenizer<'src> {
+    pub fn new(file_content: &'src str) -> Tokenizer<'src> {
+        Tokenizer::with_options(file_content, TokenizerOptions::default())
+    }
+
+    /// A leading byte order mark isn't part of the source text, offsets start after it
+    pub fn with_options(file_content: &'src str, options: TokenizerOptions) -> Tokenizer<'src> {
+        let file_content = file_content.strip_prefix('\u{FEFF}').unwrap_or(file_content);
+
         Tokenizer {
             index: 0,
-            eol_count: 0,
-            eol_index: 0,
-            file_content: file_content.chars().collect()
+            file_content,
+            source: SourceFile::new(file_content),
+            options,
+            line_hint: Cell::new(0),
+            has_failed: false,
+            has_ended: false,
+            leading_trivia: Vec::new(),
+            is_at_line_start: true,
+            is_regexp_allowed: true,
+            is_after_period: false,
+            diagnostics: Vec::new(),
+            reported: 0,
+            recovered_token: None,
         }
     }
 
+    pub fn is_at_line_start(&self) -> bool {
+        self.is_at_line_start
+    }
+
+    pub fn is_after_period(&self) -> bool {
+        self.is_after_period
+    }
+
     pub fn has_tokens(&self) -> bool {
-        self.token().is_some()
+        self.index < self.file_content.len()
+    }
+
+    pub fn token(&self) -> Option<char> {
+        self.remaining().chars().next()
     }
 
-    pub fn token(&self) -> Option<&char> {
-        self.file_content.get(self.index)
+    pub fn peek(&self) -> Option<char> {
+        self.peek_forward(1)
     }
 
-    pub fn peek(&self) -> Option<&char> {
-        self.file_content.get(self.index + 1)
+    pub fn peek_forward(&self, distance: usize) -> Option<char> {
+        self.remaining().chars().nth(distance)
     }
 
-    pub fn peek_forward(&self, distance: usize) -> Option<&char> {
-        self.file_content.get(self.index + distance)
+    /// The part of the file that hasn't been consumed yet
+    pub fn remaining(&self) -> &'src str {
+        &self.file_content[self.index..]
     }
 
-    pub fn peek_back(&self) -> Option<&char> {
-        self.file_content.get(self.index - 1)
+    pub fn starts_with(&self, word: &str) -> bool {
+        self.remaining().starts_with(word)
     }
 
-    pub fn walk_back(&mut self) {
-        self.index -= 1;
+    /// Borrows the file content between two byte indexes
+    pub fn slice(&self, start: usize, end: usize) -> &'src str {
+        &self.file_content[start..end]
     }
 
+    /// The current byte index into the file content
     pub fn get_current_index(&self) -> usize {
-        return self.index;
+        self.index
     }
-    
-    pub fn found_new_line(&mut self) {
-        self.eol_count += 1;
-        self.eol_index = self.index;
+
+    /// The current index expressed in the offset encoding the tokens should report
+    pub fn get_current_offset(&self) -> usize {
+        self.source.convert(self.index, OffsetEncoding::Byte, self.options.offset_encoding)
+    }
+
+    /// Converts a range of byte indexes to the offset encoding the tokens should report
+    pub fn get_range(&self, start: usize, end: usize) -> (usize, usize) {
+        let encoding = self.options.offset_encoding;
+
+        (
+            self.source.convert(start, OffsetEncoding::Byte, encoding),
+            self.source.convert(end, OffsetEncoding::Byte, encoding),
+        )
     }
 
     pub fn get_current_file_loc(&self) -> FileLocationPos {
-        let line = self.eol_count + 1;
-        let column = self.index - self.eol_index;
+        self.get_file_loc(self.index)
+    }
+
+    /// The line and column of a byte index, looking them up isn't free so it's only done for what ends up in a token
+    fn get_file_loc(&self, index: usize) -> FileLocationPos {
+        let encoding = self.options.offset_encoding;
+        let offset = self.source.convert(index, OffsetEncoding::Byte, encoding);
+        let (line, column) = self.source.line_column_near(offset, encoding, self.line_hint.get());
+
+        self.line_hint.set(line - 1);
 
         FileLocationPos { line, column }
     }
 
-    pub fn consume(&mut self) -> Option<&char> {
-        let value = self.file_content.get(self.index);
+    pub fn consume(&mut self) -> Option<char> {
+        let value = self.token();
 
-        self.index += 1;
+        if let Some(token) = value {
+            self.index += token.len_utf8();
+        }
 
-        return value;
+        value
     }
 
-    pub fn next(&mut self) {
-        self.index +=1;
+    pub fn next_char(&mut self) {
+        self.consume();
+    }
+
+    /// Skips a number of bytes, which should end on a char boundary
+    pub fn advance(&mut self, length: usize) {
+        self.index = (self.index + length).min(self.file_content.len());
     }
 }
 
-macro_rules! consume_and_handle {
-    ($a: expr, $b: expr) => {
-        match $a {
-            Ok(token) => $b.push(token),
-            Err(e) => return Err(e),
+impl<'src> Tokenizer<'src> {
+    /// Skips a single piece of trivia, gives back what kind it was or `None` when there's no trivia
+    fn skip_trivia(&mut self) -> Result<Option<TriviaKind>, TokenizeError> {
+        if !self.has_tokens() { return Ok(None) }
+
+        if is_eol(self) {
+            consume_eol(self)?;
+            self.is_at_line_start = true;
+            return Ok(Some(TriviaKind::LineTerminator));
         }
-    };
-}
 
-pub fn parse(file_content: &String) -> Result<Vec<Token>, TokenizeError> {
-    let mut tokens = Vec::new();
-    let mut tokenizer = Tokenizer::new(file_content);
+        if is_whitespace(self) {
+            consume_whitespace(self)?;
+            return Ok(Some(TriviaKind::Whitespace));
+        }
 
-    while tokenizer.has_tokens() {
-        if is_eol(&tokenizer) {
-            consume_eol(&mut tokenizer)?;
-            continue;
+        if is_hashbang_comment(self) {
+            consume_hashbang_comment(self);
+            return Ok(Some(TriviaKind::HashbangComment));
         }
 
-        if is_whitespace(&tokenizer) {
-            consume_whitespace(&mut tokenizer)?;
-            continue;
+        if is_line_comment(self) {
+            consume_line_comment(self);
+            return Ok(Some(TriviaKind::LineComment));
         }
 
-        if is_line_comment(&tokenizer) {
-            consume_line_comment(&mut tokenizer);
-            continue;
+        if is_block_comment(self) {
+            let start = self.get_current_index();
+
+            // When recovering, an unterminated comment is still a comment up to the end of the file
+            if let Err(error) = consume_block_comment(self) {
+                if !self.options.recover_errors { return Err(error) }
+
+                self.push_diagnostic(start, error);
+            }
+
+            if self.slice(start, self.get_current_index()).contains(is_line_terminator) {
+                self.is_at_line_start = true;
+            }
+
+            return Ok(Some(TriviaKind::BlockComment));
         }
 
-        if is_block_comment(&tokenizer) {
-            consume_block_comment(&mut tokenizer);
-            continue;
+        if self.options.source_type == SourceType::Script {
+            if is_html_open_comment(self) {
+                consume_html_comment(self);
+                return Ok(Some(TriviaKind::HtmlOpenComment));
+            }
+
+            if is_html_close_comment(self) {
+                consume_html_comment(self);
+                return Ok(Some(TriviaKind::HtmlCloseComment));
+            }
         }
 
-        if let Ok(keyword) = find_keyword(&tokenizer) {
-            consume_and_handle!(consume_keyword(&mut tokenizer, keyword), tokens);
-            continue;
+        Ok(None)
+    }
+
+    fn consume_trivia(&mut self) -> Result<Option<Trivia<'src>>, TokenizeError> {
+        let start = self.get_current_index();
+        let start_pos = self.get_current_file_loc();
+
+        let kind = match self.skip_trivia()? {
+            Some(kind) => kind,
+            None => return Ok(None),
+        };
+
+        let end = self.get_current_index();
+
+        Ok(Some(Trivia {
+            kind,
+            raw_value: self.slice(start, end),
+            range: self.get_range(start, end),
+            loc: FileLocation { start: start_pos, end: self.get_current_file_loc() },
+        }))
+    }
+
+    /// Turns the text from `start` up to where an error was found into an `Invalid` token
+    fn recover(&mut self, start: usize, error: TokenizeError) -> Token<'src> {
+        let is_string = self.slice(start, self.file_content.len()).starts_with(is_string_delimiter);
+
+        // Strings are skipped as a whole, so their contents don't turn into tokens
+        if is_string {
+            self.index = start;
+            let delimiter = self.consume();
+
+            while let Some(token) = self.token() {
+                if is_eol(self) { break }
+
+                self.next_char();
+
+                if token == delimiter.unwrap() { break }
+                if is_escape_char(token) && self.token().is_some_and(|value| !is_line_terminator(value)) { self.next_char() }
+            }
         }
 
-        if is_boolean(&tokenizer) {
-            consume_and_handle!(consume_boolean(&mut tokenizer), tokens);
-            continue;
+        // Always move forward, so the same error isn't found again
+        if self.index == start { self.next_char() }
+
+        let diagnostic = self.push_diagnostic(start, error);
+
+        Token {
+            token_type: TokenType::Invalid,
+            value: Cow::Borrowed(diagnostic.text),
+            raw_value: diagnostic.text,
+            range: diagnostic.range,
+            loc: diagnostic.loc.clone(),
+            leading_trivia: Vec::new(),
+            trailing_trivia: Vec::new(),
         }
+    }
 
-        if is_null(&tokenizer) {
-            consume_and_handle!(consume_null(&mut tokenizer), tokens);
-            continue;
+    /// Records an error that has been recovered from, it covers the text from `start` up to the current index
+    fn push_diagnostic(&mut self, start: usize, error: TokenizeError) -> &TokenDiagnostic<'src> {
+        let end = self.get_current_index();
+
+        self.diagnostics.push(TokenDiagnostic {
+            error_type: error.error_type,
+            message: error.error_type.to_string(),
+            text: self.slice(start, end),
+            index: error.index,
+            range: self.get_range(start, end),
+            loc: FileLocation { start: self.get_file_loc(start), end: self.get_current_file_loc() },
+        });
+
+        &self.diagnostics[self.diagnostics.len() - 1]
+    }
+
+    /// Diagnostics for the errors that have been recovered from so far
+    pub fn diagnostics(&self) -> &[TokenDiagnostic<'src>] {
+        &self.diagnostics
+    }
+
+    fn consume_token(&mut self) -> Result<Token<'src>, TokenizeError> {
+        self.is_at_line_start = false;
+
+        let start = self.get_current_index();
+
+        let token = match self.consume_valid_token() {
+            Ok(token) => token,
+            Err(error) if self.options.recover_errors => self.recover(start, error),
+            Err(error) => return Err(error),
+        };
+
+        self.is_regexp_allowed = is_regexp_allowed_after(&token.token_type);
+        self.is_after_period = matches!(token.token_type, TokenType::Separator(Separator::Period | Separator::OptionalChaining));
+
+        Ok(token)
+    }
+
+    fn consume_valid_token(&mut self) -> Result<Token<'src>, TokenizeError> {
+        if let Some(keyword) = find_keyword(self) {
+            return consume_keyword(self, keyword);
         }
 
-        if is_terminator(&tokenizer) {
-            consume_and_handle!(consume_terminator(&mut tokenizer), tokens);
-            continue;
+        if is_boolean(self) {
+            return consume_boolean(self);
         }
 
-        if is_period(&tokenizer) {
-            consume_and_handle!(consume_period(&mut tokenizer), tokens);
-            continue;
+        if is_null(self) {
+            return consume_null(self);
         }
 
-        if is_comma(&tokenizer) {
-            consume_and_handle!(consume_comma(&mut tokenizer), tokens);
-            continue;
+        if is_terminator(self) {
+            return consume_terminator(self);
         }
 
-        if is_parenthesis(&tokenizer) {
-            consume_and_handle!(consume_parenthesis(&mut tokenizer), tokens);
-            continue;
+        if is_number(self) {
+            return consume_number(self);
         }
 
-        if is_curly_brace(&tokenizer) {
-            consume_and_handle!(consume_curly_brace(&mut tokenizer), tokens);
-            continue;
+        if is_period(self) {
+            return consume_period(self);
         }
 
-        if is_number(&tokenizer) {
-            consume_and_handle!(consume_number(&mut tokenizer), tokens);
-            continue;
+        if is_comma(self) {
+            return consume_comma(self);
         }
 
-        if is_identifier(&tokenizer) {
-            consume_and_handle!(consume_identifier(&mut tokenizer), tokens);
-            continue;
+        if is_colon(self) {
+            return consume_colon(self);
         }
 
-        if is_string(&tokenizer) {
-            consume_and_handle!(consume_string(&mut tokenizer), tokens);
-            continue;
+        if is_parenthesis(self) {
+            return consume_parenthesis(self);
         }
 
-        if let Ok(operator) = find_operator(&tokenizer) {
-            consume_and_handle!(consume_operator(&mut tokenizer, operator), tokens);
-            continue;
+        if is_bracket(self) {
+            return consume_bracket(self);
         }
 
-        return Err(TokenizeError {
+        if is_curly_brace(self) {
+            return consume_curly_brace(self);
+        }
+
+        if is_identifier(self) {
+            return consume_identifier(self);
+        }
+
+        if is_string(self) {
+            return consume_string(self);
+        }
+
+        if self.is_regexp_allowed && is_regexp(self) {
+            return consume_regexp(self);
+        }
+
+        if let Ok(operator) = find_operator(self) {
+            return consume_operator(self, operator);
+        }
+
+        Err(TokenizeError {
             error_type: super::TokenErrorType::UnexpectedToken,
-            index: tokenizer.get_current_index(),
+            index: self.get_current_offset(),
         })
     }
 
-    return Ok(tokens);
+    fn end_of_input(&mut self, leading_trivia: Vec<Trivia<'src>>) -> Option<Token<'src>> {
+        if self.has_ended { return None }
+        self.has_ended = true;
+
+        let end = self.get_current_index();
+        let position = self.get_current_file_loc();
+
+        Some(Token {
+            token_type: TokenType::EndOfInput,
+            value: Cow::Borrowed(""),
+            raw_value: self.slice(end, end),
+            range: self.get_range(end, end),
+            loc: FileLocation { start: position.clone(), end: position },
+            leading_trivia,
+            trailing_trivia: Vec::new(),
+        })
+    }
+
+    /// Reads the next token, skipping whitespace and comments on the way
+    fn next_token(&mut self) -> Result<Option<Token<'src>>, TokenizeError> {
+        if !self.options.preserve_trivia {
+            while self.skip_trivia()?.is_some() {}
+
+            if !self.has_tokens() { return Ok(self.end_of_input(Vec::new())) }
+
+            return self.consume_token().map(Some);
+        }
+
+        let mut leading_trivia = std::mem::take(&mut self.leading_trivia);
+
+        while let Some(trivia) = self.consume_trivia()? {
+            leading_trivia.push(trivia);
+        }
+
+        if !self.has_tokens() {
+            // There's no token left for the trivia to lead or trail
+            return Ok(self.end_of_input(leading_trivia));
+        }
+
+        let mut token = self.consume_token()?;
+        token.leading_trivia = leading_trivia;
+
+        while let Some(trivia) = self.consume_trivia()? {
+            let is_end_of_line = trivia.kind == TriviaKind::LineTerminator;
+            token.trailing_trivia.push(trivia);
+
+            if is_end_of_line { break }
+        }
+
+        // Everything after the end of the line leads the next token, unless the file ends first
+        while let Some(trivia) = self.consume_trivia()? {
+            self.leading_trivia.push(trivia);
+        }
+
+        if !self.has_tokens() {
+            token.trailing_trivia.append(&mut self.leading_trivia);
+        }
+
+        Ok(Some(token))
+    }
+}
+
+// After something that ends an expression a `/` is a division, e.g. `a / b` or `(a) / b`. `await` and `yield` are
+// identifiers to the parser, so they end an expression too
+fn is_regexp_allowed_after(token_type: &TokenType) -> bool {
+    !matches!(
+        token_type,
+        TokenType::Identifier
+            | TokenType::Keyword(Keyword::Await | Keyword::Yield)
+            | TokenType::Literal(_)
+            | TokenType::Keyword(Keyword::This | Keyword::Super)
+            | TokenType::Separator(Separator::CloseParenthesis | Separator::CloseBracket | Separator::CloseCurlyBrace)
+            | TokenType::Operator(Operator::Increment | Operator::Decrement)
+    )
 }
 
+impl<'src> Iterator for Tokenizer<'src> {
+    type Item = Result<Token<'src>, TokenizeError>;
+
+    /// Tokens are read on demand, the last one is an `EndOfInput` token at the end of the source. The iterator ends after
+    /// the first error instead, unless it recovers from errors, then every error is given back before the token read
+    /// after it, usually the `Invalid` token that covers it
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(diagnostic) = self.diagnostics.get(self.reported) {
+            self.reported += 1;
+
+            return Some(Err(TokenizeError { error_type: diagnostic.error_type, index: diagnostic.index }));
+        }
+
+        if let Some(token) = self.recovered_token.take() {
+            return Some(Ok(token));
+        }
+
+        if self.has_failed { return None }
+
+        let result = self.next_token().transpose();
+
+        if let Some(Err(_)) = result {
+            self.has_failed = true;
+        }
+
+        if self.reported < self.diagnostics.len() {
+            self.recovered_token = result.and_then(Result::ok);
+            return self.next();
+        }
+
+        result
+    }
+}
+
+// An `EndOfInput` token without trivia only marks where the source ends, it's only kept for the parser
+fn is_kept(token: &Token) -> bool {
+    token.token_type != TokenType::EndOfInput || !token.leading_trivia.is_empty()
+}
+
+pub fn parse(file_content: &str, options: TokenizerOptions) -> Result<Vec<Token<'_>>, TokenizeError> {
+    Tokenizer::with_options(file_content, options)
+        .filter(|result| result.as_ref().map_or(true, is_kept))
+        .collect()
+}
+
+pub fn parse_recovering(file_content: &str, options: TokenizerOptions) -> RecoveredTokens<'_> {
+    let options = TokenizerOptions { recover_errors: true, ..options };
+    let mut tokenizer = Tokenizer::with_options(file_content, options);
+
+    // The tokenizer only stops at the end of the file, every error it gives back on the way is a diagnostic as well
+    let tokens = tokenizer.by_ref().filter_map(Result::ok).filter(is_kept).collect();
+
+    RecoveredTokens { tokens, diagnostics: tokenizer.diagnostics }
+}
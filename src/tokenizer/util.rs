@@ -1,63 +1,67 @@
-extern crate regex;
-use regex::Regex;
-use once_cell::sync::Lazy;
+use crate::source::is_line_terminator;
 
-static REGEX_WHITESPACE: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"\s").unwrap()
-});
+const ZWNJ: char = '\u{200C}';
+const ZWJ: char = '\u{200D}';
 
-static REGEX_IDENTIFIER: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"[a-zA-Z]").unwrap()
-});
+// https://262.ecma-international.org/13.0/#sec-white-space
+pub fn is_whitespace(token: char) -> bool {
+    match token {
+        '\t' | '\u{000B}' | '\u{000C}' | ' ' | '\u{00A0}' | '\u{FEFF}' => true,
+        // The remaining white space is every other code point in the Space_Separator category
+        _ => !token.is_ascii() && token != '\u{0085}' && token.is_whitespace() && !is_line_terminator(token),
+    }
+}
 
-static REGEX_NUMBER: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"[0-9]").unwrap()
-});
+// https://262.ecma-international.org/13.0/#prod-IdentifierStartChar
+pub fn is_identifier_start(token: char) -> bool {
+    token == '$' || token == '_' || unicode_id_start::is_id_start(token)
+}
 
-static REGEX_EOL: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"\n").unwrap()
-});
+// https://262.ecma-international.org/13.0/#prod-IdentifierPartChar
+pub fn is_identifier_part(token: char) -> bool {
+    token == '$' || token == ZWNJ || token == ZWJ || unicode_id_start::is_id_continue(token)
+}
 
-pub fn is_whitespace(token: &char) -> bool {   
-    REGEX_WHITESPACE.is_match(&(*token).to_string())
+pub fn is_number(token: char) -> bool {
+    token.is_ascii_digit()
 }
 
-pub fn is_identifier(token: &char) -> bool {
-    REGEX_IDENTIFIER.is_match(&(*token).to_string())
+pub fn is_period(token: char) -> bool {
+    token == '.'
 }
 
-pub fn is_number(token: &char) -> bool {
-    REGEX_NUMBER.is_match(&(*token).to_string())
+pub fn is_comma(token: char) -> bool {
+    token == ','
 }
 
-pub fn is_period(token: &char) -> bool {
-    *token == '.'
+pub fn is_colon(token: char) -> bool {
+    token == ':'
 }
 
-pub fn is_comma(token: &char) -> bool {
-    *token == ','
+pub fn is_parenthesis(token: char) -> bool {
+    token == '(' || token == ')'
 }
 
-pub fn is_parenthesis(token: &char) -> bool {
-    *token == '(' || *token == ')'
+pub fn is_bracket(token: char) -> bool {
+    token == '[' || token == ']'
 }
 
-pub fn is_terminator(token: &char) -> bool {
-    *token == ';'
+pub fn is_terminator(token: char) -> bool {
+    token == ';'
 }
 
-pub fn is_curly_brace(token: &char) -> bool {
-    *token == '{' || *token == '}'
+pub fn is_curly_brace(token: char) -> bool {
+    token == '{' || token == '}'
 }
 
-pub fn is_eol(token: &char) -> bool {
-    REGEX_EOL.is_match(&(*token).to_string())
+pub fn is_eol(token: char) -> bool {
+    is_line_terminator(token)
 }
 
-pub fn is_escape_char(token: &char) -> bool {
-    *token == '\\'
+pub fn is_escape_char(token: char) -> bool {
+    token == '\\'
 }
 
-pub fn is_string_delimiter(token: &char) -> bool {
-    *token == '\'' || *token == '\"'
+pub fn is_string_delimiter(token: char) -> bool {
+    token == '\'' || token == '\"'
 }
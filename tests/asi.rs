@@ -0,0 +1,150 @@
+use joey_script::{ast::{self, nodes::{AstNode, expression_statement::Expression}}, tokenizer::Tokenizer};
+
+// Classic automatic semicolon insertion hazards, each source is parsed as the statements it should have
+fn kinds(body: &[AstNode]) -> Vec<&'static str> {
+    body.iter().map(|node| match node {
+        AstNode::ExpressionStatement(statement) => match statement.expression {
+            Expression::Identifier(_) => "Identifier",
+            Expression::Literal(_) => "Literal",
+            Expression::BinaryExpression(_) => "BinaryExpression",
+            Expression::LogicalExpression(_) => "LogicalExpression",
+            Expression::CallExpression(_) => "CallExpression",
+            Expression::MemberExpression(_) => "MemberExpression",
+            Expression::ChainExpression(_) => "ChainExpression",
+            Expression::SequenceExpression(_) => "SequenceExpression",
+            Expression::UpdateExpression(_) => "UpdateExpression",
+            Expression::ArrowFunctionExpression(_) => "ArrowFunctionExpression",
+            Expression::ObjectExpression(_) | Expression::ArrayExpression(_) => "Expression",
+        },
+        AstNode::BlockStatement(_) => "BlockStatement",
+        AstNode::FunctionDeclaration(_) => "FunctionDeclaration",
+        AstNode::VariableDeclaration(_) => "VariableDeclaration",
+        AstNode::ReturnStatement(_) => "ReturnStatement",
+        AstNode::ThrowStatement(_) => "ThrowStatement",
+        AstNode::BreakStatement(_) => "BreakStatement",
+        AstNode::ContinueStatement(_) => "ContinueStatement",
+        AstNode::ForStatement(_) => "ForStatement",
+        AstNode::ForInStatement(_) => "ForInStatement",
+        AstNode::ForOfStatement(_) => "ForOfStatement",
+        AstNode::DoWhileStatement(_) => "DoWhileStatement",
+        AstNode::EmptyStatement(_) => "EmptyStatement",
+        AstNode::Error(_) => "Error",
+    }).collect()
+}
+
+fn function_body(content: &str) -> Vec<&'static str> {
+    let program = ast::parse(Tokenizer::new(content)).unwrap();
+
+    let Some(AstNode::FunctionDeclaration(function)) = program.body.first() else { panic!("Expected a function declaration") };
+
+    kinds(&function.body.body)
+}
+
+macro_rules! asi_tests {
+    ($($name:ident: $content:expr => [$($kind:expr),*],)*) => {
+    $(
+        #[test]
+        fn $name() {
+            let program = ast::parse(Tokenizer::new($content)).unwrap();
+
+            assert_eq!(kinds(&program.body), vec![$($kind),*] as Vec<&str>);
+        }
+    )*
+    };
+}
+
+macro_rules! asi_error_tests {
+    ($($name:ident: $content:expr => $index:expr,)*) => {
+    $(
+        #[test]
+        fn $name() {
+            let error = ast::parse(Tokenizer::new($content)).unwrap_err();
+
+            assert_eq!(error.index, $index);
+        }
+    )*
+    };
+}
+
+asi_tests! {
+    line_break_ends_a_statement: "a\nb" => ["Identifier", "Identifier"],
+    closing_brace_ends_a_statement: "{ a }" => ["BlockStatement"],
+    end_of_input_ends_a_statement: "foo(1)" => ["CallExpression"],
+    semicolons_are_not_doubled: "a;\nb;" => ["Identifier", "Identifier"],
+    empty_statements_are_kept: "a;;b" => ["Identifier", "EmptyStatement", "Identifier"],
+    comment_with_line_break_ends_a_statement: "a /*\n*/ b" => ["Identifier", "Identifier"],
+    parenthesis_on_next_line_continues_as_call: "a\n(b)" => ["CallExpression"],
+    operator_on_next_line_continues_expression: "a\n+ b" => ["BinaryExpression"],
+    operator_at_end_of_line_continues_expression: "1 +\n2\nfoo" => ["BinaryExpression", "Identifier"],
+    comma_on_next_line_continues_sequence: "a\n, b\nc" => ["SequenceExpression", "Identifier"],
+    calls_on_separate_lines_are_separate: "foo()\nbar()" => ["CallExpression", "CallExpression"],
+    member_access_on_next_line_continues_expression: "promise\n  .then(done)" => ["CallExpression"],
+    optional_chain_on_next_line_continues_expression: "a\n?.b" => ["ChainExpression"],
+    statement_after_block_needs_no_semicolon: "{ a } b" => ["BlockStatement", "Identifier"],
+    line_break_ends_a_loop_body: "for (x of xs) a\nb" => ["ForOfStatement", "Identifier"],
+    increment_on_the_next_line_is_a_prefix_update: "a\n++b" => ["Identifier", "UpdateExpression"],
+    decrement_on_the_next_line_is_a_prefix_update: "a\n--\nb" => ["Identifier", "UpdateExpression"],
+    increment_on_the_same_line_is_a_postfix_update: "a++\nb" => ["UpdateExpression", "Identifier"],
+    update_in_a_for_header: "for (var i = 0; i < n; i++) ;" => ["ForStatement"],
+    semicolon_after_do_while_is_optional: "do x; while (y) z" => ["DoWhileStatement", "Identifier"],
+    do_while_with_a_block_needs_no_semicolon: "do {} while (a) b" => ["DoWhileStatement", "Identifier"],
+    arrow_function_is_an_expression_statement: "x => 1" => ["ArrowFunctionExpression"],
+    parenthesis_after_arrow_function_body_starts_a_statement: "(a, b) => {}\n(c)" => ["ArrowFunctionExpression", "Identifier"],
+    line_break_ends_a_declaration: "var x = 1\nlet y\nz" => ["VariableDeclaration", "VariableDeclaration", "Identifier"],
+}
+
+asi_error_tests! {
+    same_line_needs_a_semicolon: "a b" => 1,
+    comment_without_line_break_needs_a_semicolon: "a /* */ b" => 1,
+    call_followed_by_identifier_needs_a_semicolon: "foo() bar" => 3,
+    sequence_followed_by_identifier_needs_a_semicolon: "a, b c" => 3,
+    line_break_in_for_header_needs_a_semicolon: "for (a\nb; c) {}" => 3,
+    increment_before_an_identifier_on_the_same_line_is_postfix: "a ++b" => 2,
+    line_break_before_arrow_is_an_error: "x\n=> 1" => 1,
+    declarations_on_the_same_line_need_a_semicolon: "let a const b = 1" => 2,
+}
+
+#[test]
+fn return_followed_by_a_line_break_returns_nothing() {
+    assert_eq!(function_body("function f() {\n  return\n  a + b\n}"), vec!["ReturnStatement", "BinaryExpression"]);
+}
+
+#[test]
+fn return_argument_continues_on_the_next_line() {
+    let kinds = function_body("function f() {\n  return a\n    + b\n}");
+
+    assert_eq!(kinds, vec!["ReturnStatement"]);
+}
+
+#[test]
+fn return_before_closing_brace_returns_nothing() {
+    assert_eq!(function_body("function f() { return }"), vec!["ReturnStatement"]);
+}
+
+#[test]
+fn break_label_on_the_next_line_is_a_statement() {
+    let program = ast::parse(Tokenizer::new("break\nouter")).unwrap();
+
+    let AstNode::BreakStatement(statement) = &program.body[0] else { panic!("Expected a break statement") };
+
+    assert!(statement.label.is_none());
+    assert_eq!(kinds(&program.body), vec!["BreakStatement", "Identifier"]);
+}
+
+#[test]
+fn continue_label_on_the_same_line_is_kept() {
+    let program = ast::parse(Tokenizer::new("continue outer\nfoo")).unwrap();
+
+    let AstNode::ContinueStatement(statement) = &program.body[0] else { panic!("Expected a continue statement") };
+
+    assert_eq!(statement.label.as_ref().unwrap().name, "outer");
+    assert_eq!(kinds(&program.body), vec!["ContinueStatement", "Identifier"]);
+}
+
+#[test]
+fn throw_followed_by_a_line_break_is_an_error() {
+    let error = ast::parse(Tokenizer::new("throw\nerror;")).unwrap_err();
+
+    assert_eq!(error.index, 1);
+    assert_eq!(error.help.unwrap().message, "the expression has to start on the same line as `throw`");
+}
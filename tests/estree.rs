@@ -0,0 +1,109 @@
+#![cfg(feature = "serde")]
+
+use std::{fs, path::{Path, PathBuf}};
+
+use joey_script::{ast, tokenizer::{Tokenizer, TokenizerOptions}, source::OffsetEncoding};
+
+// Every `<name>.js` fixture is parsed and compared with the ESTree JSON in `<name>.json`,
+// run with `UPDATE_FIXTURES=1` to write the current output instead
+fn fixtures() -> Vec<PathBuf> {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/estree");
+
+    let mut fixtures: Vec<PathBuf> = fs::read_dir(directory).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "js"))
+        .collect();
+
+    fixtures.sort();
+    fixtures
+}
+
+// Offsets are UTF-16 code units like in acorn, the same as `ast --json`
+fn parse_to_json(source: &str) -> String {
+    let options = TokenizerOptions { offset_encoding: OffsetEncoding::Utf16, ..Default::default() };
+    let program = ast::parse(Tokenizer::with_options(source, options)).unwrap();
+
+    ast::estree::to_json_pretty(&program)
+}
+
+#[test]
+fn fixtures_match_estree_json() {
+    let fixtures = fixtures();
+    assert!(!fixtures.is_empty());
+
+    for fixture in fixtures {
+        let source = fs::read_to_string(&fixture).unwrap();
+        let expected_path = fixture.with_extension("json");
+        let json = parse_to_json(&source);
+
+        if std::env::var_os("UPDATE_FIXTURES").is_some() {
+            fs::write(&expected_path, json + "\n").unwrap();
+            continue;
+        }
+
+        let expected: serde_json::Value = serde_json::from_str(&fs::read_to_string(&expected_path).unwrap()).unwrap();
+        let actual: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(actual, expected, "{} doesn't match its ESTree JSON", fixture.display());
+    }
+}
+
+// Walks every node and checks that it lies within its parent and maps back to the source
+fn check_locations(node: &serde_json::Value, source: &[u16], parent: (u64, u64)) {
+    match node {
+        serde_json::Value::Array(values) => values.iter().for_each(|value| check_locations(value, source, parent)),
+        serde_json::Value::Object(fields) if fields.contains_key("type") => {
+            let start = fields["start"].as_u64().unwrap();
+            let end = fields["end"].as_u64().unwrap();
+            let text = String::from_utf16(&source[start as usize..end as usize]).unwrap();
+
+            assert!(parent.0 <= start && end <= parent.1, "{} lies outside of its parent", fields["type"]);
+
+            match fields["type"].as_str().unwrap() {
+                "Identifier" => assert_eq!(fields["name"], text),
+                "Literal" => assert_eq!(fields["raw"], text),
+                _ => {},
+            }
+
+            fields.values().for_each(|value| check_locations(value, source, (start, end)));
+        },
+        _ => {},
+    }
+}
+
+#[test]
+fn fixture_locations_map_back_to_source() {
+    for fixture in fixtures() {
+        let source = fs::read_to_string(&fixture).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&parse_to_json(&source)).unwrap();
+
+        let code_units: Vec<u16> = source.encode_utf16().collect();
+
+        check_locations(&value, &code_units, (0, code_units.len() as u64));
+    }
+}
+
+#[test]
+fn fixture_json_round_trips_through_program() {
+    for fixture in fixtures() {
+        let json = fs::read_to_string(fixture.with_extension("json")).unwrap();
+
+        let program = ast::estree::from_json(&json).unwrap();
+
+        let expected: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let actual: serde_json::Value = serde_json::from_str(&ast::estree::to_json(&program)).unwrap();
+
+        assert_eq!(actual, expected, "{} changes when read back", fixture.display());
+    }
+}
+
+#[test]
+fn malformed_fixture_json_reports_path() {
+    let json = fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/estree/call_expression.json")).unwrap();
+    let json = json.replacen("\"type\": \"Identifier\"", "\"type\": \"Identifer\"", 1);
+
+    let error = ast::estree::from_json(&json).unwrap_err();
+
+    assert_eq!(error.to_string(), "$.body[0].expression.callee: unknown node type `Identifer`");
+}
+
@@ -0,0 +1,3 @@
+function f([a, , ...b], { c, d: [e], [k]: g, "h": i, ...rest }) {}
+function g(...[x, y]) {}
+({ a, ...b }) => a;
@@ -0,0 +1,7 @@
+for (let i = 0, n = xs.length; i < n; i) {}
+for (;;);
+for (const key in object) key;
+for (a.b of [c in d]) {}
+for (x in y, z) {}
+for (const [k, { v }] of entries) {}
+for (var i = 0; i < n; i++) f(i);
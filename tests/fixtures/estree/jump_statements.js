@@ -0,0 +1,8 @@
+function f(a) {
+  return a;
+}
+
+function g() {
+  return
+  throw error;
+}
@@ -0,0 +1,6 @@
+42;
+'hello';
+true;
+null;
+/ab+c/gi;
+10n;
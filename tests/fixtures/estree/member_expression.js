@@ -0,0 +1,5 @@
+a.b;
+a[0]?.c;
+f?.(x).y;
+(a?.b).c();
+this_.catch?.[key]?.();
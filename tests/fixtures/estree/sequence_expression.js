@@ -0,0 +1,4 @@
+a, b;
+(a, b), c;
+f((a, b), c);
+(1 + 2) * 3;
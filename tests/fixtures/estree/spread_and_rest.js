@@ -0,0 +1,4 @@
+f(...args, a);
+[a, , ...xs];
+({ a, b: 1, [k]: v, ...rest, "c": 2 });
+function g(a, ...rest) {}
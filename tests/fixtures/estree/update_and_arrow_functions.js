@@ -0,0 +1,5 @@
+i++;
+--a.b
+do i++; while (i < n)
+(a, ...rest) => a;
+x => { return x; };
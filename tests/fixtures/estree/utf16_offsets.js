@@ -0,0 +1,2 @@
+f("😀 ä", s);
+["𝒳", x];
@@ -0,0 +1,4 @@
+var x = 1;
+let [a, ...b] = c
+const { d } = e;
+for (const y of ys) { let z = y; }